@@ -124,6 +124,9 @@ impl Fuzzer {
                 | Instruction::MintFungible {
                     resource_address, ..
                 }
+                | Instruction::BurnNonFungibles {
+                    resource_address, ..
+                }
                 | Instruction::MintNonFungible {
                     resource_address, ..
                 }
@@ -7,7 +7,7 @@ use radix_engine_interface::data::scrypto::{
     scrypto_decode, scrypto_encode, ScryptoDecode, ScryptoEncode, ScryptoValue,
 };
 use radix_engine_interface::math::Decimal;
-use sbor::rust::collections::BTreeMap;
+use sbor::rust::collections::{BTreeMap, BTreeSet};
 use sbor::rust::fmt::Debug;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
@@ -208,6 +208,27 @@ impl ResourceManager {
         Ok(scrypto_decode(&rtn).unwrap())
     }
 
+    /// Burns non-fungibles by id through the resource manager, which requires the resource's
+    /// burn rule.
+    pub fn burn_non_fungibles<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            RENodeId::GlobalObject(self.0.into()),
+            NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerBurnNonFungiblesInput {
+                non_fungible_local_ids,
+            })
+            .unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn total_supply<Y, E: Debug + ScryptoDecode>(&self, api: &mut Y) -> Result<Decimal, E>
     where
         Y: ClientApi<E>,
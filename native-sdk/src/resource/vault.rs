@@ -96,6 +96,43 @@ impl Vault {
         Ok(scrypto_decode(&rtn).unwrap())
     }
 
+    pub fn sys_burn<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientSubstateApi<E> + ClientObjectApi<E>,
+    {
+        let rtn = api.call_method(
+            RENodeId::Object(self.0),
+            VAULT_BURN_IDENT,
+            scrypto_encode(&VaultBurnInput { amount }).unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    pub fn sys_burn_non_fungibles<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientSubstateApi<E> + ClientObjectApi<E>,
+    {
+        let rtn = api.call_method(
+            RENodeId::Object(self.0),
+            VAULT_BURN_NON_FUNGIBLES_IDENT,
+            scrypto_encode(&VaultBurnNonFungiblesInput {
+                non_fungible_local_ids,
+            })
+            .unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn sys_amount<Y, E: Debug + ScryptoDecode>(&self, api: &mut Y) -> Result<Decimal, E>
     where
         Y: ClientSubstateApi<E> + ClientObjectApi<E>,
@@ -6,7 +6,7 @@ use radix_engine_interface::data::scrypto::model::*;
 use radix_engine_interface::data::scrypto::{
     scrypto_decode, scrypto_encode, ScryptoCategorize, ScryptoDecode,
 };
-use radix_engine_interface::math::Decimal;
+use radix_engine_interface::math::{Decimal, RoundingMode};
 use sbor::rust::collections::BTreeSet;
 use sbor::rust::fmt::Debug;
 use sbor::rust::vec::Vec;
@@ -74,6 +74,30 @@ impl Worktop {
         Ok(scrypto_decode(&rtn).unwrap())
     }
 
+    pub fn sys_take_advanced<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        rounding_mode: RoundingMode,
+        api: &mut Y,
+    ) -> Result<Bucket, E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            RENodeId::Object(self.0),
+            WORKTOP_TAKE_ADVANCED_IDENT,
+            scrypto_encode(&WorktopTakeAdvancedInput {
+                resource_address,
+                amount,
+                rounding_mode,
+            })
+            .unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn sys_take_non_fungibles<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
         &self,
         resource_address: ResourceAddress,
@@ -0,0 +1,23 @@
+use crate::crypto::*;
+use sha3::{Digest, Keccak256};
+
+/// Computes the Keccak-256 hash of the data, as used by Ethereum (which differs from SHA3-256).
+pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+    Hash(Keccak256::digest(data).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::str::FromStr;
+
+    #[test]
+    fn test_keccak256_hash() {
+        let hash = keccak256_hash("");
+        assert_eq!(
+            hash,
+            Hash::from_str("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::*;
+use sbor::rust::borrow::ToOwned;
+use sbor::rust::fmt;
+use sbor::rust::str::FromStr;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
+use sbor::*;
+use utils::copy_u8_array;
+
+/// Represents a BLS12-381 public key, as a compressed G1 point.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Sbor)]
+#[sbor(transparent)]
+pub struct Bls12381G1PublicKey(
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))] pub [u8; Self::LENGTH],
+);
+
+impl Bls12381G1PublicKey {
+    pub const LENGTH: usize = 48;
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for Bls12381G1PublicKey {
+    type Error = ParseBls12381G1PublicKeyError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        if slice.len() != Bls12381G1PublicKey::LENGTH {
+            return Err(ParseBls12381G1PublicKeyError::InvalidLength(slice.len()));
+        }
+
+        Ok(Bls12381G1PublicKey(copy_u8_array(slice)))
+    }
+}
+
+//======
+// error
+//======
+
+/// Represents an error when parsing BLS12-381 public key from hex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBls12381G1PublicKeyError {
+    InvalidHex(String),
+    InvalidLength(usize),
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for ParseBls12381G1PublicKeyError {}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Display for ParseBls12381G1PublicKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+//======
+// text
+//======
+
+impl FromStr for Bls12381G1PublicKey {
+    type Err = ParseBls12381G1PublicKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes =
+            hex::decode(s).map_err(|_| ParseBls12381G1PublicKeyError::InvalidHex(s.to_owned()))?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl fmt::Display for Bls12381G1PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", hex::encode(self.to_vec()))
+    }
+}
+
+impl fmt::Debug for Bls12381G1PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self)
+    }
+}
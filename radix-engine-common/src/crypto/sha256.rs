@@ -0,0 +1,23 @@
+use crate::crypto::*;
+use sha2::{Digest, Sha256};
+
+pub fn sha256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+    Hash(Sha256::digest(data).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::str::FromStr;
+
+    #[test]
+    fn test_sha256_hash() {
+        let data = "Hello Radix";
+        let hash = sha256_hash(data);
+        assert_eq!(
+            hash,
+            Hash::from_str("374d9dc94c1252acf828cdfb94946cf808cb112aa9760a2e6216c14b4891f934")
+                .unwrap()
+        );
+    }
+}
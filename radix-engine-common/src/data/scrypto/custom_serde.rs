@@ -1,10 +1,13 @@
 use super::*;
-use crate::data::scrypto::model::InternalRef;
+use crate::address::{AddressError, Bech32Decoder};
+use crate::data::scrypto::model::*;
+use crate::math::{Decimal, PreciseDecimal};
 use crate::*;
+use sbor::rust::prelude::*;
 use sbor::serde_serialization::*;
 use sbor::traversal::*;
 use sbor::*;
-use utils::ContextualDisplay;
+use utils::{copy_u8_array, ContextualDisplay};
 
 impl<'a> CustomSerializationContext<'a> for ScryptoValueDisplayContext<'a> {
     type CustomTypeExtension = ScryptoCustomTypeExtension;
@@ -46,6 +49,152 @@ impl SerializableCustomTypeExtension for ScryptoCustomTypeExtension {
     }
 }
 
+impl DeserializableCustomTypeExtension for ScryptoCustomTypeExtension {
+    fn custom_value_kind_from_name(name: &str) -> Option<Self::CustomValueKind> {
+        let custom_value_kind = match name {
+            "Address" => ScryptoCustomValueKind::Address,
+            "Own" => ScryptoCustomValueKind::Own,
+            "Decimal" => ScryptoCustomValueKind::Decimal,
+            "PreciseDecimal" => ScryptoCustomValueKind::PreciseDecimal,
+            "NonFungibleLocalId" => ScryptoCustomValueKind::NonFungibleLocalId,
+            "Reference" => ScryptoCustomValueKind::Reference,
+            _ => return None,
+        };
+        Some(custom_value_kind)
+    }
+
+    fn custom_value_kind_for_type_kind(
+        type_kind: &SchemaCustomTypeKind<Self>,
+    ) -> Self::CustomValueKind {
+        match type_kind {
+            ScryptoCustomTypeKind::Address
+            | ScryptoCustomTypeKind::PackageAddress
+            | ScryptoCustomTypeKind::ComponentAddress
+            | ScryptoCustomTypeKind::ResourceAddress => ScryptoCustomValueKind::Address,
+            ScryptoCustomTypeKind::Own
+            | ScryptoCustomTypeKind::Bucket
+            | ScryptoCustomTypeKind::Proof
+            | ScryptoCustomTypeKind::Vault
+            | ScryptoCustomTypeKind::KeyValueStore => ScryptoCustomValueKind::Own,
+            ScryptoCustomTypeKind::Decimal => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomTypeKind::PreciseDecimal => ScryptoCustomValueKind::PreciseDecimal,
+            ScryptoCustomTypeKind::NonFungibleLocalId => ScryptoCustomValueKind::NonFungibleLocalId,
+            ScryptoCustomTypeKind::Reference => ScryptoCustomValueKind::Reference,
+        }
+    }
+
+    fn deserialize_value_body(
+        context: &SerializationContext<'_, '_, Self>,
+        type_index: LocalTypeIndex,
+        value_kind: Self::CustomValueKind,
+        value: &JsonValue,
+        encoder: &mut VecEncoder<Self::CustomValueKind>,
+    ) -> Result<(), DeserializationError<Self>> {
+        let value = value
+            .as_str()
+            .ok_or_else(|| DeserializationError::UnexpectedJsonType {
+                expected: "a string",
+                actual: json_type_name(value),
+            })?;
+        let custom_type_kind = match context.schema.resolve_type_kind(type_index) {
+            Some(TypeKind::Custom(custom_type_kind)) => Some(custom_type_kind),
+            _ => None,
+        };
+        match value_kind {
+            ScryptoCustomValueKind::Address => {
+                parse_address(context, custom_type_kind, value)?.encode_body(encoder)?
+            }
+            ScryptoCustomValueKind::Own => {
+                // The variant of an Own isn't output in the JSON, so we take it from the schema
+                let id = parse_object_id(value)?;
+                let own = match custom_type_kind {
+                    Some(ScryptoCustomTypeKind::Bucket) => Own::Bucket(id),
+                    Some(ScryptoCustomTypeKind::Proof) => Own::Proof(id),
+                    Some(ScryptoCustomTypeKind::Vault) => Own::Vault(id),
+                    Some(ScryptoCustomTypeKind::KeyValueStore) => Own::KeyValueStore(id),
+                    _ => Own::Object(id),
+                };
+                own.encode_body(encoder)?
+            }
+            ScryptoCustomValueKind::Decimal => Decimal::from_str(value)
+                .map_err(|error| DeserializationError::InvalidCustomValue(format!("{:?}", error)))?
+                .encode_body(encoder)?,
+            ScryptoCustomValueKind::PreciseDecimal => PreciseDecimal::from_str(value)
+                .map_err(|error| DeserializationError::InvalidCustomValue(format!("{:?}", error)))?
+                .encode_body(encoder)?,
+            ScryptoCustomValueKind::NonFungibleLocalId => NonFungibleLocalId::from_str(value)
+                .map_err(|error| DeserializationError::InvalidCustomValue(format!("{:?}", error)))?
+                .encode_body(encoder)?,
+            ScryptoCustomValueKind::Reference => {
+                InternalRef(parse_object_id(value)?).encode_body(encoder)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses an address, either from its Bech32m form - or from the form used when there is no
+/// network context, eg `FungibleResource[0100..]`.
+fn parse_address(
+    context: &SerializationContext<'_, '_, ScryptoCustomTypeExtension>,
+    custom_type_kind: Option<&ScryptoCustomTypeKind>,
+    value: &str,
+) -> Result<Address, DeserializationError<ScryptoCustomTypeExtension>> {
+    let invalid_address =
+        |error: AddressError| DeserializationError::InvalidCustomValue(format!("{:?}", error));
+
+    if let (Some(start), true) = (value.find('['), value.ends_with(']')) {
+        let hex = &value[start + 1..value.len() - 1];
+        let slice =
+            hex::decode(hex).map_err(|_| DeserializationError::InvalidHex(hex.to_string()))?;
+        return PackageAddress::try_from(slice.as_slice())
+            .map(Address::Package)
+            .or(ComponentAddress::try_from(slice.as_slice()).map(Address::Component))
+            .or(ResourceAddress::try_from(slice.as_slice()).map(Address::Resource))
+            .map_err(invalid_address);
+    }
+
+    let bech32_encoder = context.custom_context.bech32_encoder.ok_or_else(|| {
+        DeserializationError::InvalidCustomValue(format!(
+            "Cannot decode Bech32 address {} without a network context",
+            value
+        ))
+    })?;
+    let decoder = Bech32Decoder {
+        hrp_set: bech32_encoder.hrp_set.clone(),
+    };
+    match custom_type_kind {
+        Some(ScryptoCustomTypeKind::PackageAddress) => decoder
+            .validate_and_decode_package_address(value)
+            .map(Address::Package),
+        Some(ScryptoCustomTypeKind::ComponentAddress) => decoder
+            .validate_and_decode_component_address(value)
+            .map(Address::Component),
+        Some(ScryptoCustomTypeKind::ResourceAddress) => decoder
+            .validate_and_decode_resource_address(value)
+            .map(Address::Resource),
+        _ => decoder
+            .validate_and_decode_package_address(value)
+            .map(Address::Package)
+            .or(decoder
+                .validate_and_decode_component_address(value)
+                .map(Address::Component))
+            .or(decoder
+                .validate_and_decode_resource_address(value)
+                .map(Address::Resource)),
+    }
+    .map_err(invalid_address)
+}
+
+fn parse_object_id(
+    value: &str,
+) -> Result<[u8; OBJECT_ID_LENGTH], DeserializationError<ScryptoCustomTypeExtension>> {
+    match hex::decode(value) {
+        Ok(slice) if slice.len() == OBJECT_ID_LENGTH => Ok(copy_u8_array(&slice)),
+        _ => Err(DeserializationError::InvalidHex(value.to_string())),
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
 mod tests {
@@ -64,6 +213,24 @@ mod tests {
         pub a: ResourceAddress,
     }
 
+    #[derive(ScryptoSbor)]
+    pub struct SampleWithCustomValues {
+        pub resource: ResourceAddress,
+        pub amount: Decimal,
+        pub precise_amount: PreciseDecimal,
+        pub ids: Vec<NonFungibleLocalId>,
+        pub reference: InternalRef,
+    }
+
+    #[derive(ScryptoSbor)]
+    pub struct SampleWithAddressesAndDecimals {
+        pub package: PackageAddress,
+        pub components: Vec<ComponentAddress>,
+        pub resources: Vec<ResourceAddress>,
+        pub decimals: Vec<crate::math::Decimal>,
+        pub precise_decimals: Vec<crate::math::PreciseDecimal>,
+    }
+
     pub fn assert_json_eq<T: Serialize>(actual: T, expected: JsonValue) {
         let actual = to_value(&actual).unwrap();
         if actual != expected {
@@ -244,6 +411,82 @@ mod tests {
         assert_invertible_json_matches(&value, context, expected_invertible);
     }
 
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_custom_values_json_round_trip() {
+        let value = SampleWithCustomValues {
+            resource: ResourceAddress::NonFungible([3; ADDRESS_HASH_LENGTH]),
+            amount: Decimal::ONE / 100,
+            precise_amount: PreciseDecimal::ONE,
+            ids: vec![
+                NonFungibleLocalId::string("hello").unwrap(),
+                NonFungibleLocalId::integer(123),
+                NonFungibleLocalId::bytes(vec![0x23, 0x45]).unwrap(),
+            ],
+            reference: InternalRef([7; OBJECT_ID_LENGTH]),
+        };
+        let encoder = Bech32Encoder::for_simulator();
+
+        for mode in [SerializationMode::Simple, SerializationMode::Invertible] {
+            assert_json_round_trips(&value, ScryptoValueDisplayContext::no_context(), mode);
+            assert_json_round_trips(&value, &encoder, mode);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_addresses_and_decimals_json_round_trip() {
+        use crate::math::{Decimal, PreciseDecimal};
+
+        let value = SampleWithAddressesAndDecimals {
+            package: PackageAddress::Normal([1; ADDRESS_HASH_LENGTH]),
+            components: vec![
+                ComponentAddress::Normal([2; ADDRESS_HASH_LENGTH]),
+                ComponentAddress::Account([3; ADDRESS_HASH_LENGTH]),
+                ComponentAddress::EcdsaSecp256k1VirtualAccount([4; ADDRESS_HASH_LENGTH]),
+            ],
+            resources: vec![
+                ResourceAddress::Fungible([5; ADDRESS_HASH_LENGTH]),
+                ResourceAddress::NonFungible([6; ADDRESS_HASH_LENGTH]),
+            ],
+            decimals: vec![Decimal::ZERO, -Decimal::ONE / 3, Decimal::MIN, Decimal::MAX],
+            precise_decimals: vec![-PreciseDecimal::ONE / 7, PreciseDecimal::MAX],
+        };
+        let encoder = Bech32Encoder::for_simulator();
+
+        for mode in [SerializationMode::Simple, SerializationMode::Invertible] {
+            assert_json_round_trips(&value, ScryptoValueDisplayContext::no_context(), mode);
+            assert_json_round_trips(&value, &encoder, mode);
+        }
+    }
+
+    fn assert_json_round_trips<
+        'a,
+        T: ScryptoEncode + ScryptoDescribe,
+        C: Into<ScryptoValueDisplayContext<'a>>,
+    >(
+        value: &T,
+        context: C,
+        mode: SerializationMode,
+    ) {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<T, ScryptoCustomTypeExtension>();
+        let payload = scrypto_encode(value).unwrap();
+        let context = SerializationContext {
+            schema: &schema,
+            mode,
+            custom_context: context.into(),
+        };
+
+        let json = to_value(
+            SborPayloadWithSchema::<ScryptoCustomTypeExtension>::new(&payload, type_index)
+                .serializable(context),
+        )
+        .unwrap();
+
+        assert_eq!(json_to_payload(&json, &context, type_index), Ok(payload));
+    }
+
     fn assert_simple_json_matches<'a, T: ScryptoEncode, C: Into<ScryptoValueDisplayContext<'a>>>(
         value: &T,
         context: C,
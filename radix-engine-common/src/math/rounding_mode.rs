@@ -1,5 +1,7 @@
+use sbor::*;
+
 /// Defines how rounding should be done.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sbor)]
 pub enum RoundingMode {
     /// Rounds towards positive infinity, e.g. `3.1 -> 4`, `-3.1 -> -3`.
     TowardsPositiveInfinity,
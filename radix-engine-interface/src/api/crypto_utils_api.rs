@@ -0,0 +1,42 @@
+use crate::crypto::*;
+use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
+
+/// Cryptographic primitives, computed natively rather than in WASM.
+pub trait ClientCryptoUtilsApi<E: Debug> {
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    /// Computes the Keccak-256 hash of the data, as used by Ethereum.
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Hash,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Result<bool, E>;
+
+    /// Recovers the public key of a recoverable signature, if it's valid.
+    fn recover_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Hash,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Result<Option<EcdsaSecp256k1PublicKey>, E>;
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EddsaEd25519PublicKey,
+        signature: EddsaEd25519Signature,
+    ) -> Result<bool, E>;
+
+    /// Verifies an aggregate signature of each message by its public key, with the
+    /// proof-of-possession ciphersuite.
+    fn verify_bls12381_aggregate(
+        &mut self,
+        messages: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, E>;
+}
@@ -1,5 +1,6 @@
 pub mod actor_api;
 pub mod component;
+pub mod crypto_utils_api;
 pub mod kernel_modules;
 pub mod node_modules;
 pub mod object_api;
@@ -8,6 +9,7 @@ pub mod types;
 
 // Re-exports
 pub use actor_api::ClientActorApi;
+pub use crypto_utils_api::ClientCryptoUtilsApi;
 pub use kernel_modules::costing_api::ClientCostingApi;
 pub use kernel_modules::event_api::ClientEventApi;
 pub use kernel_modules::execution_trace_api::ClientExecutionTraceApi;
@@ -31,5 +33,6 @@ pub trait ClientApi<E: sbor::rust::fmt::Debug>:
     + ClientTransactionLimitsApi<E>
     + ClientTransactionRuntimeApi<E>
     + ClientExecutionTraceApi<E>
+    + ClientCryptoUtilsApi<E>
 {
 }
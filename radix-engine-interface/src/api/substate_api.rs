@@ -28,6 +28,9 @@ impl LockFlags {
     }
 }
 
+/// The maximum number of entries a single key value store scan can return.
+pub const MAX_KEY_VALUE_STORE_SCAN_LIMIT: u32 = 1000;
+
 pub trait ClientSubstateApi<E: Debug> {
     // TODO: expose non-SELF?
     fn sys_lock_substate(
@@ -56,4 +59,24 @@ pub trait ClientSubstateApi<E: Debug> {
         self.sys_write_substate(lock_handle, buf)
     }
     fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), E>;
+
+    /// Removes an entry from a key value store, returning the previous value if there was one.
+    /// Entries whose value owns nodes can't be removed.
+    fn sys_remove_key_value_entry(
+        &mut self,
+        node_id: RENodeId,
+        key: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, E>;
+
+    /// Returns up to `limit` non-empty entries of a key value store, starting at `start_key`
+    /// (inclusive). Entries are ordered by the encoding of their (encoded) key as a byte array,
+    /// which orders shorter keys first as long as keys are shorter than 128 bytes.
+    ///
+    /// `limit` can be at most [`MAX_KEY_VALUE_STORE_SCAN_LIMIT`], and is paid for up front.
+    fn sys_scan_key_value_store(
+        &mut self,
+        node_id: RENodeId,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, E>;
 }
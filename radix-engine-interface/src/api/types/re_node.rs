@@ -224,13 +224,16 @@ pub enum VaultOffset {
     LockedFungible,
     LiquidNonFungible,
     LockedNonFungible,
+    FrozenFlag,
 }
 
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum EpochManagerOffset {
     EpochManager,
+    Config,
     CurrentValidatorSet,
     PreparingValidatorSet,
+    CurrentProposalStatistic,
 }
 
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord)]
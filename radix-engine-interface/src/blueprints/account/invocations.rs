@@ -85,6 +85,92 @@ pub struct AccountDepositBatchInput {
 
 pub type AccountDepositBatchOutput = ();
 
+//=======================
+// Account Deposit Rules
+//=======================
+
+/// The policy an account applies to the resources deposited into it through the `try_deposit_*`
+/// methods. The plain `deposit` and `deposit_batch` methods accept any resource.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub enum AccountDepositRule {
+    /// Any resource can be deposited.
+    AcceptAll,
+    /// Only resources the account already holds a vault for can be deposited.
+    AcceptExisting,
+    /// Only the listed resources can be deposited.
+    AllowList(BTreeSet<ResourceAddress>),
+    /// Any resource but the listed ones can be deposited.
+    DenyList(BTreeSet<ResourceAddress>),
+}
+
+impl AccountDepositRule {
+    /// Returns whether a deposit of the given resource is accepted, given whether the account
+    /// already holds a vault for it.
+    pub fn accepts(&self, resource_address: &ResourceAddress, has_vault: bool) -> bool {
+        match self {
+            Self::AcceptAll => true,
+            Self::AcceptExisting => has_vault,
+            Self::AllowList(resources) => resources.contains(resource_address),
+            Self::DenyList(resources) => !resources.contains(resource_address),
+        }
+    }
+}
+
+pub const ACCOUNT_SET_DEPOSIT_RULE_IDENT: &str = "set_deposit_rule";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountSetDepositRuleInput {
+    pub rule: AccountDepositRule,
+}
+
+pub type AccountSetDepositRuleOutput = ();
+
+//==============================
+// Account Try Deposit Or Abort
+//==============================
+
+pub const ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT: &str = "try_deposit_or_abort";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountTryDepositOrAbortInput {
+    pub bucket: Bucket,
+}
+
+pub type AccountTryDepositOrAbortOutput = ();
+
+pub const ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT: &str = "try_deposit_batch_or_abort";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountTryDepositBatchOrAbortInput {
+    pub buckets: Vec<Bucket>,
+}
+
+pub type AccountTryDepositBatchOrAbortOutput = ();
+
+//===============================
+// Account Try Deposit Or Refund
+//===============================
+
+pub const ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT: &str = "try_deposit_or_refund";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountTryDepositOrRefundInput {
+    pub bucket: Bucket,
+}
+
+/// The bucket, if its deposit was rejected.
+pub type AccountTryDepositOrRefundOutput = Option<Bucket>;
+
+pub const ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT: &str = "try_deposit_batch_or_refund";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountTryDepositBatchOrRefundInput {
+    pub buckets: Vec<Bucket>,
+}
+
+/// The buckets whose deposits were rejected.
+pub type AccountTryDepositBatchOrRefundOutput = Vec<Bucket>;
+
 //============================
 // Account Withdraw
 //============================
@@ -6,6 +6,7 @@ use radix_engine_interface::crypto::EcdsaSecp256k1PublicKey;
 use radix_engine_interface::math::Decimal;
 use sbor::rust::collections::BTreeMap;
 use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
 
 pub const EPOCH_MANAGER_BLUEPRINT: &str = "EpochManager";
 pub const VALIDATOR_BLUEPRINT: &str = "Validator";
@@ -32,8 +33,7 @@ pub struct EpochManagerCreateInput {
     pub component_address: [u8; 26],               // TODO: Clean this up
     pub validator_set: BTreeMap<EcdsaSecp256k1PublicKey, ValidatorInit>,
     pub initial_epoch: u64,
-    pub rounds_per_epoch: u64,
-    pub num_unstake_epochs: u64,
+    pub config: EpochManagerConfig,
 }
 
 pub type EpochManagerCreateOutput = ComponentAddress;
@@ -57,8 +57,41 @@ impl Clone for EpochManagerCreateInput {
             component_address: self.component_address,
             validator_set,
             initial_epoch: self.initial_epoch,
-            rounds_per_epoch: self.rounds_per_epoch,
-            num_unstake_epochs: self.num_unstake_epochs,
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// The parameters of the epoch manager which are set at genesis and can only be changed by a
+/// protocol update.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct EpochManagerConfig {
+    pub rounds_per_epoch: u64,
+    pub num_unstake_epochs: u64,
+    /// The stake a registered validator needs to be selected into a validator set.
+    pub min_validator_stake: Decimal,
+    /// The maximum number of validators in an epoch's validator set.
+    pub max_validators: u32,
+    /// The XRD minted at the end of each epoch, distributed to the epoch's validators in
+    /// proportion to their stake.
+    pub total_emission_xrd_per_epoch: Decimal,
+    /// If set, an epoch also ends at the first round at which the Clock (at minute precision)
+    /// shows this many minutes since the epoch started, even if it has not yet reached
+    /// `rounds_per_epoch` rounds.
+    pub target_epoch_duration_minutes: Option<u64>,
+}
+
+impl Default for EpochManagerConfig {
+    /// The configuration of development and test networks: epochs of a single round, no
+    /// emissions, no minimum stake and no time-based epoch changes.
+    fn default() -> Self {
+        Self {
+            rounds_per_epoch: 1,
+            num_unstake_epochs: 1,
+            min_validator_stake: Decimal::zero(),
+            max_validators: 100,
+            total_emission_xrd_per_epoch: Decimal::zero(),
+            target_epoch_duration_minutes: None,
         }
     }
 }
@@ -81,13 +114,62 @@ pub type EpochManagerSetEpochOutput = ();
 
 pub const EPOCH_MANAGER_NEXT_ROUND_IDENT: &str = "next_round";
 
-#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
 pub struct EpochManagerNextRoundInput {
     pub round: u64,
+    /// The timestamp of the proposal which started the round, in milliseconds since the unix
+    /// epoch. It can't be earlier than the Clock's current time.
+    pub proposer_timestamp_ms: i64,
+    pub leader_proposal_history: LeaderProposalHistory,
+}
+
+/// Which validators of the current validator set made or missed their proposals since the
+/// previous round change.
+#[derive(Debug, Clone, Default, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct LeaderProposalHistory {
+    /// The leaders of the rounds which were skipped because their leader missed its proposal.
+    pub gap_round_leaders: Vec<ComponentAddress>,
+    /// The leader of the new round, if it made its proposal.
+    pub current_leader: Option<ComponentAddress>,
 }
 
 pub type EpochManagerNextRoundOutput = ();
 
+pub const EPOCH_MANAGER_GET_PROPOSAL_STATISTICS_IDENT: &str = "get_proposal_statistics";
+
+#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+pub struct EpochManagerGetProposalStatisticsInput;
+
+pub type EpochManagerGetProposalStatisticsOutput = BTreeMap<ComponentAddress, ProposalStatistic>;
+
+/// The proposals a validator made and missed in the current epoch.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ScryptoSbor)]
+pub struct ProposalStatistic {
+    pub made: u64,
+    pub missed: u64,
+}
+
+impl ProposalStatistic {
+    /// The fraction of its proposals the validator made, or one if it had none.
+    pub fn success_ratio(&self) -> Decimal {
+        let total = self.made + self.missed;
+        if total == 0 {
+            Decimal::one()
+        } else {
+            Decimal::from(self.made) / Decimal::from(total)
+        }
+    }
+}
+
+pub const EPOCH_MANAGER_UPDATE_CONFIG_IDENT: &str = "update_config";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct EpochManagerUpdateConfigInput {
+    pub config: EpochManagerConfig,
+}
+
+pub type EpochManagerUpdateConfigOutput = ();
+
 pub const EPOCH_MANAGER_CREATE_VALIDATOR_IDENT: &str = "create_validator";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
@@ -172,3 +254,48 @@ pub struct ValidatorUpdateAcceptDelegatedStakeInput {
 }
 
 pub type ValidatorUpdateAcceptDelegatedStakeOutput = ();
+
+pub const VALIDATOR_APPLY_EMISSION_IDENT: &str = "apply_emission";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct ValidatorApplyEmissionInput {
+    pub xrd_bucket: Bucket,
+    pub epoch: u64,
+}
+
+pub type ValidatorApplyEmissionOutput = ();
+
+pub const VALIDATOR_UPDATE_FEE_IDENT: &str = "update_fee";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorUpdateFeeInput {
+    /// A fraction between 0 and 1 of each emission taken by the owner as a fee.
+    pub new_fee_factor: Decimal,
+}
+
+pub type ValidatorUpdateFeeOutput = ();
+
+pub const VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT: &str = "lock_owner_stake_units";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct ValidatorLockOwnerStakeUnitsInput {
+    pub stake_unit_bucket: Bucket,
+}
+
+pub type ValidatorLockOwnerStakeUnitsOutput = ();
+
+pub const VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT: &str = "start_unlock_owner_stake_units";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorStartUnlockOwnerStakeUnitsInput {
+    pub requested_stake_unit_amount: Decimal,
+}
+
+pub type ValidatorStartUnlockOwnerStakeUnitsOutput = ();
+
+pub const VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT: &str = "finish_unlock_owner_stake_units";
+
+#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+pub struct ValidatorFinishUnlockOwnerStakeUnitsInput {}
+
+pub type ValidatorFinishUnlockOwnerStakeUnitsOutput = Bucket;
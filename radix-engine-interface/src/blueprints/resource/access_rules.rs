@@ -272,6 +272,10 @@ pub fn resource_access_rules_from_owner_badge(
         ResourceMethodAuthKey::Recall,
         (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
     );
+    access_rules.insert(
+        ResourceMethodAuthKey::Freeze,
+        (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
+    );
     access_rules.insert(
         Mint,
         (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
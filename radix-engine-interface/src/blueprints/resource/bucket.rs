@@ -48,6 +48,16 @@ pub struct BucketTakeInput {
 
 pub type BucketTakeOutput = Bucket;
 
+pub const BUCKET_TAKE_ADVANCED_IDENT: &str = "Bucket_take_advanced";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct BucketTakeAdvancedInput {
+    pub amount: Decimal,
+    pub rounding_mode: RoundingMode,
+}
+
+pub type BucketTakeAdvancedOutput = Bucket;
+
 pub const BUCKET_PUT_IDENT: &str = "Bucket_put";
 
 #[derive(Debug, Eq, PartialEq, ScryptoSbor)]
@@ -103,6 +103,16 @@ pub struct NonFungibleResourceManagerGetNonFungibleInput {
 
 pub type NonFungibleResourceManagerGetNonFungibleOutput = ScryptoValue;
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_IDENT: &str = "burn_non_fungibles";
+
+/// Burns non-fungibles by id, which requires the resource's burn rule.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerBurnNonFungiblesInput {
+    pub non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+}
+
+pub type NonFungibleResourceManagerBurnNonFungiblesOutput = ();
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT: &str = "mint";
 
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
@@ -11,6 +11,7 @@ pub enum ResourceMethodAuthKey {
     Withdraw,
     Deposit,
     Recall,
+    Freeze,
 }
 
 pub const RESOURCE_MANAGER_BURN_IDENT: &str = "burn";
@@ -1,6 +1,6 @@
 use crate::data::scrypto::model::*;
 use crate::{
-    math::{BnumI256, Decimal},
+    math::{BnumI256, Decimal, RoundingMode},
     *,
 };
 use sbor::*;
@@ -42,4 +42,9 @@ impl ResourceType {
             && amount.0 % BnumI256::from(10i128.pow((18 - self.divisibility().unwrap_or(0)).into()))
                 == BnumI256::from(0)
     }
+
+    /// Rounds the given amount to the divisibility of this resource.
+    pub fn round_amount(&self, amount: Decimal, mode: RoundingMode) -> Decimal {
+        amount.round(self.divisibility().unwrap_or(0).into(), mode)
+    }
 }
@@ -5,6 +5,7 @@ use crate::data::scrypto::ScryptoCustomTypeKind;
 use crate::data::scrypto::ScryptoCustomValueKind;
 use crate::math::*;
 use crate::*;
+use bitflags::bitflags;
 use sbor::rust::prelude::*;
 use sbor::*;
 
@@ -36,6 +37,16 @@ pub struct VaultTakeInput {
 
 pub type VaultTakeOutput = Bucket;
 
+pub const VAULT_TAKE_ADVANCED_IDENT: &str = "take_advanced";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct VaultTakeAdvancedInput {
+    pub amount: Decimal,
+    pub rounding_mode: RoundingMode,
+}
+
+pub type VaultTakeAdvancedOutput = Bucket;
+
 pub const VAULT_TAKE_NON_FUNGIBLES_IDENT: &str = "take_non_fungibles";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -73,6 +84,55 @@ pub struct VaultRecallNonFungiblesInput {
 
 pub type VaultRecallNonFungiblesOutput = Bucket;
 
+pub const VAULT_BURN_IDENT: &str = "burn";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct VaultBurnInput {
+    pub amount: Decimal,
+}
+
+pub type VaultBurnOutput = ();
+
+pub const VAULT_BURN_NON_FUNGIBLES_IDENT: &str = "burn_non_fungibles";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct VaultBurnNonFungiblesInput {
+    pub non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+}
+
+pub type VaultBurnNonFungiblesOutput = ();
+
+bitflags! {
+    /// The operations blocked on a frozen vault. Recalls are never blocked.
+    #[derive(Sbor)]
+    pub struct VaultFreezeFlags: u32 {
+        /// Blocks withdrawing resources from the vault, including locking fees.
+        const WITHDRAW = 0b00000001;
+        /// Blocks depositing resources into the vault.
+        const DEPOSIT = 0b00000010;
+        /// Blocks burning resources directly from the vault.
+        const BURN = 0b00000100;
+    }
+}
+
+pub const VAULT_FREEZE_IDENT: &str = "freeze";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct VaultFreezeInput {
+    pub to_freeze: VaultFreezeFlags,
+}
+
+pub type VaultFreezeOutput = ();
+
+pub const VAULT_UNFREEZE_IDENT: &str = "unfreeze";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct VaultUnfreezeInput {
+    pub to_unfreeze: VaultFreezeFlags,
+}
+
+pub type VaultUnfreezeOutput = ();
+
 pub const VAULT_GET_AMOUNT_IDENT: &str = "get_amount";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -1,6 +1,6 @@
 use crate::blueprints::resource::*;
 use crate::data::scrypto::model::*;
-use crate::math::Decimal;
+use crate::math::{Decimal, RoundingMode};
 use crate::*;
 use sbor::rust::prelude::*;
 
@@ -42,6 +42,17 @@ pub struct WorktopTakeInput {
 
 pub type WorktopTakeOutput = Bucket;
 
+pub const WORKTOP_TAKE_ADVANCED_IDENT: &str = "Worktop_take_advanced";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct WorktopTakeAdvancedInput {
+    pub amount: Decimal,
+    pub resource_address: ResourceAddress,
+    pub rounding_mode: RoundingMode,
+}
+
+pub type WorktopTakeAdvancedOutput = Bucket;
+
 pub const WORKTOP_TAKE_NON_FUNGIBLES_IDENT: &str = "Worktop_take_non_fungibles";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
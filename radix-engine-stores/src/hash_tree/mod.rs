@@ -1,4 +1,5 @@
 use jellyfish::JellyfishMerkleTree;
+use proof::{LeafProof, ProvenLeaf, SubstateProof};
 use radix_engine_interface::api::types::{NodeModuleId, RENodeId, SubstateId, SubstateOffset};
 use radix_engine_interface::crypto::{hash, Hash};
 use radix_engine_interface::data::scrypto::scrypto_encode;
@@ -8,14 +9,14 @@ use sbor::rust::vec::Vec;
 use tree_store::{
     Payload, ReNodeModulePayload, ReadableTreeStore, TreeNode, TreeStore, WriteableTreeStore,
 };
-use types::{NibblePath, NodeKey, Version};
+use types::{NibblePath, NodeKey, SparseMerkleProof, Version};
 
 pub mod hash_tree_facade;
+pub mod proof;
 pub mod tree_store;
 
-// The sources copied from Aptos (the `jellyfish` and `types` modules) contain support for
-// generating proofs, which we plan to use in near future. Hence, we do not delete that code, but
-// suppress warnings.
+// The sources copied from Aptos (the `jellyfish` and `types` modules) contain more features than
+// we currently use (e.g. range proofs). Hence, we do not delete that code, but suppress warnings.
 
 #[allow(dead_code)]
 mod jellyfish;
@@ -74,6 +75,49 @@ pub fn put_at_next_version<S: TreeStore<ReNodeModulePayload> + TreeStore<Substat
     put_re_node_changes(store, current_version, nested_root_changes)
 }
 
+/// Reads the hash of the given substate at the given `version` (or `None` if the substate does not
+/// exist), together with a proof which can be checked against the root hash of that version (see
+/// `proof::verify_substate_proof()`).
+///
+/// # Panics
+/// Panics if a root node for `version` does not exist.
+pub fn get_with_proof<
+    S: ReadableTreeStore<ReNodeModulePayload> + ReadableTreeStore<SubstateOffset>,
+>(
+    store: &S,
+    version: Version,
+    substate_id: &SubstateId,
+) -> (Option<Hash>, SubstateProof) {
+    let re_node_module = ReNodeModule::new(substate_id.0, substate_id.1);
+    let (re_node_module_leaf, re_node_module_proof) =
+        JellyfishMerkleTree::<S, ReNodeModulePayload>::new(store)
+            .get_with_proof(hash(scrypto_encode(&re_node_module).unwrap()), version)
+            .expect("error while reading tree during proof generation");
+    let Some((_, (payload, subtree_version))) = re_node_module_leaf else {
+        return (
+            None,
+            SubstateProof {
+                re_node_module_proof: to_leaf_proof(re_node_module_proof),
+                substate_proof: None,
+            },
+        );
+    };
+    let subtree_store = NestedTreeReader::new(store, &re_node_module, payload.substates_root);
+    let (substate_leaf, substate_proof) = JellyfishMerkleTree::new(&subtree_store)
+        .get_with_proof(
+            hash(scrypto_encode(&substate_id.2).unwrap()),
+            subtree_version,
+        )
+        .expect("error while reading nested tree during proof generation");
+    (
+        substate_leaf.map(|(value_hash, _)| value_hash),
+        SubstateProof {
+            re_node_module_proof: to_leaf_proof(re_node_module_proof),
+            substate_proof: Some(to_leaf_proof(substate_proof)),
+        },
+    )
+}
+
 // only internals below
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ScryptoSbor)]
@@ -226,6 +270,16 @@ fn to_re_node_change(
     }
 }
 
+fn to_leaf_proof(proof: SparseMerkleProof) -> LeafProof {
+    LeafProof {
+        leaf: proof.leaf().map(|leaf| ProvenLeaf {
+            key_hash: leaf.key(),
+            value_hash: leaf.value_hash(),
+        }),
+        siblings: proof.siblings().to_vec(),
+    }
+}
+
 fn to_substate_change(change: IdChange<SubstateOffset, Hash>) -> LeafChange<SubstateOffset> {
     LeafChange {
         key_hash: hash(scrypto_encode(&change.id).unwrap()),
@@ -248,9 +302,7 @@ impl<'s, S> NestedTreeStore<'s, S> {
     ) -> NestedTreeStore<'s, S> {
         NestedTreeStore {
             underlying,
-            parent_path: NibblePath::new_even(
-                hash(scrypto_encode(re_node_module).unwrap()).to_vec(),
-            ),
+            parent_path: nested_tree_path(re_node_module),
             current_root: root,
             new_root: None,
         }
@@ -263,14 +315,7 @@ impl<'s, S> NestedTreeStore<'s, S> {
     }
 
     fn prefixed(&self, key: &NodeKey) -> NodeKey {
-        NodeKey::new(
-            key.version(),
-            NibblePath::from_iter(
-                self.parent_path
-                    .nibbles()
-                    .chain(key.nibble_path().nibbles()),
-            ),
-        )
+        prefix_node_key(&self.parent_path, key)
     }
 }
 
@@ -305,3 +350,48 @@ impl<'s, S: WriteableTreeStore<SubstateOffset>> WriteableTreeStore<SubstateOffse
         }
     }
 }
+
+/// A read-only counterpart of `NestedTreeStore`, used for proof generation.
+struct NestedTreeReader<'s, S> {
+    underlying: &'s S,
+    parent_path: NibblePath,
+    root: TreeNode<SubstateOffset>,
+}
+
+impl<'s, S> NestedTreeReader<'s, S> {
+    pub fn new(
+        underlying: &'s S,
+        re_node_module: &ReNodeModule,
+        root: TreeNode<SubstateOffset>,
+    ) -> NestedTreeReader<'s, S> {
+        NestedTreeReader {
+            underlying,
+            parent_path: nested_tree_path(re_node_module),
+            root,
+        }
+    }
+}
+
+impl<'s, S: ReadableTreeStore<SubstateOffset>> ReadableTreeStore<SubstateOffset>
+    for NestedTreeReader<'s, S>
+{
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode<SubstateOffset>> {
+        if key.nibble_path().is_empty() {
+            Some(self.root.clone())
+        } else {
+            self.underlying
+                .get_node(&prefix_node_key(&self.parent_path, key))
+        }
+    }
+}
+
+fn nested_tree_path(re_node_module: &ReNodeModule) -> NibblePath {
+    NibblePath::new_even(hash(scrypto_encode(re_node_module).unwrap()).to_vec())
+}
+
+fn prefix_node_key(parent_path: &NibblePath, key: &NodeKey) -> NodeKey {
+    NodeKey::new(
+        key.version(),
+        NibblePath::from_iter(parent_path.nibbles().chain(key.nibble_path().nibbles())),
+    )
+}
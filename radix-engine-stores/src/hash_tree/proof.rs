@@ -0,0 +1,195 @@
+use super::ReNodeModule;
+use radix_engine_interface::api::types::SubstateId;
+use radix_engine_interface::crypto::{hash, Hash};
+use radix_engine_interface::data::scrypto::scrypto_encode;
+use radix_engine_interface::*;
+use sbor::rust::vec::Vec;
+
+/// The hash of an empty (sub)tree, as used by the JMT.
+const PLACEHOLDER_HASH: Hash = Hash([0u8; Hash::LENGTH]);
+
+/// The maximum number of siblings in a single-layer proof (i.e. the number of bits in a key hash).
+const MAX_SIBLINGS: usize = Hash::LENGTH * 8;
+
+/// A proof of inclusion (or exclusion) of a single substate in the state identified by a root hash.
+/// Since our JMT is "nested", the proof consists of a proof within the upper (ReNode+Module) layer
+/// and, if the ReNode+Module leaf exists, a proof within its Substate layer tree.
+#[derive(Clone, PartialEq, Eq, Debug, ScryptoSbor)]
+pub struct SubstateProof {
+    /// Proof of the ReNode+Module leaf (whose value hash is the root hash of the Substate tree).
+    pub re_node_module_proof: LeafProof,
+    /// Proof of the Substate leaf, present only if the ReNode+Module leaf exists.
+    pub substate_proof: Option<LeafProof>,
+}
+
+/// A proof of inclusion (or exclusion) of a single key within a single layer of the tree.
+#[derive(Clone, PartialEq, Eq, Debug, ScryptoSbor)]
+pub struct LeafProof {
+    /// The leaf found at the end of the searched path.
+    /// - If its key hash equals the requested one, this is an inclusion proof.
+    /// - Otherwise, it is an exclusion proof: the leaf is the only one in the subtree where the
+    ///   requested key would be placed.
+    /// - If `None`, it is also an exclusion proof: that subtree is empty.
+    pub leaf: Option<ProvenLeaf>,
+    /// Hashes of all the siblings on the path, ordered from the bottom level to the root level.
+    pub siblings: Vec<Hash>,
+}
+
+/// The contents of a leaf, as needed for computing its hash.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ScryptoSbor)]
+pub struct ProvenLeaf {
+    /// The hash of the leaf's key.
+    pub key_hash: Hash,
+    /// The externally-provided hash of the leaf's value.
+    pub value_hash: Hash,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProofVerificationError {
+    /// The proof contains more siblings than a key hash has bits.
+    TooManySiblings(usize),
+    /// An inclusion was expected, but the proof shows the key is absent.
+    KeyNotIncluded(Hash),
+    /// An exclusion was expected, but the proof shows the key is present.
+    KeyIncluded(Hash),
+    /// The proven leaf has a different key, but it could not be located on the key's path.
+    LeafNotOnPath { key_hash: Hash, leaf_key_hash: Hash },
+    /// The proven leaf carries a different value hash than expected.
+    ValueHashMismatch { expected: Hash, actual: Hash },
+    /// The ReNode+Module leaf exists, but no proof within its Substate tree was given.
+    MissingSubstateProof,
+    /// The ReNode+Module leaf does not exist, but a proof within its Substate tree was given.
+    UnexpectedSubstateProof,
+    /// The proof leads to a different root hash than the trusted one.
+    RootHashMismatch { expected: Hash, actual: Hash },
+}
+
+/// Verifies that the given `proof` shows the substate identified by `substate_id` to have the
+/// given `substate_hash` (or to not exist, if `None`) in the state with the trusted `root_hash`.
+pub fn verify_substate_proof(
+    root_hash: Hash,
+    substate_id: &SubstateId,
+    substate_hash: Option<Hash>,
+    proof: &SubstateProof,
+) -> Result<(), ProofVerificationError> {
+    let re_node_module_key =
+        hash(scrypto_encode(&ReNodeModule::new(substate_id.0, substate_id.1)).unwrap());
+    let substate_key = hash(scrypto_encode(&substate_id.2).unwrap());
+    let substates_root_hash = match (&proof.substate_proof, substate_hash) {
+        (Some(substate_proof), _) => {
+            Some(substate_proof.compute_root(substate_key, substate_hash)?)
+        }
+        (None, Some(_)) => return Err(ProofVerificationError::MissingSubstateProof),
+        (None, None) => None,
+    };
+    let actual = proof
+        .re_node_module_proof
+        .compute_root(re_node_module_key, substates_root_hash)
+        .map_err(|error| match error {
+            ProofVerificationError::KeyNotIncluded(_) if proof.substate_proof.is_some() => {
+                ProofVerificationError::UnexpectedSubstateProof
+            }
+            ProofVerificationError::KeyIncluded(_) if proof.substate_proof.is_none() => {
+                ProofVerificationError::MissingSubstateProof
+            }
+            error => error,
+        })?;
+    if actual != root_hash {
+        return Err(ProofVerificationError::RootHashMismatch {
+            expected: root_hash,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+impl LeafProof {
+    /// Verifies that this proof shows the `key_hash` to be associated with the given `value_hash`
+    /// (or to be absent, if `None`) in the tree with the trusted `root_hash`.
+    pub fn verify(
+        &self,
+        root_hash: Hash,
+        key_hash: Hash,
+        value_hash: Option<Hash>,
+    ) -> Result<(), ProofVerificationError> {
+        let actual = self.compute_root(key_hash, value_hash)?;
+        if actual != root_hash {
+            return Err(ProofVerificationError::RootHashMismatch {
+                expected: root_hash,
+                actual,
+            });
+        }
+        Ok(())
+    }
+
+    /// Computes the root hash of the tree in which the `key_hash` is associated with the given
+    /// `value_hash` (or is absent, if `None`), according to this proof.
+    pub fn compute_root(
+        &self,
+        key_hash: Hash,
+        value_hash: Option<Hash>,
+    ) -> Result<Hash, ProofVerificationError> {
+        if self.siblings.len() > MAX_SIBLINGS {
+            return Err(ProofVerificationError::TooManySiblings(self.siblings.len()));
+        }
+        match (value_hash, self.leaf) {
+            (Some(expected), Some(leaf)) => {
+                if leaf.key_hash != key_hash {
+                    return Err(ProofVerificationError::KeyNotIncluded(key_hash));
+                }
+                if leaf.value_hash != expected {
+                    return Err(ProofVerificationError::ValueHashMismatch {
+                        expected,
+                        actual: leaf.value_hash,
+                    });
+                }
+            }
+            (Some(_), None) => return Err(ProofVerificationError::KeyNotIncluded(key_hash)),
+            (None, Some(leaf)) => {
+                if leaf.key_hash == key_hash {
+                    return Err(ProofVerificationError::KeyIncluded(key_hash));
+                }
+                // The other leaf must be the only one in the subtree where our key would be placed
+                if common_prefix_bits(&key_hash, &leaf.key_hash) < self.siblings.len() {
+                    return Err(ProofVerificationError::LeafNotOnPath {
+                        key_hash,
+                        leaf_key_hash: leaf.key_hash,
+                    });
+                }
+            }
+            (None, None) => {}
+        }
+        let leaf_hash = self
+            .leaf
+            .map(|leaf| hash_pair(&leaf.key_hash, &leaf.value_hash))
+            .unwrap_or(PLACEHOLDER_HASH);
+        let root_hash =
+            self.siblings
+                .iter()
+                .enumerate()
+                .fold(leaf_hash, |current, (index, sibling)| {
+                    if bit_at(&key_hash, self.siblings.len() - 1 - index) {
+                        hash_pair(sibling, &current)
+                    } else {
+                        hash_pair(&current, sibling)
+                    }
+                });
+        Ok(root_hash)
+    }
+}
+
+// only internals below
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    hash([left.0, right.0].concat())
+}
+
+fn bit_at(hash: &Hash, index: usize) -> bool {
+    (hash.0[index / 8] >> (7 - index % 8)) & 1 != 0
+}
+
+fn common_prefix_bits(left: &Hash, right: &Hash) -> usize {
+    (0..MAX_SIBLINGS)
+        .take_while(|index| bit_at(left, *index) == bit_at(right, *index))
+        .count()
+}
@@ -1,9 +1,10 @@
 use super::types::{Nibble, NibblePath, Version, SPARSE_MERKLE_PLACEHOLDER_HASH};
+use crate::hash_tree::proof::{verify_substate_proof, ProofVerificationError};
 use crate::hash_tree::tree_store::{
     SerializedInMemoryTreeStore, TreeChildEntry, TreeInternalNode, TreeLeafNode, TreeNode,
     TypedInMemoryTreeStore,
 };
-use crate::hash_tree::{put_at_next_version, SubstateHashChange};
+use crate::hash_tree::{get_with_proof, put_at_next_version, SubstateHashChange};
 use itertools::Itertools;
 use radix_engine::system::node_substates::PersistedSubstate;
 use radix_engine::types::PackageAddress;
@@ -293,6 +294,146 @@ fn records_stale_tree_node_keys() {
     assert_eq!(stale_versions, vec![1, 2]);
 }
 
+#[test]
+fn proves_inclusion_of_existing_substates() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(1, NodeModuleId::SELF, 3, Some(40)),
+            change(4, NodeModuleId::Metadata, 5, Some(50)),
+        ],
+    );
+    for (re_node_id_seed, node_module_id, substate_offset_seed, value_seed) in [
+        (1, NodeModuleId::SELF, 2, 30),
+        (1, NodeModuleId::SELF, 3, 40),
+        (4, NodeModuleId::Metadata, 5, 50),
+    ] {
+        let substate_id = substate_id(re_node_id_seed, node_module_id, substate_offset_seed);
+        let (value, proof) = get_with_proof(&store, 1, &substate_id);
+        assert_eq!(value, Some(value_hash(value_seed)));
+        assert_eq!(
+            verify_substate_proof(root_hash, &substate_id, value, &proof),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn proves_exclusion_of_missing_substates() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(4, NodeModuleId::SELF, 5, Some(50)),
+        ],
+    );
+    // missing offset within an existing ReNode+Module, and an entirely missing ReNode+Module
+    for substate_id in [
+        substate_id(1, NodeModuleId::SELF, 7),
+        substate_id(1, NodeModuleId::Metadata, 2),
+        substate_id(9, NodeModuleId::SELF, 2),
+    ] {
+        let (value, proof) = get_with_proof(&store, 1, &substate_id);
+        assert_eq!(value, None);
+        assert_eq!(
+            verify_substate_proof(root_hash, &substate_id, None, &proof),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn proves_substates_at_historical_version() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let hash_v1 = put_at_next_version(
+        &mut store,
+        None,
+        vec![change(1, NodeModuleId::SELF, 2, Some(30))],
+    );
+    let hash_v2 = put_at_next_version(
+        &mut store,
+        Some(1),
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(70)),
+            change(3, NodeModuleId::SELF, 4, Some(20)),
+        ],
+    );
+    let substate_id = substate_id(1, NodeModuleId::SELF, 2);
+    let (value_v1, proof_v1) = get_with_proof(&store, 1, &substate_id);
+    let (value_v2, proof_v2) = get_with_proof(&store, 2, &substate_id);
+    assert_eq!(value_v1, Some(value_hash(30)));
+    assert_eq!(value_v2, Some(value_hash(70)));
+    assert_eq!(
+        verify_substate_proof(hash_v1, &substate_id, value_v1, &proof_v1),
+        Ok(())
+    );
+    assert_eq!(
+        verify_substate_proof(hash_v2, &substate_id, value_v2, &proof_v2),
+        Ok(())
+    );
+    assert!(matches!(
+        verify_substate_proof(hash_v2, &substate_id, value_v1, &proof_v1),
+        Err(ProofVerificationError::RootHashMismatch { .. })
+    ));
+}
+
+#[test]
+fn rejects_proofs_of_different_value_or_key() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(1, NodeModuleId::SELF, 3, Some(40)),
+        ],
+    );
+    let substate_id_2 = substate_id(1, NodeModuleId::SELF, 2);
+    let substate_id_3 = substate_id(1, NodeModuleId::SELF, 3);
+    let (_, proof) = get_with_proof(&store, 1, &substate_id_2);
+    assert_eq!(
+        verify_substate_proof(root_hash, &substate_id_2, Some(value_hash(40)), &proof),
+        Err(ProofVerificationError::ValueHashMismatch {
+            expected: value_hash(40),
+            actual: value_hash(30),
+        })
+    );
+    assert!(verify_substate_proof(root_hash, &substate_id_2, None, &proof).is_err());
+    assert!(
+        verify_substate_proof(root_hash, &substate_id_3, Some(value_hash(40)), &proof).is_err()
+    );
+    assert_eq!(
+        verify_substate_proof(
+            Hash([7; Hash::LENGTH]),
+            &substate_id_2,
+            Some(value_hash(30)),
+            &proof
+        ),
+        Err(ProofVerificationError::RootHashMismatch {
+            expected: Hash([7; Hash::LENGTH]),
+            actual: root_hash,
+        })
+    );
+}
+
+#[test]
+fn proves_exclusion_within_empty_state() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(&mut store, None, vec![]);
+    let substate_id = substate_id(1, NodeModuleId::SELF, 2);
+    let (value, proof) = get_with_proof(&store, 1, &substate_id);
+    assert_eq!(value, None);
+    assert_eq!(
+        verify_substate_proof(root_hash, &substate_id, None, &proof),
+        Ok(())
+    );
+}
+
 #[test]
 fn sbor_uses_custom_direct_codecs_for_nibbles() {
     let nibbles = nibbles("a1a2a3");
@@ -1,5 +1,7 @@
 use radix_engine::ledger::{
-    OutputValue, QueryableSubstateStore, ReadableSubstateStore, WriteableSubstateStore,
+    decode_kv_store_entry_key, encode_kv_store_entries_prefix, encode_kv_store_entry_id,
+    take_kv_store_entries, OutputValue, QueryableSubstateStore, ReadableSubstateStore,
+    WriteableSubstateStore,
 };
 use radix_engine::system::node_substates::PersistedSubstate;
 use radix_engine::types::*;
@@ -8,13 +10,13 @@ use radix_engine_interface::api::types::RENodeId;
 /// A substate store that stores all typed substates in host memory.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SerializedInMemorySubstateStore {
-    substates: HashMap<Vec<u8>, Vec<u8>>,
+    substates: BTreeMap<Vec<u8>, Vec<u8>>,
 }
 
 impl SerializedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
-            substates: HashMap::new(),
+            substates: BTreeMap::new(),
         }
     }
 }
@@ -31,6 +33,27 @@ impl ReadableSubstateStore for SerializedInMemorySubstateStore {
             .get(&scrypto_encode(substate_id).expect("Could not encode substate id"))
             .map(|b| scrypto_decode(&b).unwrap())
     }
+
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let prefix = encode_kv_store_entries_prefix(kv_store_id);
+        let start = encode_kv_store_entry_id(kv_store_id, start_key.unwrap_or_default());
+        let entries = self
+            .substates
+            .range(start..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                (
+                    decode_kv_store_entry_key(key),
+                    scrypto_decode(value).unwrap(),
+                )
+            });
+        take_kv_store_entries(entries, limit)
+    }
 }
 
 impl WriteableSubstateStore for SerializedInMemorySubstateStore {
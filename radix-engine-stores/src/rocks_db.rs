@@ -1,22 +1,76 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::hash_tree::tree_store::{
+    encode_key, NodeKey, Payload, ReadableTreeStore, TreeNode, Version, WriteableTreeStore,
+};
+use crate::hash_tree::{put_at_next_version, SubstateHashChange};
 use radix_engine::kernel::interpreters::ScryptoInterpreter;
+use radix_engine::state_manager::{CommitReceipt, StateDiff};
 use radix_engine::system::node_substates::PersistedSubstate;
 use radix_engine::types::*;
 use radix_engine::{ledger::*, wasm::WasmEngine};
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::data::scrypto::ScryptoDecode;
-use rocksdb::{DBWithThreadMode, Direction, IteratorMode, SingleThreaded, DB};
+use rocksdb::{
+    ColumnFamily, DBWithThreadMode, Direction, IteratorMode, Options, SingleThreaded, WriteBatch,
+    DB,
+};
+
+/// Substates live in the default column family (as they did before state hashing was added).
+const TREE_NODES_CF: &str = "tree_nodes";
+/// Keys of tree nodes which became stale, prefixed by the version at which they became stale.
+const STALE_TREE_NODES_CF: &str = "stale_tree_nodes";
+const STATE_HASH_CF: &str = "state_hash";
+/// Every value put for a substate, keyed by the substate ID followed by the state version.
+/// Versions which are no longer needed are only deleted by [`RadixEngineDB::prune_substate_versions`].
+const SUBSTATE_VERSIONS_CF: &str = "substate_versions";
+const STATE_HASH_KEY: &[u8] = b"state_hash";
+
+/// The version and root hash of the Jellyfish tree over all committed substates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+struct StateHash {
+    version: Version,
+    root_hash: Hash,
+}
 
 pub struct RadixEngineDB {
     db: DBWithThreadMode<SingleThreaded>,
+    state_hash: StateHash,
+    /// Writes which are buffered while a transaction is committed, so that its substates, tree
+    /// nodes and state hash are persisted atomically (see [`Self::commit`]).
+    pending_writes: Option<WriteBatch>,
 }
 
 impl RadixEngineDB {
     pub fn new(root: PathBuf) -> Self {
-        let db = DB::open_default(root.as_path()).unwrap();
-        Self { db }
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let db = DB::open_cf(
+            &options,
+            root.as_path(),
+            [
+                TREE_NODES_CF,
+                STALE_TREE_NODES_CF,
+                STATE_HASH_CF,
+                SUBSTATE_VERSIONS_CF,
+            ],
+        )
+        .unwrap();
+        let state_hash = db
+            .get_cf(db.cf_handle(STATE_HASH_CF).unwrap(), STATE_HASH_KEY)
+            .unwrap()
+            .map(|bytes| scrypto_decode(&bytes).expect("Could not decode persisted state hash"))
+            .unwrap_or(StateHash {
+                version: 0,
+                root_hash: Hash([0; Hash::LENGTH]),
+            });
+        Self {
+            db,
+            state_hash,
+            pending_writes: None,
+        }
     }
 
     pub fn with_bootstrap<W: WasmEngine>(
@@ -24,10 +78,164 @@ impl RadixEngineDB {
         scrypto_interpreter: &ScryptoInterpreter<W>,
     ) -> Self {
         let mut substate_store = Self::new(root);
-        bootstrap(&mut substate_store, scrypto_interpreter);
+        substate_store.with_write_batch(|db| {
+            if let Some(receipt) = bootstrap(db, scrypto_interpreter) {
+                let genesis_outputs = receipt
+                    .expect_commit(true)
+                    .state_updates
+                    .up_substates
+                    .iter()
+                    .map(|(substate_id, output_value)| OutputId {
+                        substate_id: substate_id.clone(),
+                        substate_hash: hash(
+                            scrypto_encode(&output_value.substate)
+                                .expect("Could not encode genesis substate"),
+                        ),
+                        version: output_value.version,
+                    })
+                    .collect();
+                db.update_state_hash(genesis_outputs);
+            }
+        });
         substate_store
     }
 
+    /// Returns the number of transactions which were included in the state hash so far.
+    pub fn get_state_version(&self) -> Version {
+        self.state_hash.version
+    }
+
+    /// Returns the root hash of the Jellyfish tree at the current state version.
+    pub fn get_state_hash(&self) -> Hash {
+        self.state_hash.root_hash
+    }
+
+    /// Commits the state updates of a transaction, and puts its outputs into the state hash tree.
+    /// All of it is written in a single batch, so a failure cannot leave a partial commit behind.
+    pub fn commit(&mut self, state_updates: &StateDiff) -> CommitReceipt {
+        self.with_write_batch(|db| {
+            let commit_receipt = state_updates.commit(db);
+            db.update_state_hash(commit_receipt.outputs.clone());
+            commit_receipt
+        })
+    }
+
+    /// Puts the outputs of a committed transaction into the Jellyfish tree (at the next state
+    /// version), and returns the new state hash.
+    ///
+    /// This mirrors the `StateHashSupport` of the `TestRunner`, so a ledger which commits the same
+    /// transactions (including genesis, see [`Self::with_bootstrap`]) ends up with the same hash.
+    pub fn update_state_hash(&mut self, transaction_outputs: Vec<OutputId>) -> Hash {
+        self.with_write_batch(|db| db.put_state_hash(transaction_outputs))
+    }
+
+    fn put_state_hash(&mut self, transaction_outputs: Vec<OutputId>) -> Hash {
+        let hash_changes = transaction_outputs
+            .into_iter()
+            .map(|output_id| {
+                SubstateHashChange::new(output_id.substate_id, Some(output_id.substate_hash))
+            })
+            .collect();
+        let current_version = Some(self.state_hash.version).filter(|version| *version > 0);
+        let root_hash = put_at_next_version(self, current_version, hash_changes);
+        self.state_hash = StateHash {
+            version: self.state_hash.version + 1,
+            root_hash,
+        };
+        let state_hash = scrypto_encode(&self.state_hash).expect("Could not encode state hash");
+        self.put_cf(STATE_HASH_CF, STATE_HASH_KEY, &state_hash);
+        root_hash
+    }
+
+    /// Runs `f` with all writes buffered in a batch, which is written once `f` returns.
+    /// Nested calls share the batch of the outermost one.
+    fn with_write_batch<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        if self.pending_writes.is_some() {
+            return f(self);
+        }
+        self.pending_writes = Some(WriteBatch::default());
+        let result = f(self);
+        let batch = self.pending_writes.take().unwrap();
+        self.db.write(batch).unwrap();
+        result
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) {
+        match &mut self.pending_writes {
+            Some(batch) => batch.put(key, value),
+            None => self.db.put(key, value).unwrap(),
+        }
+    }
+
+    fn put_cf(&mut self, cf: &str, key: &[u8], value: &[u8]) {
+        let cf = self.db.cf_handle(cf).unwrap();
+        match &mut self.pending_writes {
+            Some(batch) => batch.put_cf(cf, key, value),
+            None => self.db.put_cf(cf, key, value).unwrap(),
+        }
+    }
+
+    /// Deletes the tree nodes which are no longer needed to read the trees of the most recent
+    /// `retained_versions` state versions (the current version is always retained).
+    /// Returns the number of deleted nodes.
+    pub fn prune_stale_tree_nodes(&mut self, retained_versions: Version) -> usize {
+        let last_prunable_version =
+            (self.state_hash.version + 1).saturating_sub(retained_versions.max(1));
+        let mut batch = WriteBatch::default();
+        let mut pruned_count = 0;
+        let mut iter = self
+            .db
+            .iterator_cf(self.cf(STALE_TREE_NODES_CF), IteratorMode::Start);
+        while let Some(kv) = iter.next() {
+            let (key, _value) = kv.unwrap();
+            let (version_bytes, node_key) = key.split_at(8);
+            let stale_since_version = Version::from_be_bytes(version_bytes.try_into().unwrap());
+            if stale_since_version > last_prunable_version {
+                break;
+            }
+            batch.delete_cf(self.cf(TREE_NODES_CF), node_key);
+            batch.delete_cf(self.cf(STALE_TREE_NODES_CF), &key);
+            pruned_count += 1;
+        }
+        self.db.write(batch).unwrap();
+        pruned_count
+    }
+
+    /// Deletes the substate versions which are no longer needed to read substates at the most
+    /// recent `retained_versions` state versions (the current version is always retained).
+    /// Afterwards, reads at earlier state versions may not find the substates anymore.
+    /// Returns the number of deleted substate versions.
+    pub fn prune_substate_versions(&mut self, retained_versions: Version) -> usize {
+        let first_retained_version =
+            (self.state_hash.version + 1).saturating_sub(retained_versions.max(1));
+        let version_len = first_retained_version.to_be_bytes().len();
+        let mut batch = WriteBatch::default();
+        let mut pruned_count = 0;
+        let mut previous_versioned_key: Option<Box<[u8]>> = None;
+        for kv in self
+            .db
+            .iterator_cf(self.cf(SUBSTATE_VERSIONS_CF), IteratorMode::Start)
+        {
+            let (versioned_key, _value) = kv.unwrap();
+            let (key, version_bytes) = versioned_key.split_at(versioned_key.len() - version_len);
+            let version = StateVersion::from_be_bytes(version_bytes.try_into().unwrap());
+            // The versions of a substate are adjacent and in ascending order, so a version is not
+            // needed anymore if the next one was put at or before the first retained version
+            if let Some(previous_versioned_key) = previous_versioned_key {
+                if version <= first_retained_version
+                    && previous_versioned_key.len() == versioned_key.len()
+                    && previous_versioned_key.starts_with(key)
+                {
+                    batch.delete_cf(self.cf(SUBSTATE_VERSIONS_CF), previous_versioned_key);
+                    pruned_count += 1;
+                }
+            }
+            previous_versioned_key = Some(versioned_key);
+        }
+        self.db.write(batch).unwrap();
+        pruned_count
+    }
+
     pub fn list_packages(&self) -> Vec<PackageAddress> {
         let start = &scrypto_encode(&SubstateId(
             RENodeId::GlobalObject(PackageAddress::Normal([0; 26]).into()),
@@ -157,13 +365,43 @@ impl RadixEngineDB {
             .unwrap()
     }
 
-    fn write(&self, substate_id: SubstateId, value: Vec<u8>) {
-        self.db
-            .put(
-                scrypto_encode(&substate_id).expect("Could not encode substate id"),
-                value,
-            )
-            .unwrap();
+    fn write(&mut self, substate_id: SubstateId, value: Vec<u8>) {
+        let key = scrypto_encode(&substate_id).expect("Could not encode substate id");
+        // Substates put before the current transaction is committed belong to the next version
+        let state_version = self.state_hash.version + 1;
+        self.with_write_batch(|db| {
+            db.put_cf(
+                SUBSTATE_VERSIONS_CF,
+                &[&key[..], &state_version.to_be_bytes()[..]].concat(),
+                &value,
+            );
+            db.put(&key, &value);
+        });
+    }
+
+    fn read_at_version(
+        &self,
+        substate_id: &SubstateId,
+        state_version: StateVersion,
+    ) -> Option<Vec<u8>> {
+        let key = scrypto_encode(substate_id).expect("Could not encode substate id");
+        let seek_key = [&key[..], &state_version.to_be_bytes()[..]].concat();
+        let mut iter = self.db.iterator_cf(
+            self.cf(SUBSTATE_VERSIONS_CF),
+            IteratorMode::From(&seek_key, Direction::Reverse),
+        );
+        // SBOR encoding is prefix-free, so the closest preceding entry either belongs to this
+        // substate or there is no such version
+        let (versioned_key, value) = iter.next()?.unwrap();
+        if versioned_key.len() == seek_key.len() && versioned_key.starts_with(&key) {
+            Some(value.to_vec())
+        } else {
+            None
+        }
+    }
+
+    fn cf(&self, name: &str) -> &ColumnFamily {
+        self.db.cf_handle(name).unwrap()
     }
 }
 
@@ -198,6 +436,28 @@ impl ReadableSubstateStore for RadixEngineDB {
         self.read(substate_id)
             .map(|b| scrypto_decode(&b).expect("Could not decode persisted substate"))
     }
+
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let prefix = encode_kv_store_entries_prefix(kv_store_id);
+        let start = encode_kv_store_entry_id(kv_store_id, start_key.unwrap_or_default());
+        let entries = self
+            .db
+            .iterator(IteratorMode::From(&start, Direction::Forward))
+            .map(|kv| kv.unwrap())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                (
+                    decode_kv_store_entry_key(&key),
+                    scrypto_decode(&value).expect("Could not decode persisted substate"),
+                )
+            });
+        take_kv_store_entries(entries, limit)
+    }
 }
 
 impl WriteableSubstateStore for RadixEngineDB {
@@ -208,3 +468,105 @@ impl WriteableSubstateStore for RadixEngineDB {
         );
     }
 }
+
+/// Note: substates committed by a database created before the substate versions were retained have
+/// no history, so they cannot be read at any version. The same holds for versions deleted by
+/// [`RadixEngineDB::prune_substate_versions`].
+impl ReadableVersionedSubstateStore for RadixEngineDB {
+    fn get_state_version(&self) -> StateVersion {
+        self.state_hash.version
+    }
+
+    fn get_substate_at_version(
+        &self,
+        substate_id: &SubstateId,
+        state_version: StateVersion,
+    ) -> Option<OutputValue> {
+        self.read_at_version(substate_id, state_version)
+            .map(|b| scrypto_decode(&b).expect("Could not decode persisted substate"))
+    }
+
+    fn scan_kv_store_entries_at_version(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+        state_version: StateVersion,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let prefix = encode_kv_store_entries_prefix(kv_store_id);
+        let start = encode_kv_store_entry_id(kv_store_id, start_key.unwrap_or_default());
+        let version_len = state_version.to_be_bytes().len();
+        let mut versions = self
+            .db
+            .iterator_cf(
+                self.cf(SUBSTATE_VERSIONS_CF),
+                IteratorMode::From(&start, Direction::Forward),
+            )
+            .map(|kv| kv.unwrap())
+            .take_while(|(versioned_key, _)| versioned_key.starts_with(&prefix))
+            .map(|(versioned_key, value)| {
+                let (key, version) = versioned_key.split_at(versioned_key.len() - version_len);
+                let version = StateVersion::from_be_bytes(version.try_into().unwrap());
+                (key.to_vec(), version, value)
+            })
+            .peekable();
+
+        // The versions of a substate are adjacent and in ascending order, so the last one seen
+        // at or before the given version is the one to read
+        let entries = std::iter::from_fn(|| {
+            let (key, version, value) = versions.next()?;
+            let mut latest_value = (version <= state_version).then_some(value);
+            while let Some((_, version, value)) =
+                versions.next_if(|(next_key, _, _)| next_key == &key)
+            {
+                if version <= state_version {
+                    latest_value = Some(value);
+                }
+            }
+            Some((key, latest_value))
+        })
+        .filter_map(|(key, latest_value)| {
+            latest_value.map(|value| {
+                (
+                    decode_kv_store_entry_key(&key),
+                    scrypto_decode(&value).expect("Could not decode persisted substate"),
+                )
+            })
+        });
+        take_kv_store_entries(entries, limit)
+    }
+}
+
+impl<P: Payload> ReadableTreeStore<P> for RadixEngineDB {
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode<P>> {
+        self.db
+            .get_cf(self.cf(TREE_NODES_CF), encode_key(key))
+            .unwrap()
+            .map(|bytes| scrypto_decode(&bytes).expect("Could not decode persisted tree node"))
+    }
+}
+
+impl<P: Payload> WriteableTreeStore<P> for RadixEngineDB {
+    fn insert_node(&mut self, key: NodeKey, node: TreeNode<P>) {
+        self.put_cf(
+            TREE_NODES_CF,
+            &encode_key(&key),
+            &scrypto_encode(&node).expect("Could not encode tree node for persistence"),
+        );
+    }
+
+    /// Records the node as stale since the state version which is currently being put, so that
+    /// [`RadixEngineDB::prune_stale_tree_nodes`] knows when it is safe to delete it.
+    fn record_stale_node(&mut self, key: NodeKey) {
+        let stale_since_version = self.state_hash.version + 1;
+        self.put_cf(
+            STALE_TREE_NODES_CF,
+            &[
+                &stale_since_version.to_be_bytes()[..],
+                &encode_key(&key)[..],
+            ]
+            .concat(),
+            b"",
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use radix_engine::blueprints::account::AccountError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
 use radix_engine::system::kernel_modules::execution_trace::ResourceChange;
 use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -182,6 +185,230 @@ fn assert_resource_changes_for_transfer(
             && r.amount == Decimal::from(transfer_amount)));
 }
 
+fn set_deposit_rule(
+    test_runner: &mut TestRunner,
+    public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+    rule: AccountDepositRule,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .call_method(
+            account,
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT,
+            to_manifest_value(&AccountSetDepositRuleInput { rule }),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+}
+
+fn is_deposit_rejected_error(e: &RuntimeError, resource: ResourceAddress) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::AccountError(
+            AccountError::DepositRejected { resource_address }
+        )) if *resource_address == resource
+    )
+}
+
+#[test]
+fn cannot_set_deposit_rule_of_other_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (_, _, other_account) = test_runner.new_account(false);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .call_method(
+            other_account,
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT,
+            to_manifest_value(&AccountSetDepositRuleInput {
+                rule: AccountDepositRule::AcceptExisting,
+            }),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+fn deposit_rule_is_enforced_internal(is_virtual: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(is_virtual);
+    let (other_public_key, _, other_account) = test_runner.new_account(is_virtual);
+    let spam = test_runner.create_fungible_resource(100.into(), 0, account);
+    let listed = test_runner.create_fungible_resource(100.into(), 0, account);
+    set_deposit_rule(
+        &mut test_runner,
+        other_public_key,
+        other_account,
+        AccountDepositRule::AllowList(btreeset!(listed)),
+    );
+
+    for (resource, accepted) in [(spam, false), (listed, true), (RADIX_TOKEN, false)] {
+        // Act
+        let manifest = ManifestBuilder::new()
+            .lock_fee_and_withdraw(account, 10.into(), resource, 1.into())
+            .call_method(
+                other_account,
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+                manifest_args!(ManifestExpression::EntireWorktop),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        );
+
+        // Assert
+        if accepted {
+            receipt.expect_commit_success();
+        } else {
+            receipt.expect_specific_failure(|e| is_deposit_rejected_error(e, resource));
+        }
+    }
+}
+
+#[test]
+fn deposit_rule_is_enforced_on_allocated_account() {
+    deposit_rule_is_enforced_internal(false);
+}
+
+#[test]
+fn deposit_rule_is_enforced_on_virtual_account() {
+    deposit_rule_is_enforced_internal(true);
+}
+
+#[test]
+fn deposit_batch_ignores_deposit_rule() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (other_public_key, _, other_account) = test_runner.new_account(false);
+    let spam = test_runner.create_fungible_resource(100.into(), 0, account);
+    set_deposit_rule(
+        &mut test_runner,
+        other_public_key,
+        other_account,
+        AccountDepositRule::DenyList(btreeset!(spam)),
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account, 10.into(), spam, 1.into())
+        .call_method(
+            other_account,
+            ACCOUNT_DEPOSIT_BATCH_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(other_account, spam),
+        Some(1.into())
+    );
+}
+
+#[test]
+fn accept_existing_deposit_rule_rejects_unknown_resources() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (other_public_key, _, other_account) = test_runner.new_account(false);
+    let spam = test_runner.create_fungible_resource(100.into(), 0, account);
+    set_deposit_rule(
+        &mut test_runner,
+        other_public_key,
+        other_account,
+        AccountDepositRule::AcceptExisting,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, 1.into())
+        .withdraw_from_account(account, spam, 1.into())
+        .call_method(
+            other_account,
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| is_deposit_rejected_error(e, spam));
+}
+
+#[test]
+fn try_deposit_batch_or_refund_returns_rejected_buckets_to_worktop() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let (other_public_key, _, other_account) = test_runner.new_account(false);
+    let spam = test_runner.create_fungible_resource(100.into(), 0, account);
+    set_deposit_rule(
+        &mut test_runner,
+        other_public_key,
+        other_account,
+        AccountDepositRule::DenyList(btreeset!(spam)),
+    );
+    let other_account_balance = test_runner
+        .account_balance(other_account, RADIX_TOKEN)
+        .unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, 1.into())
+        .withdraw_from_account(account, spam, 1.into())
+        .call_method(
+            other_account,
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .assert_worktop_contains_by_amount(1.into(), spam)
+        .call_method(
+            account,
+            ACCOUNT_DEPOSIT_BATCH_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(other_account, RADIX_TOKEN),
+        Some(other_account_balance + 1)
+    );
+    assert_eq!(test_runner.account_balance(other_account, spam), None);
+    assert_eq!(test_runner.account_balance(account, spam), Some(100.into()));
+}
+
 fn aggregate_resource_changes(
     resource_changes: IndexMap<usize, Vec<ResourceChange>>,
 ) -> Vec<ResourceChange> {
@@ -119,6 +119,17 @@ mod bucket_test {
             (bucket, x)
         }
 
+        pub fn take_from_bucket_advanced(
+            mut bucket: Bucket,
+            amount: Decimal,
+            rounding_mode: RoundingMode,
+        ) -> (Bucket, Decimal) {
+            let x = bucket.take_advanced(amount, rounding_mode);
+            let taken_amount = x.amount();
+            bucket.put(x);
+            (bucket, taken_amount)
+        }
+
         pub fn create_empty_bucket_fungible() -> Bucket {
             Bucket::new(RADIX_TOKEN)
         }
@@ -0,0 +1,50 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod crypto_utils {
+    struct CryptoUtilsTest {}
+
+    impl CryptoUtilsTest {
+        pub fn blake2b_256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::blake2b_256_hash(data)
+        }
+
+        pub fn sha256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::sha256_hash(data)
+        }
+
+        pub fn keccak256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::keccak256_hash(data)
+        }
+
+        pub fn verify_ecdsa_secp256k1(
+            message_hash: Hash,
+            public_key: EcdsaSecp256k1PublicKey,
+            signature: EcdsaSecp256k1Signature,
+        ) -> bool {
+            CryptoUtils::verify_ecdsa_secp256k1(message_hash, public_key, signature)
+        }
+
+        pub fn recover_ecdsa_secp256k1(
+            message_hash: Hash,
+            signature: EcdsaSecp256k1Signature,
+        ) -> Option<EcdsaSecp256k1PublicKey> {
+            CryptoUtils::recover_ecdsa_secp256k1(message_hash, signature)
+        }
+
+        pub fn verify_eddsa_ed25519(
+            message: Vec<u8>,
+            public_key: EddsaEd25519PublicKey,
+            signature: EddsaEd25519Signature,
+        ) -> bool {
+            CryptoUtils::verify_eddsa_ed25519(message, public_key, signature)
+        }
+
+        pub fn verify_bls12381_aggregate(
+            messages: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+            signature: Bls12381G2Signature,
+        ) -> bool {
+            CryptoUtils::verify_bls12381_aggregate(messages, signature)
+        }
+    }
+}
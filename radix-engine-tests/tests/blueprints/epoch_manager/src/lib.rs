@@ -17,7 +17,12 @@ mod epoch_manager_test {
                 .call_method(
                     RENodeId::GlobalObject(epoch_manager.into()),
                     EPOCH_MANAGER_NEXT_ROUND_IDENT,
-                    scrypto_encode(&EpochManagerNextRoundInput { round }).unwrap(),
+                    scrypto_encode(&EpochManagerNextRoundInput {
+                        round,
+                        proposer_timestamp_ms: 0,
+                        leader_proposal_history: LeaderProposalHistory::default(),
+                    })
+                    .unwrap(),
                 )
                 .unwrap();
         }
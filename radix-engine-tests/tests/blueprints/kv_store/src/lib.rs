@@ -2,5 +2,6 @@ pub mod cyclic_map;
 pub mod kv_store;
 pub mod multiple_reads;
 pub mod precommitted;
+pub mod range;
 pub mod ref_check;
 pub mod super_kv_store;
@@ -0,0 +1,63 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod key_value_store_range {
+    struct KeyValueStoreRange {
+        map: KeyValueStore<u8, String>,
+        vaults: KeyValueStore<u8, Vault>,
+    }
+
+    impl KeyValueStoreRange {
+        pub fn new(size: u8) -> ComponentAddress {
+            let map = KeyValueStore::new();
+            for i in 0..size {
+                map.insert(i, i.to_string());
+            }
+            let vaults = KeyValueStore::new();
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .mint_initial_supply(1);
+            vaults.insert(0u8, Vault::with_bucket(bucket));
+            KeyValueStoreRange { map, vaults }.instantiate().globalize()
+        }
+
+        pub fn remove_and_range_in_memory() {
+            let map = KeyValueStore::new();
+            map.insert(2u8, "2".to_owned());
+            map.insert(0u8, "0".to_owned());
+            map.insert(1u8, "1".to_owned());
+
+            assert_eq!(map.remove(&1u8), Some("1".to_owned()));
+            assert_eq!(map.remove(&1u8), None);
+            assert_eq!(
+                map.range(None, 10),
+                vec![(0u8, "0".to_owned()), (2u8, "2".to_owned())]
+            );
+            assert_eq!(map.range(Some(&1u8), 10), vec![(2u8, "2".to_owned())]);
+
+            KeyValueStoreRange {
+                map,
+                vaults: KeyValueStore::new(),
+            }
+            .instantiate()
+            .globalize();
+        }
+
+        pub fn remove(&mut self, key: u8) -> Option<String> {
+            self.map.remove(&key)
+        }
+
+        pub fn range(&self, start_key: Option<u8>, limit: u32) -> Vec<(u8, String)> {
+            self.map.range(start_key.as_ref(), limit)
+        }
+
+        pub fn remove_and_range(&mut self, key: u8, limit: u32) -> Vec<(u8, String)> {
+            self.map.remove(&key);
+            self.map.range(None, limit)
+        }
+
+        pub fn remove_vault(&mut self) {
+            self.vaults.remove(&0u8);
+        }
+    }
+}
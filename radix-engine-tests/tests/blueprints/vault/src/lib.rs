@@ -1,2 +1,3 @@
 pub mod non_existent_vault;
 pub mod vault;
+pub mod vault_burn;
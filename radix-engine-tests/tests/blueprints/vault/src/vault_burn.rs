@@ -0,0 +1,26 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod vault_burn {
+    struct VaultBurn {
+        vault: Vault,
+    }
+
+    impl VaultBurn {
+        pub fn new(bucket: Bucket) -> ComponentAddress {
+            VaultBurn {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn burn(&mut self, amount: Decimal) {
+            self.vault.burn(amount);
+        }
+
+        pub fn burn_non_fungibles(&mut self, non_fungible_local_ids: BTreeSet<NonFungibleLocalId>) {
+            self.vault.burn_non_fungibles(&non_fungible_local_ids);
+        }
+    }
+}
@@ -160,6 +160,48 @@ fn test_take_with_invalid_granularity() {
     });
 }
 
+#[test]
+fn test_take_advanced_with_invalid_granularity_rounds_amount() {
+    test_take_advanced_from_bucket(RoundingMode::TowardsZero, dec!("1.12"));
+    test_take_advanced_from_bucket(RoundingMode::AwayFromZero, dec!("1.13"));
+}
+
+fn test_take_advanced_from_bucket(rounding_mode: RoundingMode, expected_amount: Decimal) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 2, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/bucket");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, resource_address, 100.into())
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            let bucket = bucket_id;
+            builder.call_function(
+                package_address,
+                "BucketTest",
+                "take_from_bucket_advanced",
+                manifest_args!(bucket, dec!("1.123"), rounding_mode),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let (_, taken_amount): (Bucket, Decimal) = receipt.expect_commit_success().output(3);
+    assert_eq!(taken_amount, expected_amount);
+}
+
 #[test]
 fn test_take_with_negative_amount() {
     // Arrange
@@ -0,0 +1,236 @@
+use radix_engine::blueprints::resource::VaultError;
+use radix_engine::errors::{ApplicationError, ModuleError, RuntimeError};
+use radix_engine::system::kernel_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn create_vault_burn_component(
+    test_runner: &mut TestRunner,
+    public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account(account, resource_address, amount)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "VaultBurn",
+                "new",
+                manifest_args!(bucket_id),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+#[test]
+fn can_burn_non_fungibles_by_id_with_burn_auth() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let (auth_resource_address, resource_address) =
+        test_runner.create_restricted_burn_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, auth_resource_address)
+        .burn_non_fungibles(
+            resource_address,
+            BTreeSet::from([NonFungibleLocalId::integer(1)]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_burn_non_fungibles_by_id_without_burn_auth() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let (_, resource_address) = test_runner.create_restricted_burn_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .burn_non_fungibles(
+            resource_address,
+            BTreeSet::from([NonFungibleLocalId::integer(1)]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn can_burn_non_fungibles_from_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let resource_address = test_runner.create_burnable_non_fungible_resource(account);
+    let component_address = create_vault_burn_component(
+        &mut test_runner,
+        public_key,
+        account,
+        resource_address,
+        3.into(),
+    );
+    let vault_id = test_runner.get_component_vaults(component_address, resource_address)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(
+            component_address,
+            "burn_non_fungibles",
+            manifest_args!(BTreeSet::from([NonFungibleLocalId::integer(1)])),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.inspect_non_fungible_vault(vault_id),
+        Some(BTreeSet::from([
+            NonFungibleLocalId::integer(2),
+            NonFungibleLocalId::integer(3)
+        ]))
+    );
+}
+
+#[test]
+fn can_burn_amount_of_non_fungibles_from_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let resource_address = test_runner.create_burnable_non_fungible_resource(account);
+    let component_address = create_vault_burn_component(
+        &mut test_runner,
+        public_key,
+        account,
+        resource_address,
+        3.into(),
+    );
+    let vault_id = test_runner.get_component_vaults(component_address, resource_address)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(component_address, "burn", manifest_args!(Decimal::from(2)))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .inspect_non_fungible_vault(vault_id)
+            .map(|ids| ids.len()),
+        Some(1)
+    );
+}
+
+#[test]
+fn cannot_burn_from_vault_without_burn_auth() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let (_, resource_address) = test_runner.create_restricted_burn_token(account);
+    let component_address = create_vault_burn_component(
+        &mut test_runner,
+        public_key,
+        account,
+        resource_address,
+        Decimal::one(),
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(component_address, "burn", manifest_args!(Decimal::one()))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn cannot_burn_from_vault_frozen_for_burn() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let resource_address = test_runner.create_burnable_non_fungible_resource(account);
+    let component_address = create_vault_burn_component(
+        &mut test_runner,
+        public_key,
+        account,
+        resource_address,
+        3.into(),
+    );
+    let vault_id = test_runner.get_component_vaults(component_address, resource_address)[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(vault_id, VaultFreezeFlags::BURN)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(
+            component_address,
+            "burn_non_fungibles",
+            manifest_args!(BTreeSet::from([NonFungibleLocalId::integer(1)])),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultIsFrozen))
+        )
+    });
+}
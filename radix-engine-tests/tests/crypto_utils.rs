@@ -0,0 +1,174 @@
+use radix_engine::system::kernel_modules::costing::{CostingReason, FeeTable};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::eddsa_ed25519::EddsaEd25519PrivateKey;
+
+fn call_crypto_utils<T: ScryptoDecode>(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+    function_name: &str,
+    args: ManifestValue,
+) -> T {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "CryptoUtilsTest", function_name, args)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    receipt.expect_commit(true).output(1)
+}
+
+#[test]
+fn test_hash_functions_match_native_implementations() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto_utils");
+    let data = b"Hello Radix".to_vec();
+
+    // Act
+    let blake2b: Hash = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "blake2b_256_hash",
+        manifest_args!(data.clone()),
+    );
+    let sha256: Hash = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "sha256_hash",
+        manifest_args!(data.clone()),
+    );
+    let keccak256: Hash = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "keccak256_hash",
+        manifest_args!(data.clone()),
+    );
+
+    // Assert
+    assert_eq!(blake2b, blake2b_256_hash(&data));
+    assert_eq!(sha256, sha256_hash(&data));
+    assert_eq!(keccak256, keccak256_hash(&data));
+}
+
+#[test]
+fn test_verify_and_recover_ecdsa_secp256k1() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto_utils");
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let other_public_key = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap().public_key();
+    let message_hash = hash("Hello Radix");
+    let signature = private_key.sign(&message_hash);
+
+    // Act
+    let valid: bool = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "verify_ecdsa_secp256k1",
+        manifest_args!(message_hash, private_key.public_key(), signature),
+    );
+    let invalid: bool = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "verify_ecdsa_secp256k1",
+        manifest_args!(message_hash, other_public_key, signature),
+    );
+    let recovered: Option<EcdsaSecp256k1PublicKey> = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "recover_ecdsa_secp256k1",
+        manifest_args!(message_hash, signature),
+    );
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+    assert_eq!(recovered, Some(private_key.public_key()));
+}
+
+#[test]
+fn test_verify_eddsa_ed25519() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto_utils");
+    let private_key = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+    let message_hash = hash("Hello Radix");
+    let signature = private_key.sign(&message_hash);
+
+    // Act
+    let valid: bool = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "verify_eddsa_ed25519",
+        manifest_args!(message_hash.to_vec(), private_key.public_key(), signature),
+    );
+    let invalid: bool = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "verify_eddsa_ed25519",
+        manifest_args!(b"Hello".to_vec(), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn test_verify_bls12381_aggregate_rejects_empty_messages() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto_utils");
+    let messages: Vec<(Bls12381G1PublicKey, Vec<u8>)> = vec![];
+
+    // Act
+    let valid: bool = call_crypto_utils(
+        &mut test_runner,
+        package_address,
+        "verify_bls12381_aggregate",
+        manifest_args!(messages, Bls12381G2Signature([0u8; 96])),
+    );
+
+    // Assert
+    assert!(!valid);
+}
+
+#[test]
+fn test_verify_bls12381_aggregate_cost_grows_with_message_size() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto_utils");
+    let mut verify = |message_size: usize| {
+        let messages = vec![(
+            Bls12381G1PublicKey([0u8; Bls12381G1PublicKey::LENGTH]),
+            vec![0u8; message_size],
+        )];
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(
+                package_address,
+                "CryptoUtilsTest",
+                "verify_bls12381_aggregate",
+                manifest_args!(messages, Bls12381G2Signature([0u8; 96])),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+        receipt
+            .expect_commit(true)
+            .fee_summary
+            .execution_cost_breakdown[&CostingReason::CryptoUtils]
+    };
+
+    // Act
+    let short_message_cost = verify(10);
+    let long_message_cost = verify(1010);
+
+    // Assert
+    assert_eq!(
+        long_message_cost - short_message_cost,
+        1000 * FeeTable::new().verify_bls12381_aggregate_per_byte
+    );
+}
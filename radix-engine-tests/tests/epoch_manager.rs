@@ -1,7 +1,11 @@
-use radix_engine::blueprints::epoch_manager::{Validator, ValidatorError};
+use radix_engine::blueprints::epoch_manager::{
+    EpochManagerConfigError, EpochManagerError, Validator, ValidatorEmissionAppliedEvent,
+    ValidatorError,
+};
 use radix_engine::errors::{ApplicationError, ModuleError, RuntimeError};
 use radix_engine::ledger::create_genesis;
 use radix_engine::system::kernel_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::blueprints::epoch_manager::*;
@@ -74,8 +78,11 @@ fn next_round_with_validator_auth_succeeds() {
         BTreeMap::new(),
         BTreeMap::new(),
         1u64,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -85,6 +92,8 @@ fn next_round_with_validator_auth_succeeds() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch - 1,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -112,8 +121,11 @@ fn next_epoch_with_validator_auth_succeeds() {
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -123,6 +135,8 @@ fn next_epoch_with_validator_auth_succeeds() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -157,8 +171,11 @@ fn register_validator_with_auth_succeeds() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -199,8 +216,11 @@ fn register_validator_without_auth_fails() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -234,8 +254,11 @@ fn unregister_validator_with_auth_succeeds() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -276,8 +299,11 @@ fn unregister_validator_without_auth_fails() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -310,8 +336,11 @@ fn test_disabled_delegated_stake(owner: bool, expect_success: bool) {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&pub_key);
@@ -387,8 +416,11 @@ fn registered_validator_with_no_stake_does_not_become_part_of_validator_on_epoch
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let (pub_key, validator_address) = test_runner.new_validator();
@@ -408,6 +440,8 @@ fn registered_validator_with_no_stake_does_not_become_part_of_validator_on_epoch
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -437,8 +471,11 @@ fn registered_validator_with_stake_does_become_part_of_validator_on_epoch_change
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let (pub_key, _, account_address) = test_runner.new_account(false);
@@ -468,6 +505,8 @@ fn registered_validator_with_stake_does_become_part_of_validator_on_epoch_change
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -513,8 +552,11 @@ fn unregistered_validator_gets_removed_on_epoch_change() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -535,6 +577,8 @@ fn unregistered_validator_gets_removed_on_epoch_change() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -574,8 +618,11 @@ fn updated_validator_keys_gets_updated_on_epoch_change() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -603,6 +650,8 @@ fn updated_validator_keys_gets_updated_on_epoch_change() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -644,8 +693,11 @@ fn cannot_claim_unstake_immediately() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -705,8 +757,11 @@ fn can_claim_unstake_after_epochs() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -775,8 +830,11 @@ fn unstaked_validator_gets_less_stake_on_epoch_change() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -809,6 +867,8 @@ fn unstaked_validator_gets_less_stake_on_epoch_change() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -853,8 +913,7 @@ fn epoch_manager_create_should_fail_with_supervisor_privilege() {
             EPOCH_MANAGER.to_array_without_entity_id(),
             validator_set,
             1u64,
-            1u64,
-            1u64
+            EpochManagerConfig::default()
         ),
     }];
     let blobs = vec![];
@@ -894,8 +953,7 @@ fn epoch_manager_create_should_succeed_with_system_privilege() {
             EPOCH_MANAGER.to_array_without_entity_id(),
             validator_set,
             1u64,
-            1u64,
-            1u64
+            EpochManagerConfig::default()
         ),
     }];
     let blobs = vec![];
@@ -912,3 +970,1055 @@ fn epoch_manager_create_should_succeed_with_system_privilege() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn validators_receive_emissions_pro_rata_to_stake_on_epoch_change() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let total_emission_xrd_per_epoch = Decimal::from(100);
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let account_pub_key = EcdsaSecp256k1PrivateKey::from_u64(1u64)
+        .unwrap()
+        .public_key();
+    let account_with_lp = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(validator1_pub_key, (Decimal::from(10), account_with_lp));
+    validator_set_and_stake_owners.insert(validator2_pub_key, (Decimal::from(30), account_with_lp));
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            total_emission_xrd_per_epoch,
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    let result = receipt.expect_commit(true);
+    let next_epoch = result.next_epoch().expect("Should have next epoch");
+    assert_eq!(
+        next_epoch.0.get(&validator1_address).unwrap().stake,
+        Decimal::from(35)
+    );
+    assert_eq!(
+        next_epoch.0.get(&validator2_address).unwrap().stake,
+        Decimal::from(105)
+    );
+    let validator1_substate = test_runner.get_validator_info(validator1_address);
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator1_substate.stake_xrd_vault_id),
+        Some(Decimal::from(35))
+    );
+
+    let emission_events: Vec<ValidatorEmissionAppliedEvent> = result
+        .application_events
+        .iter()
+        .filter(|(event_identifier, _)| {
+            test_runner.is_event_name_equal::<ValidatorEmissionAppliedEvent>(event_identifier)
+        })
+        .map(|(_, event_data)| scrypto_decode(event_data).unwrap())
+        .collect();
+    assert_eq!(emission_events.len(), 2);
+    let added_xrd: Decimal = emission_events
+        .iter()
+        .map(|event| event.stake_pool_added_xrd)
+        .sum();
+    assert_eq!(added_xrd, total_emission_xrd_per_epoch);
+    assert!(emission_events
+        .iter()
+        .all(|event| event.epoch == initial_epoch));
+}
+
+#[test]
+fn stake_units_appreciate_with_emissions() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let validator_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let account_pub_key = EcdsaSecp256k1PrivateKey::from_u64(1u64)
+        .unwrap()
+        .public_key();
+    let account_with_lp = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(validator_pub_key, (Decimal::from(10), account_with_lp));
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            total_emission_xrd_per_epoch: Decimal::from(10),
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
+    let validator_substate = test_runner.get_validator_info(validator_address);
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(
+            account_with_lp,
+            validator_substate.liquidity_token,
+            Decimal::one(),
+        )
+        .take_from_worktop(validator_substate.liquidity_token, |builder, bucket| {
+            builder.unstake_validator(validator_address, bucket)
+        })
+        .call_method(
+            account_with_lp,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&account_pub_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.pending_xrd_withdraw_vault_id),
+        Some(Decimal::from(2))
+    );
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.stake_xrd_vault_id),
+        Some(Decimal::from(18))
+    );
+}
+
+#[test]
+fn cannot_apply_emission_without_epoch_manager_auth() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (pub_key, _, account) = test_runner.new_account(false);
+    let (_, validator_address) = test_runner.new_validator();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, Decimal::one())
+        .take_from_worktop(RADIX_TOKEN, |builder, bucket| {
+            builder.call_method(
+                validator_address,
+                VALIDATOR_APPLY_EMISSION_IDENT,
+                manifest_args!(bucket, 1u64),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pub_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
+
+#[test]
+fn validator_fee_increase_is_delayed_and_collected_as_locked_stake_units() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let validator_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator_account_address =
+        ComponentAddress::virtual_account_from_public_key(&validator_pub_key);
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(
+        validator_pub_key,
+        (Decimal::from(10), validator_account_address),
+    );
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            total_emission_xrd_per_epoch: Decimal::from(10),
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
+    let validator_substate = test_runner.get_validator_info(validator_address);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account(validator_account_address, OLYMPIA_VALIDATOR_TOKEN)
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_FEE_IDENT,
+            manifest_args!(dec!("0.5")),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_pub_key)],
+    );
+    receipt.expect_commit_success();
+    for nonce in 0..2 {
+        let instructions = vec![Instruction::CallMethod {
+            component_address: EPOCH_MANAGER,
+            method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+            args: to_manifest_value(&EpochManagerNextRoundInput {
+                round: rounds_per_epoch,
+                proposer_timestamp_ms: 0,
+                leader_proposal_history: LeaderProposalHistory::default(),
+            }),
+        }];
+        let receipt = test_runner.execute_transaction(
+            SystemTransaction {
+                instructions,
+                blobs: vec![],
+                nonce,
+                pre_allocated_ids: BTreeSet::new(),
+            }
+            .get_executable(vec![AuthAddresses::validator_role()]),
+        );
+        receipt.expect_commit_success();
+    }
+
+    // Assert
+    // The increase only applies to the second epoch's emission: 10 XRD, of which 5 XRD are
+    // staked for the owner once the other 5 XRD have raised the stake unit price to 2.5 XRD.
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.stake_xrd_vault_id),
+        Some(Decimal::from(30))
+    );
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.locked_owner_stake_unit_vault_id),
+        Some(Decimal::from(2))
+    );
+    let validator_substate = test_runner.get_validator_info(validator_address);
+    assert_eq!(validator_substate.validator_fee_factor, dec!("0.5"));
+    assert_eq!(validator_substate.validator_fee_change_request, None);
+}
+
+#[test]
+fn validator_fee_factor_above_one_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (pub_key, validator_address) = test_runner.new_validator();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_FEE_IDENT,
+            manifest_args!(dec!("1.5")),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pub_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                ValidatorError::InvalidValidatorFeeFactor
+            ))
+        )
+    });
+}
+
+#[test]
+fn owner_stake_units_can_only_be_withdrawn_after_unlock_delay() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let validator_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator_account_address =
+        ComponentAddress::virtual_account_from_public_key(&validator_pub_key);
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(
+        validator_pub_key,
+        (Decimal::from(10), validator_account_address),
+    );
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
+    let validator_substate = test_runner.get_validator_info(validator_address);
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account(validator_account_address, OLYMPIA_VALIDATOR_TOKEN)
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(
+            validator_account_address,
+            validator_substate.liquidity_token,
+            Decimal::from(5),
+        )
+        .take_from_worktop(validator_substate.liquidity_token, |builder, bucket| {
+            builder.call_method(
+                validator_address,
+                VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT,
+                manifest_args!(bucket),
+            )
+        })
+        .call_method(
+            validator_address,
+            VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+            manifest_args!(Decimal::from(5)),
+        )
+        .call_method(
+            validator_address,
+            VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+            manifest_args!(),
+        )
+        .call_method(
+            validator_account_address,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_pub_key)],
+    );
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .inspect_vault_balance(validator_substate.pending_owner_stake_unit_unlock_vault_id),
+        Some(Decimal::from(5))
+    );
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account(validator_account_address, OLYMPIA_VALIDATOR_TOKEN)
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            validator_address,
+            VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+            manifest_args!(),
+        )
+        .call_method(
+            validator_account_address,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_pub_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .inspect_vault_balance(validator_substate.pending_owner_stake_unit_unlock_vault_id),
+        Some(Decimal::zero())
+    );
+    assert_eq!(
+        test_runner.account_balance(
+            validator_account_address,
+            validator_substate.liquidity_token
+        ),
+        Some(Decimal::from(10))
+    );
+}
+
+#[test]
+fn validator_set_is_capped_to_top_validators_by_stake_on_epoch_change() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let max_validators = 2u32;
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    let mut pub_keys = Vec::new();
+    for (k, stake) in [(2u64, 10), (3u64, 30), (4u64, 10)] {
+        let pub_key = EcdsaSecp256k1PrivateKey::from_u64(k).unwrap().public_key();
+        let account = ComponentAddress::virtual_account_from_public_key(&pub_key);
+        validator_set_and_stake_owners.insert(pub_key, (Decimal::from(stake), account));
+        pub_keys.push(pub_key);
+    }
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            max_validators,
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator_addresses: Vec<ComponentAddress> = pub_keys
+        .iter()
+        .map(|pub_key| test_runner.get_validator_with_key(pub_key))
+        .collect();
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    let result = receipt.expect_commit(true);
+    let next_epoch = result.next_epoch().expect("Should have next epoch");
+    assert_eq!(next_epoch.0.len(), max_validators as usize);
+    assert!(next_epoch.0.contains_key(&validator_addresses[1]));
+    // Equal stakes are tie-broken on the lowest address
+    let tie_winner = validator_addresses[0].min(validator_addresses[2]);
+    assert!(next_epoch.0.contains_key(&tie_winner));
+}
+
+#[test]
+fn proposal_statistics_are_recorded_per_round_and_queryable() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 5u64;
+    let num_unstake_epochs = 1u64;
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    for pub_key in [validator1_pub_key, validator2_pub_key] {
+        let account = ComponentAddress::virtual_account_from_public_key(&pub_key);
+        validator_set_and_stake_owners.insert(pub_key, (Decimal::from(10), account));
+    }
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+
+    // Act
+    let histories = [
+        LeaderProposalHistory {
+            gap_round_leaders: vec![validator1_address],
+            current_leader: Some(validator2_address),
+        },
+        LeaderProposalHistory {
+            gap_round_leaders: vec![],
+            current_leader: Some(validator1_address),
+        },
+    ];
+    for (nonce, leader_proposal_history) in histories.into_iter().enumerate() {
+        let instructions = vec![Instruction::CallMethod {
+            component_address: EPOCH_MANAGER,
+            method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+            args: to_manifest_value(&EpochManagerNextRoundInput {
+                round: nonce as u64 + 2,
+                proposer_timestamp_ms: 0,
+                leader_proposal_history,
+            }),
+        }];
+        let receipt = test_runner.execute_transaction(
+            SystemTransaction {
+                instructions,
+                blobs: vec![],
+                nonce: nonce as u64,
+                pre_allocated_ids: BTreeSet::new(),
+            }
+            .get_executable(vec![AuthAddresses::validator_role()]),
+        );
+        receipt.expect_commit_success();
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            EPOCH_MANAGER,
+            EPOCH_MANAGER_GET_PROPOSAL_STATISTICS_IDENT,
+            to_manifest_value(&EpochManagerGetProposalStatisticsInput),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let statistics: BTreeMap<ComponentAddress, ProposalStatistic> =
+        receipt.expect_commit(true).output(1);
+    assert_eq!(
+        statistics.get(&validator1_address),
+        Some(&ProposalStatistic { made: 1, missed: 1 })
+    );
+    assert_eq!(
+        statistics.get(&validator2_address),
+        Some(&ProposalStatistic { made: 1, missed: 0 })
+    );
+}
+
+#[test]
+fn emissions_are_scaled_by_proposal_reliability() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    for pub_key in [validator1_pub_key, validator2_pub_key] {
+        let account = ComponentAddress::virtual_account_from_public_key(&pub_key);
+        validator_set_and_stake_owners.insert(pub_key, (Decimal::from(10), account));
+    }
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        initial_epoch,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            total_emission_xrd_per_epoch: Decimal::from(100),
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory {
+                gap_round_leaders: vec![validator1_address],
+                current_leader: Some(validator1_address),
+            },
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    let result = receipt.expect_commit(true);
+    let next_epoch = result.next_epoch().expect("Should have next epoch");
+    // Validator 1 made half of its proposals, so it only earns half of its share
+    assert_eq!(
+        next_epoch.0.get(&validator1_address).unwrap().stake,
+        Decimal::from(35)
+    );
+    assert_eq!(
+        next_epoch.0.get(&validator2_address).unwrap().stake,
+        Decimal::from(60)
+    );
+}
+
+#[test]
+fn next_round_with_leader_outside_validator_set_fails() {
+    // Arrange
+    let rounds_per_epoch = 5u64;
+    let genesis = create_genesis(
+        BTreeMap::new(),
+        BTreeMap::new(),
+        1u64,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let (_, validator_address) = test_runner.new_validator();
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: 1,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory {
+                gap_round_leaders: vec![],
+                current_leader: Some(validator_address),
+            },
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::UnknownRoundLeader { leader }
+            )) if *leader == validator_address
+        )
+    });
+}
+
+#[test]
+fn update_config_with_protocol_update_role_changes_epoch_length() {
+    // Arrange
+    let genesis = create_genesis(
+        BTreeMap::new(),
+        BTreeMap::new(),
+        1u64,
+        EpochManagerConfig {
+            rounds_per_epoch: 5u64,
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerUpdateConfigInput {
+            config: EpochManagerConfig {
+                rounds_per_epoch: 2u64,
+                ..Default::default()
+            },
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::protocol_update_role()]),
+    );
+    receipt.expect_commit_success();
+
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: 2u64,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    let result = receipt.expect_commit(true);
+    let next_epoch = result.next_epoch().expect("Should have next epoch");
+    assert_eq!(next_epoch.1, 2u64);
+}
+
+#[test]
+fn update_config_without_protocol_update_role_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerUpdateConfigInput {
+            config: EpochManagerConfig {
+                total_emission_xrd_per_epoch: Decimal::from(1000),
+                ..Default::default()
+            },
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::system_role()]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
+
+#[test]
+fn update_config_with_invalid_config_fails() {
+    let invalid_configs = [
+        (
+            EpochManagerConfig {
+                rounds_per_epoch: 0,
+                ..Default::default()
+            },
+            EpochManagerConfigError::ZeroRoundsPerEpoch,
+        ),
+        (
+            EpochManagerConfig {
+                max_validators: 0,
+                ..Default::default()
+            },
+            EpochManagerConfigError::ZeroMaxValidators,
+        ),
+        (
+            EpochManagerConfig {
+                min_validator_stake: Decimal::from(-1),
+                ..Default::default()
+            },
+            EpochManagerConfigError::NegativeMinValidatorStake,
+        ),
+        (
+            EpochManagerConfig {
+                total_emission_xrd_per_epoch: Decimal::from(-1),
+                ..Default::default()
+            },
+            EpochManagerConfigError::NegativeEmission,
+        ),
+        (
+            EpochManagerConfig {
+                target_epoch_duration_minutes: Some(0),
+                ..Default::default()
+            },
+            EpochManagerConfigError::ZeroTargetEpochDuration,
+        ),
+    ];
+
+    for (config, expected_error) in invalid_configs {
+        // Arrange
+        let mut test_runner = TestRunner::builder().build();
+
+        // Act
+        let instructions = vec![Instruction::CallMethod {
+            component_address: EPOCH_MANAGER,
+            method_name: EPOCH_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            args: to_manifest_value(&EpochManagerUpdateConfigInput { config }),
+        }];
+        let receipt = test_runner.execute_transaction(
+            SystemTransaction {
+                instructions,
+                blobs: vec![],
+                nonce: 0,
+                pre_allocated_ids: BTreeSet::new(),
+            }
+            .get_executable(vec![AuthAddresses::protocol_update_role()]),
+        );
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            e == &RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::InvalidConfig(expected_error.clone()),
+            ))
+        });
+    }
+}
+
+#[test]
+fn validators_below_min_stake_are_not_selected() {
+    // Arrange
+    let rounds_per_epoch = 1u64;
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    for (pub_key, stake) in [(validator1_pub_key, 10), (validator2_pub_key, 5)] {
+        let account = ComponentAddress::virtual_account_from_public_key(&pub_key);
+        validator_set_and_stake_owners.insert(pub_key, (Decimal::from(stake), account));
+    }
+    let genesis = create_genesis(
+        validator_set_and_stake_owners,
+        BTreeMap::new(),
+        1u64,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            min_validator_stake: Decimal::from(8),
+            ..Default::default()
+        },
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+
+    // Act
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    let result = receipt.expect_commit(true);
+    let next_epoch = result.next_epoch().expect("Should have next epoch");
+    assert!(next_epoch.0.contains_key(&validator1_address));
+    assert!(!next_epoch.0.contains_key(&validator2_address));
+}
+
+fn execute_next_round(
+    test_runner: &mut TestRunner,
+    round: u64,
+    proposer_timestamp_ms: i64,
+) -> TransactionReceipt {
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerNextRoundInput {
+            round,
+            proposer_timestamp_ms,
+            leader_proposal_history: LeaderProposalHistory::default(),
+        }),
+    }];
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    )
+}
+
+fn create_test_runner_with_target_epoch_duration(minutes: u64) -> TestRunner {
+    let genesis = create_genesis(
+        BTreeMap::new(),
+        BTreeMap::new(),
+        1u64,
+        EpochManagerConfig {
+            rounds_per_epoch: 100u64,
+            target_epoch_duration_minutes: Some(minutes),
+            ..Default::default()
+        },
+    );
+    TestRunner::builder().with_custom_genesis(genesis).build()
+}
+
+const MINUTE_MS: i64 = 60 * 1000;
+
+#[test]
+fn epoch_changes_once_target_duration_has_elapsed() {
+    // Arrange
+    let mut test_runner = create_test_runner_with_target_epoch_duration(10);
+
+    // Act
+    let mut receipts = Vec::new();
+    for (round, proposer_timestamp_ms) in [
+        (1u64, 0i64),
+        (2u64, 9 * MINUTE_MS + 59 * 1000),
+        (3u64, 10 * MINUTE_MS),
+    ] {
+        test_runner.set_current_time(proposer_timestamp_ms);
+        receipts.push(execute_next_round(
+            &mut test_runner,
+            round,
+            proposer_timestamp_ms,
+        ));
+    }
+
+    // Assert
+    assert!(receipts[0].expect_commit(true).next_epoch().is_none());
+    assert!(receipts[1].expect_commit(true).next_epoch().is_none());
+    let next_epoch = receipts[2]
+        .expect_commit(true)
+        .next_epoch()
+        .expect("Should have next epoch");
+    assert_eq!(next_epoch.1, 2u64);
+}
+
+#[test]
+fn epoch_duration_is_measured_on_the_clock() {
+    // Arrange
+    let mut test_runner = create_test_runner_with_target_epoch_duration(10);
+    test_runner.set_current_time(0);
+    execute_next_round(&mut test_runner, 1, 0).expect_commit_success();
+
+    // Act
+    let receipt = execute_next_round(&mut test_runner, 2, 10 * MINUTE_MS);
+
+    // Assert
+    assert!(receipt.expect_commit(true).next_epoch().is_none());
+}
+
+#[test]
+fn next_round_with_proposer_timestamp_before_clock_fails() {
+    // Arrange
+    let mut test_runner = create_test_runner_with_target_epoch_duration(10);
+    test_runner.set_current_time(5 * MINUTE_MS);
+
+    // Act
+    let receipt = execute_next_round(&mut test_runner, 1, 4 * MINUTE_MS);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::ProposerTimestampBeforeClock { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn set_epoch_restarts_epoch_duration() {
+    // Arrange
+    let mut test_runner = create_test_runner_with_target_epoch_duration(10);
+    test_runner.set_current_time(0);
+    execute_next_round(&mut test_runner, 1, 0).expect_commit_success();
+    test_runner.set_current_time(10 * MINUTE_MS);
+    test_runner.set_current_epoch(5);
+
+    // Act
+    let receipt = execute_next_round(&mut test_runner, 2, 10 * MINUTE_MS);
+
+    // Assert
+    assert!(receipt.expect_commit(true).next_epoch().is_none());
+}
@@ -14,11 +14,14 @@ use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::api::node_modules::metadata::{MetadataEntry, MetadataValue};
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::epoch_manager::{
-    EpochManagerNextRoundInput, ValidatorUpdateAcceptDelegatedStakeInput,
-    EPOCH_MANAGER_NEXT_ROUND_IDENT, VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
+    EpochManagerConfig, EpochManagerNextRoundInput, LeaderProposalHistory,
+    ValidatorUpdateAcceptDelegatedStakeInput, EPOCH_MANAGER_NEXT_ROUND_IDENT,
+    VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
 };
 use scrypto::prelude::Mutability::LOCKED;
-use scrypto::prelude::{AccessRule, AccessRulesConfig, FromPublicKey, ResourceMethodAuthKey};
+use scrypto::prelude::{
+    AccessRule, AccessRulesConfig, FromPublicKey, ResourceMethodAuthKey, VaultFreezeFlags,
+};
 use scrypto::NonFungibleData;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -382,6 +385,61 @@ fn vault_non_fungible_recall_emits_correct_events() {
 // Resource Manager
 //==================
 
+#[test]
+fn vault_freeze_and_unfreeze_emit_correct_events() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (_, _, account) = test_runner.new_account(false);
+    let freezeable_resource_address = test_runner.create_freezeable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, freezeable_resource_address)[0];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .freeze_vault(
+            vault_id,
+            VaultFreezeFlags::WITHDRAW | VaultFreezeFlags::BURN,
+        )
+        .unfreeze_vault(vault_id, VaultFreezeFlags::BURN)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    {
+        let events = receipt.expect_commit(true).clone().application_events;
+        assert_eq!(events.len(), 3); // Three events: vault lock fee, vault freeze, vault unfreeze
+        assert!(match events.get(1) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<FreezeVaultEvent>(event_identifier)
+                && is_decoded_equal(
+                    &FreezeVaultEvent {
+                        frozen: VaultFreezeFlags::WITHDRAW | VaultFreezeFlags::BURN
+                    },
+                    event_data
+                ) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(2) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<UnfreezeVaultEvent>(event_identifier)
+                && is_decoded_equal(
+                    &UnfreezeVaultEvent {
+                        unfrozen: VaultFreezeFlags::BURN
+                    },
+                    event_data
+                ) =>
+                true,
+            _ => false,
+        });
+    }
+}
+
 #[test]
 fn resource_manager_new_vault_emits_correct_events() {
     // Arrange
@@ -618,8 +676,11 @@ fn epoch_manager_round_update_emits_correct_event() {
         BTreeMap::new(),
         BTreeMap::new(),
         1u64,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -629,6 +690,8 @@ fn epoch_manager_round_update_emits_correct_event() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch - 1,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -665,8 +728,11 @@ fn epoch_manager_epoch_update_emits_correct_event() {
         BTreeMap::new(),
         BTreeMap::new(),
         1u64,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -676,6 +742,8 @@ fn epoch_manager_epoch_update_emits_correct_event() {
         method_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
         args: to_manifest_value(&EpochManagerNextRoundInput {
             round: rounds_per_epoch,
+            proposer_timestamp_ms: 0,
+            leader_proposal_history: LeaderProposalHistory::default(),
         }),
     }];
     let receipt = test_runner.execute_transaction(
@@ -719,8 +787,11 @@ fn validator_registration_emits_correct_event() {
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -769,8 +840,11 @@ fn validator_unregistration_emits_correct_event() {
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
@@ -826,8 +900,11 @@ fn validator_staking_emits_correct_event() {
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let (account_pk, _, account) = test_runner.new_account(false);
@@ -954,8 +1031,11 @@ fn validator_unstake_emits_correct_events() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -1112,8 +1192,11 @@ fn validator_claim_xrd_emits_correct_events() {
         validator_set_and_stake_owners,
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
@@ -1251,8 +1334,11 @@ fn validator_update_stake_delegation_status_emits_correct_event() {
         BTreeMap::new(),
         BTreeMap::new(),
         initial_epoch,
-        rounds_per_epoch,
-        num_unstake_epochs,
+        EpochManagerConfig {
+            rounds_per_epoch,
+            num_unstake_epochs,
+            ..Default::default()
+        },
     );
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
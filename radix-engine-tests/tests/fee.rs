@@ -2,7 +2,8 @@ use radix_engine::blueprints::resource::WorktopError;
 use radix_engine::errors::{ApplicationError, KernelError};
 use radix_engine::errors::{RejectionError, RuntimeError};
 use radix_engine::kernel::track::TrackError;
-use radix_engine::transaction::TransactionReceipt;
+use radix_engine::system::kernel_modules::costing::{CostingReason, FeeTable, LinearCost};
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -478,3 +479,187 @@ fn test_contingent_fee_accounting_failure() {
     );
     assert_eq!(account2_new_balance, account2_balance);
 }
+
+#[test]
+fn test_fee_breakdown_covers_system_calls() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10.into())
+        .withdraw_from_account(account1, RADIX_TOKEN, 66.into())
+        .call_method(
+            account2,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let breakdown = &receipt
+        .expect_commit(true)
+        .fee_summary
+        .execution_cost_breakdown;
+    for reason in [
+        CostingReason::AllocateNodeId,
+        CostingReason::CheckAuth,
+        CostingReason::EmitEvent,
+    ] {
+        assert!(breakdown.contains_key(&reason), "{:?} not costed", reason);
+    }
+}
+
+#[test]
+fn test_system_calls_are_not_costed_when_costing_is_disabled() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", manifest_args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+
+    // Act
+    let transaction = TestTransaction::new(
+        manifest,
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+    let receipt = test_runner.execute_transaction_with_config(
+        transaction.get_executable(vec![]),
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::genesis(),
+    );
+
+    // Assert
+    let breakdown = &receipt
+        .expect_commit(true)
+        .fee_summary
+        .execution_cost_breakdown;
+    for reason in [
+        CostingReason::AllocateNodeId,
+        CostingReason::CheckAuth,
+        CostingReason::EmitEvent,
+    ] {
+        assert!(!breakdown.contains_key(&reason), "{:?} costed", reason);
+    }
+}
+
+#[test]
+fn test_fee_table_is_configurable_per_execution() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10.into())
+        .withdraw_from_account(account1, RADIX_TOKEN, 66.into())
+        .call_method(
+            account2,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let fee_table = FeeTable {
+        emit_event: LinearCost::fixed(100_000),
+        ..FeeTable::new()
+    };
+
+    // Act
+    let transaction = TestTransaction::new(
+        manifest,
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+    let executable =
+        transaction.get_executable(vec![NonFungibleGlobalId::from_public_key(&public_key)]);
+    let receipt = test_runner.execute_transaction_with_config(
+        executable,
+        &FeeReserveConfig::standard().with_fee_table(fee_table),
+        &ExecutionConfig::standard(),
+    );
+
+    // Assert
+    let event_cost = receipt
+        .expect_commit(true)
+        .fee_summary
+        .execution_cost_breakdown
+        .get(&CostingReason::EmitEvent)
+        .cloned()
+        .unwrap();
+    assert!(event_cost > 0);
+    assert_eq!(event_cost % 100_000, 0);
+}
+
+#[test]
+fn test_execution_cost_tree_matches_execution_cost_breakdown() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10.into())
+        .withdraw_from_account(account1, RADIX_TOKEN, 66.into())
+        .call_method(
+            account2,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let fee_summary = &receipt.expect_commit(true).fee_summary;
+    let tree = &fee_summary.execution_cost_tree;
+    let execution_cost: u32 = fee_summary
+        .execution_cost_breakdown
+        .iter()
+        .filter(|(reason, _)| {
+            !matches!(
+                reason,
+                CostingReason::TxBaseCost
+                    | CostingReason::TxPayloadCost
+                    | CostingReason::TxSignatureVerification
+            )
+        })
+        .map(|(_, cost)| cost)
+        .sum();
+    assert_eq!(tree.total_cost(), execution_cost);
+    assert_eq!(
+        tree.instruction_costs.keys().cloned().collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    let transaction_processor = &tree.root.children[0];
+    assert_eq!(
+        transaction_processor
+            .actor
+            .as_ref()
+            .map(|actor| actor.fn_identifier.package_address),
+        Some(TRANSACTION_PROCESSOR_PACKAGE)
+    );
+    assert!(tree
+        .cost_by_function()
+        .keys()
+        .any(
+            |(package_address, blueprint_name, ident)| *package_address == ACCOUNT_PACKAGE
+                && blueprint_name == "Account"
+                && ident == "deposit_batch"
+        ));
+}
@@ -0,0 +1,198 @@
+use radix_engine::blueprints::resource::VaultError;
+use radix_engine::errors::{ApplicationError, ModuleError, RuntimeError};
+use radix_engine::system::kernel_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn cannot_freeze_vault_of_non_freezeable_resource() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    let resource_address = test_runner.create_fungible_resource(10u32.into(), 0u8, account);
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(vault_id, VaultFreezeFlags::WITHDRAW)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+    assert_eq!(
+        test_runner.inspect_vault_frozen_flags(vault_id),
+        Some(VaultFreezeFlags::empty())
+    );
+}
+
+#[test]
+fn can_freeze_and_unfreeze_freezeable_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    let freezeable_token = test_runner.create_freezeable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, freezeable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(
+            vault_id,
+            VaultFreezeFlags::WITHDRAW | VaultFreezeFlags::DEPOSIT,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let frozen_flags = test_runner.inspect_vault_frozen_flags(vault_id);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .unfreeze_vault(vault_id, VaultFreezeFlags::WITHDRAW)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    // Assert
+    assert_eq!(
+        frozen_flags,
+        Some(VaultFreezeFlags::WITHDRAW | VaultFreezeFlags::DEPOSIT)
+    );
+    assert_eq!(
+        test_runner.inspect_vault_frozen_flags(vault_id),
+        Some(VaultFreezeFlags::DEPOSIT)
+    );
+}
+
+#[test]
+fn cannot_withdraw_from_vault_frozen_for_withdrawal() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let freezeable_token = test_runner.create_freezeable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, freezeable_token)[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(vault_id, VaultFreezeFlags::WITHDRAW)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account(account, freezeable_token, Decimal::one())
+        .call_method(
+            other_account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultIsFrozen))
+        )
+    });
+}
+
+#[test]
+fn cannot_deposit_into_vault_frozen_for_deposit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let freezeable_token = test_runner.create_freezeable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, freezeable_token)[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(vault_id, VaultFreezeFlags::DEPOSIT)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account(account, freezeable_token, Decimal::one())
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultIsFrozen))
+        )
+    });
+}
+
+#[test]
+fn can_withdraw_from_vault_after_unfreeze() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let freezeable_token = test_runner.create_freezeable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, freezeable_token)[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(vault_id, VaultFreezeFlags::WITHDRAW)
+        .unfreeze_vault(vault_id, VaultFreezeFlags::WITHDRAW)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account(account, freezeable_token, Decimal::one())
+        .call_method(
+            other_account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let other_vault_id = test_runner.get_component_vaults(other_account, freezeable_token)[0];
+    assert_eq!(
+        test_runner.inspect_fungible_vault(other_vault_id),
+        Some(Decimal::one())
+    );
+}
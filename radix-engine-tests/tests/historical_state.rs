@@ -0,0 +1,122 @@
+use radix_engine::kernel::interpreters::ScryptoInterpreter;
+use radix_engine::ledger::{
+    ReadableSubstateStore, ReadableVersionedSubstateStore, SubstateStoreAtVersion,
+    TypedInMemoryVersionedSubstateStore,
+};
+use radix_engine::transaction::{
+    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt,
+};
+use radix_engine::types::*;
+use radix_engine::wasm::DefaultWasmEngine;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use radix_engine_interface::api::types::{RENodeId, TypeInfoOffset};
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::data::manifest::model::ManifestExpression;
+use transaction::builder::ManifestBuilder;
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::model::{TestTransaction, TransactionManifest};
+
+#[test]
+fn substates_can_be_read_at_earlier_state_version() {
+    // Arrange
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut substate_store =
+        TypedInMemoryVersionedSubstateStore::with_bootstrap(&scrypto_interpreter);
+    let (_, account) = new_virtual_account_key_pair();
+    let account_substate_id = SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::TypeInfo,
+        SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+    );
+
+    // Act
+    let receipt = execute(
+        &substate_store,
+        &scrypto_interpreter,
+        load_from_faucet(account),
+        vec![],
+    );
+    substate_store.commit(&receipt.expect_commit_success().state_updates);
+
+    // Assert
+    assert_eq!(substate_store.get_state_version(), 2);
+    assert!(substate_store
+        .get_substate_at_version(&account_substate_id, 1)
+        .is_none());
+    assert_eq!(
+        substate_store.get_substate_at_version(&account_substate_id, 2),
+        substate_store.get_substate(&account_substate_id)
+    );
+    assert!(SubstateStoreAtVersion::new(&substate_store, 1)
+        .get_substate(&account_substate_id)
+        .is_none());
+}
+
+#[test]
+fn transaction_can_be_executed_against_earlier_state_version() {
+    // Arrange
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut substate_store =
+        TypedInMemoryVersionedSubstateStore::with_bootstrap(&scrypto_interpreter);
+    let (public_key, account) = new_virtual_account_key_pair();
+    let receipt = execute(
+        &substate_store,
+        &scrypto_interpreter,
+        load_from_faucet(account),
+        vec![],
+    );
+    substate_store.commit(&receipt.expect_commit_success().state_updates);
+    let manifest = ManifestBuilder::new().lock_fee(account, 10.into()).build();
+    let initial_proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+
+    // Act
+    let latest_receipt = execute(
+        &substate_store,
+        &scrypto_interpreter,
+        manifest.clone(),
+        initial_proofs.clone(),
+    );
+    let historical_receipt = execute(
+        &SubstateStoreAtVersion::new(&substate_store, 1),
+        &scrypto_interpreter,
+        manifest,
+        initial_proofs,
+    );
+
+    // Assert
+    latest_receipt.expect_commit_success();
+    assert!(!historical_receipt.is_commit_success());
+}
+
+fn new_virtual_account_key_pair() -> (EcdsaSecp256k1PublicKey, ComponentAddress) {
+    let public_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    (public_key, account)
+}
+
+fn load_from_faucet(account: ComponentAddress) -> TransactionManifest {
+    ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", manifest_args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build()
+}
+
+fn execute<S: ReadableSubstateStore>(
+    substate_store: &S,
+    scrypto_interpreter: &ScryptoInterpreter<DefaultWasmEngine>,
+    manifest: TransactionManifest,
+    initial_proofs: Vec<NonFungibleGlobalId>,
+) -> TransactionReceipt {
+    execute_transaction(
+        substate_store,
+        scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default(),
+        &TestTransaction::new(manifest, 1, DEFAULT_COST_UNIT_LIMIT).get_executable(initial_proofs),
+    )
+}
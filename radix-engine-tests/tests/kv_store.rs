@@ -1,5 +1,6 @@
-use radix_engine::errors::{CallFrameError, KernelError, RuntimeError};
+use radix_engine::errors::{CallFrameError, KernelError, RuntimeError, SystemError};
 use radix_engine::types::*;
+use radix_engine_interface::api::substate_api::MAX_KEY_VALUE_STORE_SCAN_LIMIT;
 use radix_engine_interface::api::types::RENodeId;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -375,3 +376,161 @@ fn multiple_reads_should_work() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn publish_and_instantiate_kv_store_range(
+    test_runner: &mut TestRunner,
+    size: u8,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "KeyValueStoreRange",
+            "new",
+            manifest_args!(size),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit(true).new_component_addresses()[0]
+}
+
+#[test]
+fn can_remove_and_range_in_memory_kv_store() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "KeyValueStoreRange",
+            "remove_and_range_in_memory",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_remove_stored_kv_store_entry() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate_kv_store_range(&mut test_runner, 3);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove", manifest_args!(1u8))
+        .call_method(component_address, "remove", manifest_args!(1u8))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit(true);
+    let removed: Option<String> = commit.output(1);
+    assert_eq!(removed, Some("1".to_owned()));
+    let removed: Option<String> = commit.output(2);
+    assert_eq!(removed, None);
+}
+
+#[test]
+fn can_range_stored_kv_store_in_pages() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate_kv_store_range(&mut test_runner, 5);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "range", manifest_args!(None::<u8>, 2u32))
+        .call_method(component_address, "range", manifest_args!(Some(1u8), 2u32))
+        .call_method(component_address, "range", manifest_args!(Some(4u8), 2u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit(true);
+    let page: Vec<(u8, String)> = commit.output(1);
+    assert_eq!(page, vec![(0u8, "0".to_owned()), (1u8, "1".to_owned())]);
+    let page: Vec<(u8, String)> = commit.output(2);
+    assert_eq!(page, vec![(1u8, "1".to_owned()), (2u8, "2".to_owned())]);
+    let page: Vec<(u8, String)> = commit.output(3);
+    assert_eq!(page, vec![(4u8, "4".to_owned())]);
+}
+
+#[test]
+fn range_skips_entries_removed_in_same_transaction() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate_kv_store_range(&mut test_runner, 3);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "remove_and_range",
+            manifest_args!(0u8, 10u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let page: Vec<(u8, String)> = receipt.expect_commit(true).output(1);
+    assert_eq!(page, vec![(1u8, "1".to_owned()), (2u8, "2".to_owned())]);
+}
+
+#[test]
+fn cannot_remove_kv_store_entry_owning_a_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate_kv_store_range(&mut test_runner, 0);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove_vault", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::CannotRemoveKeyValueStoreEntryWithOwnedNodes)
+        )
+    });
+}
+
+#[test]
+fn cannot_range_kv_store_beyond_max_scan_limit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate_kv_store_range(&mut test_runner, 1);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "range",
+            manifest_args!(None::<u8>, MAX_KEY_VALUE_STORE_SCAN_LIMIT + 1),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::KeyValueStoreScanLimitExceeded(limit))
+                if *limit == MAX_KEY_VALUE_STORE_SCAN_LIMIT + 1
+        )
+    });
+}
@@ -67,15 +67,16 @@ fn test_basic_transfer() {
     // Or you can run just this test with the below:
     // cargo test -p radix-engine-tests --test metering -- test_basic_transfer
     assert_eq!(
-        7500 /* CreateNode */
-        + 88000 /* DropLock */
+        1500 /* AllocateNodeId */
+        + 6500 /* CheckAuth */
+        + 7500 /* CreateNode */
+        + 89500 /* DropLock */
         + 7500 /* DropNode */
+        + 2600 /* EmitEvent */
         + 11160 /* Invoke */
-        + 89500 /* LockSubstate */
-        + 61500 /* ReadSubstate */
+        + 91000 /* LockSubstate */
+        + 63000 /* ReadSubstate */
         + 62500 /* RunNative */
-        + 7500 /* RunSystem */
-        + 0 /* RunWasm */
         + 50000 /* TxBaseCost */
         + 1260 /* TxPayloadCost */
         + 100000 /* TxSignatureVerification */
@@ -202,14 +203,17 @@ fn test_radiswap() {
     // Or you can run just this test with the below:
     // cargo test -p radix-engine-tests --test metering -- test_radiswap
     assert_eq!(
-        12500 /* CreateNode */
-        + 229000 /* DropLock */
+        2500 /* AllocateNodeId */
+        + 16000 /* CheckAuth */
+        + 12500 /* CreateNode */
+        + 231500 /* DropLock */
         + 10000 /* DropNode */
+        + 5240 /* EmitEvent */
+        + 90000 /* GrowWasmMemory */
         + 25340 /* Invoke */
-        + 231500 /* LockSubstate */
-        + 165460 /* ReadSubstate */
+        + 234000 /* LockSubstate */
+        + 167960 /* ReadSubstate */
         + 137500 /* RunNative */
-        + 15000 /* RunSystem */
         + 1653645 /* RunWasm */
         + 50000 /* TxBaseCost */
         + 1625 /* TxPayloadCost */
@@ -312,14 +316,18 @@ fn test_flash_loan() {
     // Or you can run just this test with the below:
     // cargo test -p radix-engine-tests --test metering -- test_flash_loan
     assert_eq!(
-        20000 /* CreateNode */
-        + 348000 /* DropLock */
+        4000 /* AllocateNodeId */
+        + 23500 /* CheckAuth */
+        + 20000 /* CreateNode */
+        + 351000 /* DropLock */
         + 20000 /* DropNode */
+        + 9280 /* EmitEvent */
+        + 500 /* GenerateUuid */
+        + 180000 /* GrowWasmMemory */
         + 44790 /* Invoke */
-        + 356000 /* LockSubstate */
-        + 245330 /* ReadSubstate */
+        + 359000 /* LockSubstate */
+        + 248830 /* ReadSubstate */
         + 215000 /* RunNative */
-        + 30000 /* RunSystem */
         + 1310835 /* RunWasm */
         + 50000 /* TxBaseCost */
         + 2375 /* TxPayloadCost */
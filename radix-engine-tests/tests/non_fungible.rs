@@ -1,7 +1,7 @@
 use radix_engine::blueprints::resource::NonFungibleResourceManagerError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
 use radix_engine::types::*;
-use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use scrypto::NonFungibleData;
 use scrypto_unit::*;
@@ -849,3 +849,82 @@ fn cant_burn_non_fungible_with_wrong_non_fungible_local_id_type() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+#[test]
+fn cannot_create_non_fungible_resource_with_unknown_mutable_field() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let mut non_fungible_schema = NonFungibleDataSchema::new_schema::<Sandwich>();
+    non_fungible_schema
+        .mutable_fields
+        .insert("does_not_exist".to_string());
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            RESOURCE_MANAGER_PACKAGE,
+            NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
+            to_manifest_value(&NonFungibleResourceManagerCreateInput {
+                id_type: NonFungibleIdType::Integer,
+                non_fungible_schema,
+                metadata: BTreeMap::new(),
+                access_rules: BTreeMap::new(),
+            }),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::InvalidMutableField(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_mint_non_fungible_with_data_not_matching_schema() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let mut access_rules = BTreeMap::new();
+    access_rules.insert(
+        ResourceMethodAuthKey::Mint,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_non_fungible_resource::<_, Vec<(NonFungibleLocalId, Sandwich)>, _>(
+            NonFungibleIdType::Integer,
+            BTreeMap::new(),
+            access_rules,
+            None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt.expect_commit(true).new_resource_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .mint_non_fungible(
+            resource_address,
+            btreemap!(NonFungibleLocalId::integer(1) => ("test".to_string(), 5u32)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::NonFungibleDataSchemaNotMatch(..)
+            ))
+        )
+    });
+}
@@ -135,6 +135,40 @@ fn test_basic_package() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_basic_package_with_wasm_disk_cache() {
+    // Arrange
+    let dir = std::env::temp_dir().join(format!("wasm_disk_cache_test_{}", std::process::id()));
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let execute = || {
+        let mut test_runner = TestRunner::builder().with_wasm_disk_cache_dir(&dir).build();
+        let package_address = test_runner.publish_package(
+            code.clone(),
+            single_function_package_schema("Test", "f"),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            AccessRulesConfig::new(),
+        );
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(package_address, "Test", "f", manifest_args!())
+            .build();
+        test_runner.execute_manifest(manifest, vec![])
+    };
+
+    // Act
+    let first_receipt = execute();
+    let cached_entries = std::fs::read_dir(&dir).unwrap().count();
+    let second_receipt = execute();
+
+    // Assert
+    first_receipt.expect_commit_success();
+    second_receipt.expect_commit_success();
+    assert!(cached_entries > 0);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), cached_entries);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn test_basic_package_missing_export() {
     // Arrange
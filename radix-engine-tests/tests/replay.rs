@@ -0,0 +1,85 @@
+use radix_engine::kernel::interpreters::ScryptoInterpreter;
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::{RecordedTransaction, TransactionReplayer};
+use radix_engine::types::*;
+use radix_engine::wasm::DefaultWasmEngine;
+use radix_engine_interface::blueprints::epoch_manager::{
+    EpochManagerSetEpochInput, EPOCH_MANAGER_SET_EPOCH_IDENT,
+};
+use radix_engine_interface::data::manifest::model::ManifestExpression;
+use scrypto_unit::*;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::model::{Instruction, NotarizedTransaction, SystemTransaction, TransactionHeader};
+
+#[test]
+fn recorded_transactions_can_be_replayed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_transaction_recording().build();
+    // Only notarized and system transactions are recorded, so the account must be virtual
+    let public_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner
+        .execute_system_transaction(SystemTransaction {
+            instructions: vec![Instruction::CallMethod {
+                component_address: EPOCH_MANAGER,
+                method_name: EPOCH_MANAGER_SET_EPOCH_IDENT.to_string(),
+                args: to_manifest_value(&EpochManagerSetEpochInput { epoch: 5 }),
+            }],
+            pre_allocated_ids: BTreeSet::new(),
+            blobs: vec![],
+            nonce,
+        })
+        .expect_commit_success();
+    for nonce in 0..2 {
+        test_runner
+            .execute_notarized_transaction(&load_from_faucet(account, nonce))
+            .expect_commit_success();
+    }
+    let recording = scrypto_encode(test_runner.recorded_transactions()).unwrap();
+
+    // Act
+    let transactions: Vec<RecordedTransaction> = scrypto_decode(&recording).unwrap();
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
+    let mut replayer = TransactionReplayer::new(
+        &mut substate_store,
+        &scrypto_interpreter,
+        &NetworkDefinition::simulator(),
+    );
+    let result = replayer.replay_all(&transactions);
+
+    // Assert
+    assert_eq!(result, Ok(3));
+    substate_store.assert_eq(test_runner.substate_store());
+}
+
+fn load_from_faucet(account: ComponentAddress, nonce: u64) -> NotarizedTransaction {
+    let notary_private_key = EcdsaSecp256k1PrivateKey::from_u64(100).unwrap();
+    TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce,
+            notary_public_key: notary_private_key.public_key().into(),
+            notary_as_signatory: true,
+            cost_unit_limit: 10_000_000,
+            tip_percentage: 5,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee(FAUCET_COMPONENT, 10.into())
+                .call_method(FAUCET_COMPONENT, "free", manifest_args!())
+                .call_method(
+                    account,
+                    "deposit_batch",
+                    manifest_args!(ManifestExpression::EntireWorktop),
+                )
+                .build(),
+        )
+        .notarize(&notary_private_key)
+        .build()
+}
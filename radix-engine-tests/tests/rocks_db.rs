@@ -0,0 +1,244 @@
+#![cfg(feature = "rocksdb")]
+
+use radix_engine::kernel::interpreters::ScryptoInterpreter;
+use radix_engine::ledger::{
+    ReadableSubstateStore, ReadableVersionedSubstateStore, TypedInMemoryVersionedSubstateStore,
+};
+use radix_engine::types::*;
+use radix_engine::wasm::DefaultWasmEngine;
+use radix_engine_interface::api::types::{RENodeId, TypeInfoOffset};
+use radix_engine_interface::data::manifest::model::ManifestExpression;
+use radix_engine_stores::hash_tree::get_with_proof;
+use radix_engine_stores::hash_tree::proof::verify_substate_proof;
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use scrypto_unit::*;
+use std::path::PathBuf;
+use transaction::builder::ManifestBuilder;
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::model::TransactionManifest;
+
+#[test]
+fn state_hash_matches_test_runner_and_survives_reopen() {
+    // Arrange
+    let dir = new_db_dir("state_hash");
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    let mut substate_store = RadixEngineDB::with_bootstrap(dir.clone(), &scrypto_interpreter);
+    assert_eq!(
+        substate_store.get_state_hash(),
+        test_runner.get_state_hash()
+    );
+    let account = new_virtual_account();
+
+    // Act
+    let receipt = test_runner.execute_manifest(load_from_faucet(account), vec![]);
+    substate_store.commit(&receipt.expect_commit_success().state_updates);
+    let state_hash = substate_store.get_state_hash();
+    drop(substate_store);
+    let reopened_store = RadixEngineDB::new(dir.clone());
+
+    // Assert
+    assert_eq!(state_hash, test_runner.get_state_hash());
+    assert_eq!(reopened_store.get_state_hash(), state_hash);
+    assert_eq!(reopened_store.get_state_version(), 2);
+    assert!(reopened_store
+        .get_substate(&type_info_substate_id(account))
+        .is_some());
+    drop(reopened_store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn pruning_stale_tree_nodes_keeps_retained_versions_readable() {
+    // Arrange
+    let dir = new_db_dir("prune_tree");
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut test_runner = TestRunner::builder().build();
+    let mut substate_store = RadixEngineDB::with_bootstrap(dir.clone(), &scrypto_interpreter);
+    let account = new_virtual_account();
+    let substate_id = type_info_substate_id(account);
+    let mut state_hashes = Vec::new();
+    for _ in 0..3 {
+        let receipt = test_runner.execute_manifest(load_from_faucet(account), vec![]);
+        substate_store.commit(&receipt.expect_commit_success().state_updates);
+        state_hashes.push(substate_store.get_state_hash());
+    }
+
+    // Act
+    let pruned_count = substate_store.prune_stale_tree_nodes(2);
+
+    // Assert
+    assert!(pruned_count > 0);
+    assert_eq!(substate_store.prune_stale_tree_nodes(2), 0);
+    let expected_hash =
+        hash(scrypto_encode(&substate_store.get_substate(&substate_id).unwrap().substate).unwrap());
+    let current_version = substate_store.get_state_version();
+    for (version, state_hash) in [
+        (current_version - 1, state_hashes[1]),
+        (current_version, state_hashes[2]),
+    ] {
+        let (substate_hash, proof) = get_with_proof(&substate_store, version, &substate_id);
+        assert_eq!(substate_hash, Some(expected_hash));
+        assert_eq!(
+            verify_substate_proof(state_hash, &substate_id, substate_hash, &proof),
+            Ok(())
+        );
+    }
+    drop(substate_store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn versioned_reads_match_in_memory_versioned_store() {
+    // Arrange
+    let dir = new_db_dir("versioned_reads");
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut test_runner = TestRunner::builder().build();
+    let mut substate_store = RadixEngineDB::with_bootstrap(dir.clone(), &scrypto_interpreter);
+    let mut expected_store =
+        TypedInMemoryVersionedSubstateStore::with_bootstrap(&scrypto_interpreter);
+    let account = new_virtual_account();
+
+    // Act
+    let mut substate_ids = BTreeSet::new();
+    for _ in 0..3 {
+        let receipt = test_runner.execute_manifest(load_from_faucet(account), vec![]);
+        let state_updates = &receipt.expect_commit_success().state_updates;
+        substate_store.commit(state_updates);
+        expected_store.commit(state_updates);
+        substate_ids.extend(state_updates.up_substate_ids().into_iter().cloned());
+    }
+
+    // Assert
+    assert_eq!(substate_store.get_state_version(), 4);
+    assert_versioned_reads_match(&substate_store, &expected_store, &substate_ids, 1..=4);
+    drop(substate_store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn pruning_substate_versions_keeps_retained_versions_readable() {
+    // Arrange
+    let dir = new_db_dir("prune_versions");
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut test_runner = TestRunner::builder().build();
+    let mut substate_store = RadixEngineDB::with_bootstrap(dir.clone(), &scrypto_interpreter);
+    let mut expected_store =
+        TypedInMemoryVersionedSubstateStore::with_bootstrap(&scrypto_interpreter);
+    let account = new_virtual_account();
+    let mut substate_ids = BTreeSet::new();
+    for _ in 0..3 {
+        let receipt = test_runner.execute_manifest(load_from_faucet(account), vec![]);
+        let state_updates = &receipt.expect_commit_success().state_updates;
+        substate_store.commit(state_updates);
+        expected_store.commit(state_updates);
+        substate_ids.extend(state_updates.up_substate_ids().into_iter().cloned());
+    }
+
+    // Act
+    let pruned_count = substate_store.prune_substate_versions(2);
+
+    // Assert
+    assert!(pruned_count > 0);
+    assert_eq!(substate_store.prune_substate_versions(2), 0);
+    assert_versioned_reads_match(&substate_store, &expected_store, &substate_ids, 3..=4);
+    drop(substate_store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+fn assert_versioned_reads_match(
+    substate_store: &RadixEngineDB,
+    expected_store: &TypedInMemoryVersionedSubstateStore,
+    substate_ids: &BTreeSet<SubstateId>,
+    state_versions: std::ops::RangeInclusive<u64>,
+) {
+    let kv_store_ids: BTreeSet<KeyValueStoreId> = substate_ids
+        .iter()
+        .filter_map(|substate_id| match substate_id.0 {
+            RENodeId::KeyValueStore(kv_store_id) => Some(kv_store_id),
+            _ => None,
+        })
+        .collect();
+    assert!(!kv_store_ids.is_empty());
+    for state_version in state_versions {
+        for substate_id in substate_ids {
+            assert_eq!(
+                substate_store.get_substate_at_version(substate_id, state_version),
+                expected_store.get_substate_at_version(substate_id, state_version)
+            );
+        }
+        for kv_store_id in &kv_store_ids {
+            let entries = substate_store.scan_kv_store_entries_at_version(
+                kv_store_id,
+                None,
+                10,
+                state_version,
+            );
+            assert_eq!(
+                entries,
+                expected_store.scan_kv_store_entries_at_version(
+                    kv_store_id,
+                    None,
+                    10,
+                    state_version
+                )
+            );
+            // Scans seek to their start key
+            for (key, _) in &entries {
+                assert_eq!(
+                    substate_store.scan_kv_store_entries_at_version(
+                        kv_store_id,
+                        Some(key),
+                        1,
+                        state_version
+                    ),
+                    expected_store.scan_kv_store_entries_at_version(
+                        kv_store_id,
+                        Some(key),
+                        1,
+                        state_version
+                    )
+                );
+            }
+        }
+    }
+    for kv_store_id in &kv_store_ids {
+        for start_key in [None, Some(&[0u8][..]), Some(&[u8::MAX; 40][..])] {
+            assert_eq!(
+                substate_store.scan_kv_store_entries(kv_store_id, start_key, 10),
+                expected_store.scan_kv_store_entries(kv_store_id, start_key, 10)
+            );
+        }
+    }
+}
+
+fn new_db_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rocks_db_test_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn new_virtual_account() -> ComponentAddress {
+    let public_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    ComponentAddress::virtual_account_from_public_key(&public_key)
+}
+
+fn type_info_substate_id(account: ComponentAddress) -> SubstateId {
+    SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::TypeInfo,
+        SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+    )
+}
+
+fn load_from_faucet(account: ComponentAddress) -> TransactionManifest {
+    ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", manifest_args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build()
+}
@@ -0,0 +1,104 @@
+use radix_engine::ledger::ReadableSubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::api::types::{RENodeId, TypeInfoOffset};
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_stores::hash_tree::proof::{verify_substate_proof, ProofVerificationError};
+use scrypto_unit::*;
+
+#[test]
+fn can_verify_proof_of_existing_substate_against_state_hash() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let substate_id = SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::TypeInfo,
+        SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+    );
+    let substate = test_runner
+        .substate_store()
+        .get_substate(&substate_id)
+        .unwrap()
+        .substate;
+
+    // Act
+    let (substate_hash, proof) = test_runner.get_substate_hash_with_proof(&substate_id);
+
+    // Assert
+    let expected_hash = hash(scrypto_encode(&substate).unwrap());
+    assert_eq!(substate_hash, Some(expected_hash));
+    assert_eq!(
+        verify_substate_proof(
+            test_runner.get_state_hash(),
+            &substate_id,
+            Some(expected_hash),
+            &proof
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn can_verify_proof_of_missing_substate_against_state_hash() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let substate_id = SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::TypeInfo,
+        SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(vec![1, 2, 3])),
+    );
+
+    // Act
+    let (substate_hash, proof) = test_runner.get_substate_hash_with_proof(&substate_id);
+
+    // Assert
+    assert_eq!(substate_hash, None);
+    assert_eq!(
+        verify_substate_proof(test_runner.get_state_hash(), &substate_id, None, &proof),
+        Ok(())
+    );
+}
+
+#[test]
+fn proof_becomes_stale_after_substate_update() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let substate_id = SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::Metadata,
+        SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(scrypto_encode("name").unwrap())),
+    );
+    let (substate_hash, proof) = test_runner.get_substate_hash_with_proof(&substate_id);
+
+    // Act
+    test_runner.set_metadata(
+        account.into(),
+        "name",
+        "my account",
+        NonFungibleGlobalId::from_public_key(&public_key),
+    );
+
+    // Assert
+    assert!(matches!(
+        verify_substate_proof(
+            test_runner.get_state_hash(),
+            &substate_id,
+            substate_hash,
+            &proof
+        ),
+        Err(ProofVerificationError::RootHashMismatch { .. })
+    ));
+    let (new_substate_hash, new_proof) = test_runner.get_substate_hash_with_proof(&substate_id);
+    assert!(new_substate_hash.is_some());
+    assert_eq!(
+        verify_substate_proof(
+            test_runner.get_state_hash(),
+            &substate_id,
+            new_substate_hash,
+            &new_proof
+        ),
+        Ok(())
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "wasmer")]
+
+use radix_engine::errors::{KernelError, ModuleError, RuntimeError};
+use radix_engine::system::kernel_modules::transaction_limits::TransactionLimitsError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine::wasm::{
+    WasmEngine, WasmRuntimeError, WasmerEngine, WasmiEngine, WASM_MEMORY_PAGE_SIZE,
+};
+use radix_engine_interface::blueprints::resource::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn execute_with_engine<W: WasmEngine>(wasm_engine: W, code: &[u8]) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build_with_wasm_engine(wasm_engine);
+    let package_address = test_runner.publish_package(
+        code.to_vec(),
+        single_function_package_schema("Test", "f"),
+        BTreeMap::new(),
+        BTreeMap::new(),
+        AccessRulesConfig::new(),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 450.into())
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .build();
+
+    test_runner.execute_manifest_with_cost_unit_limit(manifest, vec![], 15_000_000)
+}
+
+/// Executes the package under both engines, asserting that the receipts match.
+fn execute_with_both_engines(code: Vec<u8>) -> TransactionReceipt {
+    let wasmi_receipt = execute_with_engine(WasmiEngine::default(), &code);
+    let wasmer_receipt = execute_with_engine(WasmerEngine::default(), &code);
+
+    assert_receipts_match(&wasmi_receipt, &wasmer_receipt);
+
+    wasmi_receipt
+}
+
+#[test]
+fn test_basic_package_has_same_receipt_under_both_engines() {
+    // Act
+    let receipt = execute_with_both_engines(wat2wasm(include_str!("wasm/basic_package.wat")));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_loop_has_same_metering_under_both_engines() {
+    // Act
+    let receipt = execute_with_both_engines(wat2wasm(
+        &include_str!("wasm/loop.wat").replace("${n}", "1000"),
+    ));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_loop_out_of_cost_unit_under_both_engines() {
+    // Act
+    let receipt = execute_with_both_engines(wat2wasm(
+        &include_str!("wasm/loop.wat").replace("${n}", "2000000"),
+    ));
+
+    // Assert
+    receipt.expect_specific_failure(is_costing_error)
+}
+
+#[test]
+fn test_memory_exceeded_under_both_engines() {
+    // Arrange
+    let grow_value: usize = DEFAULT_MAX_WASM_MEM_PER_CALL_FRAME / WASM_MEMORY_PAGE_SIZE as usize;
+
+    // Act
+    let receipt = execute_with_both_engines(wat2wasm(
+        &include_str!("wasm/memory.wat").replace("${n}", &grow_value.to_string()),
+    ));
+
+    // Assert
+    let expected_mem = DEFAULT_MAX_WASM_MEM_PER_CALL_FRAME + WASM_MEMORY_PAGE_SIZE as usize;
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ModuleError(ModuleError::TransactionLimitsError(
+            TransactionLimitsError::MaxWasmInstanceMemoryExceeded(x),
+        )) => *x == expected_mem,
+        _ => false,
+    })
+}
+
+#[test]
+fn test_trap_is_mapped_to_same_error_under_both_engines() {
+    // Arrange
+    let code = wat2wasm(
+        r#"
+        (module
+          (func $Test_f (param $0 i64) (result i64)
+            (unreachable)
+          )
+          (memory $0 1)
+          (export "memory" (memory $0))
+          (export "Test_f" (func $Test_f))
+        )
+        "#,
+    );
+
+    // Act
+    let receipt = execute_with_both_engines(code);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::WasmRuntimeError(WasmRuntimeError::Trap(..)))
+        )
+    })
+}
@@ -1,4 +1,7 @@
-use radix_engine::wasm::{InvalidMemory, PrepareError, WasmValidator};
+use radix_engine::wasm::{
+    InvalidImport, InvalidMemory, PrepareError, WasmLimitUsage, WasmValidator, WasmValidatorConfig,
+    DEFAULT_MAX_INITIAL_MEMORY_SIZE_PAGES, DEFAULT_MAX_NUMBER_OF_IMPORTS,
+};
 use scrypto_unit::*;
 
 #[test]
@@ -23,3 +26,135 @@ fn test_large_memory() {
         result
     );
 }
+
+#[test]
+fn test_custom_limits() {
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let schema = single_function_package_schema("Test", "f");
+    let validator = WasmValidator::new(WasmValidatorConfig {
+        max_initial_memory_size_pages: 0,
+        ..Default::default()
+    });
+    let result = validator.validate(&code, &schema);
+
+    assert_eq!(
+        Err(PrepareError::InvalidMemory(
+            InvalidMemory::InitialMemorySizeLimitExceeded
+        )),
+        result
+    );
+}
+
+#[test]
+fn test_report_of_valid_package() {
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let schema = single_function_package_schema("Test", "f");
+    let report = WasmValidator::default()
+        .validate_with_report(&code, &schema)
+        .unwrap();
+
+    assert!(report.is_valid());
+    assert_eq!(
+        WasmLimitUsage {
+            used: 1,
+            limit: DEFAULT_MAX_INITIAL_MEMORY_SIZE_PAGES
+        },
+        report.initial_memory_size_pages
+    );
+    assert_eq!(1, report.number_of_functions.used);
+    assert_eq!(0, report.number_of_globals.used);
+    assert_eq!(
+        WasmLimitUsage {
+            used: 0,
+            limit: DEFAULT_MAX_NUMBER_OF_IMPORTS
+        },
+        report.number_of_imports
+    );
+}
+
+#[test]
+fn test_import_limit() {
+    let code = wat2wasm(
+        r#"
+        (module
+            (import "env" "generate_uuid" (func $generate_uuid (result i64)))
+            (import "env" "get_transaction_hash" (func $get_transaction_hash (result i64)))
+            (func $Test_f (param $0 i64) (result i64)
+                (i64.const 0)
+            )
+            (memory $0 1)
+            (export "memory" (memory $0))
+            (export "Test_f" (func $Test_f))
+        )
+        "#,
+    );
+    let schema = single_function_package_schema("Test", "f");
+    let validator = WasmValidator::new(WasmValidatorConfig {
+        max_number_of_imports: 1,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        Err(PrepareError::InvalidImport(InvalidImport::TooManyImports)),
+        validator.validate(&code, &schema)
+    );
+    let report = validator.validate_with_report(&code, &schema).unwrap();
+    assert_eq!(
+        vec![PrepareError::InvalidImport(InvalidImport::TooManyImports)],
+        report.violations
+    );
+    assert_eq!(
+        WasmLimitUsage { used: 2, limit: 1 },
+        report.number_of_imports
+    );
+}
+
+#[test]
+fn test_report_lists_every_violation() {
+    let code = wat2wasm(
+        r#"
+        (module
+            (func $start)
+            (func $Test_f (param $0 i64) (result i64)
+                (i64.const 0)
+            )
+            (global $g0 (mut i32) (i32.const 0))
+            (global $g1 (mut i32) (i32.const 0))
+            (memory $0 2)
+            (start $start)
+            (export "memory" (memory $0))
+            (export "Test_f" (func $Test_f))
+        )
+        "#,
+    );
+    let schema = single_function_package_schema("Test", "f");
+    let validator = WasmValidator::new(WasmValidatorConfig {
+        max_initial_memory_size_pages: 1,
+        max_number_of_functions: 1,
+        max_number_of_globals: 1,
+        ..Default::default()
+    });
+    let report = validator.validate_with_report(&code, &schema).unwrap();
+
+    assert_eq!(
+        vec![
+            PrepareError::StartFunctionNotAllowed,
+            PrepareError::InvalidMemory(InvalidMemory::InitialMemorySizeLimitExceeded),
+            PrepareError::TooManyFunctions,
+            PrepareError::TooManyGlobals,
+        ],
+        report.violations
+    );
+    assert_eq!(
+        WasmLimitUsage { used: 2, limit: 1 },
+        report.initial_memory_size_pages
+    );
+    assert_eq!(
+        WasmLimitUsage { used: 2, limit: 1 },
+        report.number_of_functions
+    );
+    assert_eq!(
+        WasmLimitUsage { used: 2, limit: 1 },
+        report.number_of_globals
+    );
+}
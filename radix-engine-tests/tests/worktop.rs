@@ -30,3 +30,52 @@ fn test_worktop_resource_leak() {
         )
     });
 }
+
+#[test]
+fn test_worktop_take_advanced_rounds_to_divisibility() {
+    test_worktop_take_advanced(RoundingMode::TowardsZero, dec!("2"));
+    test_worktop_take_advanced(RoundingMode::AwayFromZero, dec!("3"));
+    test_worktop_take_advanced(RoundingMode::TowardsNearestAndHalfAwayFromZero, dec!("3"));
+}
+
+fn test_worktop_take_advanced(rounding_mode: RoundingMode, expected_amount: Decimal) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(10.into(), 0, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, resource_address, 10.into())
+        .take_from_worktop_advanced(
+            dec!("2.5"),
+            resource_address,
+            rounding_mode,
+            |builder, bucket_id| {
+                builder.call_method(other_account, "deposit", manifest_args!(bucket_id))
+            },
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(other_account, resource_address),
+        Some(expected_amount)
+    );
+    assert_eq!(
+        test_runner.account_balance(account, resource_address),
+        Some(Decimal::from(10) - expected_amount)
+    );
+}
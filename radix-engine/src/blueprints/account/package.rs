@@ -12,6 +12,7 @@ use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_interface::blueprints::resource::AccessRulesConfig;
+use radix_engine_interface::blueprints::resource::Bucket;
 use radix_engine_interface::blueprints::resource::MethodKey;
 use radix_engine_interface::schema::{
     BlueprintSchema, FunctionSchema, KeyValueStoreSchema, PackageSchema, Receiver,
@@ -26,11 +27,14 @@ pub struct AccountSubstate {
     /// An owned [`KeyValueStore`] which maps the [`ResourceAddress`] to an [`Own`] of the vault
     /// containing that resource.
     pub vaults: Own,
+    /// The policy applied to the resources deposited into the account.
+    pub deposit_rule: AccountDepositRule,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum AccountError {
     VaultDoesNotExist { resource_address: ResourceAddress },
+    DepositRejected { resource_address: ResourceAddress },
 }
 
 impl From<AccountError> for RuntimeError {
@@ -114,6 +118,63 @@ impl AccountNativePackage {
             },
         );
 
+        functions.insert(
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<AccountSetDepositRuleInput>(),
+                output: aggregator.add_child_type_and_descendents::<AccountSetDepositRuleOutput>(),
+                export_name: ACCOUNT_SET_DEPOSIT_RULE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRef),
+                input: aggregator.add_child_type_and_descendents::<AccountTryDepositOrAbortInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositOrAbortOutput>(),
+                export_name: ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRef),
+                input: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositBatchOrAbortInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositBatchOrAbortOutput>(),
+                export_name: ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRef),
+                input: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositOrRefundInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositOrRefundOutput>(),
+                export_name: ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRef),
+                input: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositBatchOrRefundInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<AccountTryDepositBatchOrRefundOutput>(),
+                export_name: ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_WITHDRAW_IDENT.to_string(),
             FunctionSchema {
@@ -269,6 +330,46 @@ impl AccountNativePackage {
                 ))?;
                 Self::deposit_batch(receiver, input, api)
             }
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                Self::set_deposit_rule(receiver, input, api)
+            }
+            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                Self::try_deposit_or_abort(receiver, input, api)
+            }
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                Self::try_deposit_batch_or_abort(receiver, input, api)
+            }
+            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                Self::try_deposit_or_refund(receiver, input, api)
+            }
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                Self::try_deposit_batch_or_refund(receiver, input, api)
+            }
             ACCOUNT_WITHDRAW_IDENT => {
                 api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
 
@@ -362,6 +463,7 @@ impl AccountNativePackage {
         let account_id = {
             let account_substate = AccountSubstate {
                 vaults: Own::KeyValueStore(kv_store_id.into()),
+                deposit_rule: AccountDepositRule::AcceptAll,
             };
             api.new_object(
                 ACCOUNT_BLUEPRINT,
@@ -418,6 +520,7 @@ impl AccountNativePackage {
         let account_id = {
             let account_substate = AccountSubstate {
                 vaults: Own::KeyValueStore(kv_store_id.into()),
+                deposit_rule: AccountDepositRule::AcceptAll,
             };
             api.new_object(
                 ACCOUNT_BLUEPRINT,
@@ -518,7 +621,7 @@ impl AccountNativePackage {
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
-    fn deposit<Y>(
+    fn set_deposit_rule<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
         api: &mut Y,
@@ -526,132 +629,239 @@ impl AccountNativePackage {
     where
         Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
     {
-        let input: AccountDepositInput = input.as_typed().map_err(|e| {
+        let input: AccountSetDepositRuleInput = input.as_typed().map_err(|e| {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
-        let resource_address = input.bucket.sys_resource_address(api)?;
-        let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
-
         let handle = api.sys_lock_substate(
             receiver,
             SubstateOffset::Account(AccountOffset::Account),
-            LockFlags::read_only(),
+            LockFlags::MUTABLE,
         )?;
+        let account: &mut AccountSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        account.deposit_rule = input.rule;
+        api.sys_drop_lock(handle)?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    /// Deposits the bucket into the vault of its resource, creating the vault if needed, unless
+    /// the deposit rule is enforced and rejects it - in which case the bucket is returned.
+    fn deposit_internal<Y>(
+        account_handle: LockHandle,
+        bucket: Bucket,
+        enforce_deposit_rule: bool,
+        api: &mut Y,
+    ) -> Result<Option<Bucket>, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let resource_address = bucket.sys_resource_address(api)?;
+        let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
 
         // Getting an RW lock handle on the KVStore ENTRY
-        let kv_store_entry_lock_handle = {
-            let account: &AccountSubstate = api.kernel_get_substate_ref(handle)?;
+        let (kv_store_entry_lock_handle, deposit_rule) = {
+            let account: &AccountSubstate = api.kernel_get_substate_ref(account_handle)?;
             let kv_store_id = account.vaults.key_value_store_id();
+            let deposit_rule = account.deposit_rule.clone();
 
             let node_id = RENodeId::KeyValueStore(kv_store_id);
             let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(encoded_key));
             let handle = api.sys_lock_substate(node_id, offset, LockFlags::MUTABLE)?;
-            handle
+            (handle, deposit_rule)
         };
 
         // Get the vault stored in the KeyValueStore entry - if it doesn't exist, then create it and
-        // insert it's entry into the KVStore
-        let mut vault = {
+        // insert it's entry into the KVStore (unless the deposit rule only accepts existing vaults)
+        let vault = {
             let entry: &Option<ScryptoValue> =
                 api.kernel_get_substate_ref(kv_store_entry_lock_handle)?;
 
-            match entry {
-                Option::Some(value) => scrypto_decode::<Own>(&scrypto_encode(value).unwrap())
+            entry.as_ref().map(|value| {
+                scrypto_decode::<Own>(&scrypto_encode(value).unwrap())
                     .map(|own| Vault(own.vault_id()))
-                    .expect("Impossible Case!"),
-                Option::None => {
-                    let vault = Vault::sys_new(resource_address, api)?;
-                    let encoded_value = IndexedScryptoValue::from_typed(&Own::Vault(vault.0));
-
-                    let entry: &mut Option<ScryptoValue> =
-                        api.kernel_get_substate_ref_mut(kv_store_entry_lock_handle)?;
-                    *entry = Option::Some(encoded_value.to_scrypto_value());
-                    vault
-                }
+                    .expect("Impossible Case!")
+            })
+        };
+
+        if enforce_deposit_rule && !deposit_rule.accepts(&resource_address, vault.is_some()) {
+            api.sys_drop_lock(kv_store_entry_lock_handle)?;
+            return Ok(Some(bucket));
+        }
+
+        let mut vault = match vault {
+            Some(vault) => vault,
+            None => {
+                let vault = Vault::sys_new(resource_address, api)?;
+                let encoded_value = IndexedScryptoValue::from_typed(&Own::Vault(vault.0));
+
+                let entry: &mut Option<ScryptoValue> =
+                    api.kernel_get_substate_ref_mut(kv_store_entry_lock_handle)?;
+                *entry = Option::Some(encoded_value.to_scrypto_value());
+                vault
             }
         };
 
         // Put the bucket in the vault
-        vault.sys_put(input.bucket, api)?;
+        vault.sys_put(bucket, api)?;
 
-        // Drop locks (LIFO)
         api.sys_drop_lock(kv_store_entry_lock_handle)?;
-        api.sys_drop_lock(handle)?;
 
-        Ok(IndexedScryptoValue::from_typed(&()))
+        Ok(None)
     }
 
-    fn deposit_batch<Y>(
+    /// Deposits the buckets, returning the ones rejected by the account's deposit rule (if it is
+    /// enforced).
+    fn deposit_batch_internal<Y>(
         receiver: RENodeId,
-        input: IndexedScryptoValue,
+        buckets: Vec<Bucket>,
+        enforce_deposit_rule: bool,
         api: &mut Y,
-    ) -> Result<IndexedScryptoValue, RuntimeError>
+    ) -> Result<Vec<Bucket>, RuntimeError>
     where
         Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
     {
-        let input: AccountDepositBatchInput = input.as_typed().map_err(|e| {
-            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
-        })?;
-
         let handle = api.sys_lock_substate(
             receiver,
             SubstateOffset::Account(AccountOffset::Account),
             LockFlags::read_only(),
-        )?; // TODO: should this be an R or RW lock?
+        )?;
 
         // TODO: We should optimize this a bit more so that we're not locking and unlocking the same
         // KV-store entries again and again because of buckets that have the same resource address.
         // Perhaps these should be grouped into a HashMap<ResourceAddress, Vec<Bucket>> when being
         // resolved.
-        for bucket in input.buckets {
+        let mut rejected = Vec::new();
+        for bucket in buckets {
+            if let Some(bucket) = Self::deposit_internal(handle, bucket, enforce_deposit_rule, api)?
+            {
+                rejected.push(bucket);
+            }
+        }
+
+        api.sys_drop_lock(handle)?;
+
+        Ok(rejected)
+    }
+
+    fn deposit_batch_or_abort<Y>(
+        receiver: RENodeId,
+        buckets: Vec<Bucket>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let rejected = Self::deposit_batch_internal(receiver, buckets, true, api)?;
+        if let Some(bucket) = rejected.first() {
             let resource_address = bucket.sys_resource_address(api)?;
-            let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+            return Err(AccountError::DepositRejected { resource_address }.into());
+        }
+        Ok(())
+    }
 
-            // Getting an RW lock handle on the KVStore ENTRY
-            let kv_store_entry_lock_handle = {
-                let account: &AccountSubstate = api.kernel_get_substate_ref(handle)?;
-                let kv_store_id = account.vaults.key_value_store_id();
+    fn deposit<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: AccountDepositInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
 
-                let node_id = RENodeId::KeyValueStore(kv_store_id);
-                let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(encoded_key));
-                let handle = api.sys_lock_substate(node_id, offset, LockFlags::MUTABLE)?;
-                handle
-            };
+        Self::deposit_batch_internal(receiver, vec![input.bucket], false, api)?;
 
-            // Get the vault stored in the KeyValueStore entry - if it doesn't exist, then create it
-            // and insert it's entry into the KVStore
-            let mut vault = {
-                let entry: &Option<ScryptoValue> =
-                    api.kernel_get_substate_ref(kv_store_entry_lock_handle)?;
-
-                match entry {
-                    Option::Some(value) => scrypto_decode::<Own>(&scrypto_encode(value).unwrap())
-                        .map(|own| Vault(own.vault_id()))
-                        .expect("Impossible Case!"),
-                    Option::None => {
-                        let vault = Vault::sys_new(resource_address, api)?;
-                        let encoded_value = IndexedScryptoValue::from_typed(&Own::Vault(vault.0));
-
-                        let entry: &mut Option<ScryptoValue> =
-                            api.kernel_get_substate_ref_mut(kv_store_entry_lock_handle)?;
-                        *entry = Option::Some(encoded_value.to_scrypto_value());
-                        vault
-                    }
-                }
-            };
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
 
-            // Put the bucket in the vault
-            vault.sys_put(bucket, api)?;
+    fn deposit_batch<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: AccountDepositBatchInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
 
-            api.sys_drop_lock(kv_store_entry_lock_handle)?;
-        }
+        Self::deposit_batch_internal(receiver, input.buckets, false, api)?;
 
-        api.sys_drop_lock(handle)?;
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    fn try_deposit_or_abort<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: AccountTryDepositOrAbortInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        Self::deposit_batch_or_abort(receiver, vec![input.bucket], api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    fn try_deposit_batch_or_abort<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: AccountTryDepositBatchOrAbortInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        Self::deposit_batch_or_abort(receiver, input.buckets, api)?;
 
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
+    fn try_deposit_or_refund<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: AccountTryDepositOrRefundInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let refund = Self::deposit_batch_internal(receiver, vec![input.bucket], true, api)?.pop();
+
+        Ok(IndexedScryptoValue::from_typed(&refund))
+    }
+
+    fn try_deposit_batch_or_refund<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: AccountTryDepositBatchOrRefundInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let refunds = Self::deposit_batch_internal(receiver, input.buckets, true, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&refunds))
+    }
+
     fn get_vault<F, Y, R>(
         receiver: RENodeId,
         resource_address: ResourceAddress,
@@ -867,17 +1077,23 @@ impl AccountNativePackage {
 // Helpers
 //=========
 
-fn access_rules_from_withdraw_rule(withdraw_rule: AccessRule) -> AccessRulesConfig {
+/// The access rules of an account: deposits are open to anyone (subject to the account's deposit
+/// rule), while every other method, including configuring the deposit rule, requires the owner.
+pub fn access_rules_from_withdraw_rule(withdraw_rule: AccessRule) -> AccessRulesConfig {
     let mut access_rules = AccessRulesConfig::new();
-    access_rules.set_access_rule_and_mutability(
-        MethodKey::new(NodeModuleId::SELF, ACCOUNT_DEPOSIT_IDENT.to_string()),
-        AccessRule::AllowAll,
-        AccessRule::DenyAll,
-    );
-    access_rules.set_access_rule_and_mutability(
-        MethodKey::new(NodeModuleId::SELF, ACCOUNT_DEPOSIT_BATCH_IDENT.to_string()),
-        AccessRule::AllowAll,
-        AccessRule::DenyAll,
-    );
+    for ident in [
+        ACCOUNT_DEPOSIT_IDENT,
+        ACCOUNT_DEPOSIT_BATCH_IDENT,
+        ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+        ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+        ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+        ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT,
+    ] {
+        access_rules.set_access_rule_and_mutability(
+            MethodKey::new(NodeModuleId::SELF, ident.to_string()),
+            AccessRule::AllowAll,
+            AccessRule::DenyAll,
+        );
+    }
     access_rules.default(withdraw_rule.clone(), withdraw_rule)
 }
@@ -22,9 +22,9 @@ pub struct ClockSubstate {
     pub current_time_rounded_to_minutes_ms: i64,
 }
 
-const SECONDS_TO_MS_FACTOR: i64 = 1000;
+pub(crate) const SECONDS_TO_MS_FACTOR: i64 = 1000;
 const MINUTES_TO_SECONDS_FACTOR: i64 = 60;
-const MINUTES_TO_MS_FACTOR: i64 = SECONDS_TO_MS_FACTOR * MINUTES_TO_SECONDS_FACTOR;
+pub(crate) const MINUTES_TO_MS_FACTOR: i64 = SECONDS_TO_MS_FACTOR * MINUTES_TO_SECONDS_FACTOR;
 
 pub struct ClockNativePackage;
 impl ClockNativePackage {
@@ -1,4 +1,5 @@
 use super::{EpochChangeEvent, RoundChangeEvent, ValidatorCreator};
+use crate::blueprints::clock::{MINUTES_TO_MS_FACTOR, SECONDS_TO_MS_FACTOR};
 use crate::errors::RuntimeError;
 use crate::errors::{ApplicationError, InterpreterError};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
@@ -12,6 +13,7 @@ use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::api::substate_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::account::{AccountDepositInput, ACCOUNT_DEPOSIT_IDENT};
+use radix_engine_interface::blueprints::clock::TimePrecision;
 use radix_engine_interface::blueprints::epoch_manager::*;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::rule;
@@ -21,10 +23,9 @@ pub struct EpochManagerSubstate {
     pub address: ComponentAddress, // TODO: Does it make sense for this to be stored here?
     pub epoch: u64,
     pub round: u64,
-
-    // TODO: Move configuration to an immutable substate
-    pub rounds_per_epoch: u64,
-    pub num_unstake_epochs: u64,
+    /// The Clock's minute-rounded time at the round which started the current epoch, unknown
+    /// until the first round of the genesis epoch (or of an epoch set by `set_epoch`).
+    pub epoch_start_ms: Option<i64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, ScryptoSbor)]
@@ -39,9 +40,89 @@ pub struct ValidatorSetSubstate {
     pub epoch: u64,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+impl ValidatorSetSubstate {
+    /// Selects the `max_validators` validators with the most stake out of those with at least
+    /// `min_validator_stake`, breaking ties in favour of the lowest address so that every node
+    /// arrives at the same set.
+    pub fn top_validators_by_stake(
+        &self,
+        min_validator_stake: Decimal,
+        max_validators: u32,
+    ) -> BTreeMap<ComponentAddress, Validator> {
+        let mut validators: Vec<(&ComponentAddress, &Validator)> = self
+            .validator_set
+            .iter()
+            .filter(|(_, validator)| validator.stake >= min_validator_stake)
+            .collect();
+        validators.sort_by(|(address_a, validator_a), (address_b, validator_b)| {
+            validator_b
+                .stake
+                .cmp(&validator_a.stake)
+                .then_with(|| address_a.cmp(address_b))
+        });
+        validators
+            .into_iter()
+            .take(max_validators as usize)
+            .map(|(address, validator)| (*address, validator.clone()))
+            .collect()
+    }
+}
+
+/// The proposals made and missed by each validator of the current validator set, which scale
+/// its share of the epoch's emissions.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ProposalStatisticSubstate {
+    pub validator_statistics: BTreeMap<ComponentAddress, ProposalStatistic>,
+    pub epoch: u64,
+}
+
+impl ProposalStatisticSubstate {
+    fn new(epoch: u64, validator_set: &BTreeMap<ComponentAddress, Validator>) -> Self {
+        Self {
+            validator_statistics: validator_set
+                .keys()
+                .map(|address| (*address, ProposalStatistic::default()))
+                .collect(),
+            epoch,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
 pub enum EpochManagerError {
     InvalidRoundUpdate { from: u64, to: u64 },
+    InvalidConfig(EpochManagerConfigError),
+    UnknownRoundLeader { leader: ComponentAddress },
+    ProposerTimestampBeforeClock { timestamp_ms: i64, clock_ms: i64 },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub enum EpochManagerConfigError {
+    ZeroRoundsPerEpoch,
+    ZeroMaxValidators,
+    NegativeMinValidatorStake,
+    NegativeEmission,
+    ZeroTargetEpochDuration,
+}
+
+fn validate_config(config: &EpochManagerConfig) -> Result<(), RuntimeError> {
+    let error = if config.rounds_per_epoch == 0 {
+        EpochManagerConfigError::ZeroRoundsPerEpoch
+    } else if config.max_validators == 0 {
+        EpochManagerConfigError::ZeroMaxValidators
+    } else if config.min_validator_stake.is_negative() {
+        EpochManagerConfigError::NegativeMinValidatorStake
+    } else if config.total_emission_xrd_per_epoch.is_negative() {
+        EpochManagerConfigError::NegativeEmission
+    } else if config.target_epoch_duration_minutes == Some(0) {
+        EpochManagerConfigError::ZeroTargetEpochDuration
+    } else {
+        return Ok(());
+    };
+
+    Err(RuntimeError::ApplicationError(
+        ApplicationError::EpochManagerError(EpochManagerError::InvalidConfig(error)),
+    ))
 }
 
 pub struct EpochManagerBlueprint;
@@ -64,9 +145,10 @@ impl EpochManagerBlueprint {
             address,
             epoch: input.initial_epoch,
             round: 0,
-            rounds_per_epoch: input.rounds_per_epoch,
-            num_unstake_epochs: input.num_unstake_epochs,
+            epoch_start_ms: None,
         };
+        let config = input.config;
+        validate_config(&config)?;
 
         let mut olympia_validator_token_resman: ResourceManager = {
             let metadata: BTreeMap<String, String> = BTreeMap::new();
@@ -136,22 +218,29 @@ impl EpochManagerBlueprint {
             )?;
         }
 
-        let current_validator_set = ValidatorSetSubstate {
-            epoch: input.initial_epoch,
-            validator_set: validator_set.clone(),
-        };
-
+        // The preparing set holds every candidate, the active set is selected from it
         let preparing_validator_set = ValidatorSetSubstate {
             epoch: input.initial_epoch + 1,
+            validator_set,
+        };
+
+        let validator_set = preparing_validator_set
+            .top_validators_by_stake(config.min_validator_stake, config.max_validators);
+        let current_validator_set = ValidatorSetSubstate {
+            epoch: input.initial_epoch,
             validator_set: validator_set.clone(),
         };
+        let proposal_statistic =
+            ProposalStatisticSubstate::new(input.initial_epoch, &validator_set);
 
         let epoch_manager_id = api.new_object(
             EPOCH_MANAGER_BLUEPRINT,
             vec![
                 scrypto_encode(&epoch_manager).unwrap(),
+                scrypto_encode(&config).unwrap(),
                 scrypto_encode(&current_validator_set).unwrap(),
                 scrypto_encode(&preparing_validator_set).unwrap(),
+                scrypto_encode(&proposal_statistic).unwrap(),
             ],
         )?;
 
@@ -178,6 +267,13 @@ impl EpochManagerBlueprint {
             ),
             rule!(allow_all),
         );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                EPOCH_MANAGER_GET_PROPOSAL_STATISTICS_IDENT.to_string(),
+            ),
+            rule!(allow_all),
+        );
         access_rules.set_method_access_rule(
             MethodKey::new(
                 NodeModuleId::SELF,
@@ -202,6 +298,13 @@ impl EpochManagerBlueprint {
             ),
             rule!(require(AuthAddresses::system_role())), // Set epoch only used for debugging
         );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                EPOCH_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            ),
+            rule!(require(AuthAddresses::protocol_update_role())),
+        );
 
         let access_rules = AccessRulesObject::sys_new(access_rules, api)?;
         let metadata = Metadata::sys_create(api)?;
@@ -243,6 +346,31 @@ impl EpochManagerBlueprint {
         Ok(IndexedScryptoValue::from_typed(&epoch_manager.epoch))
     }
 
+    pub(crate) fn get_proposal_statistics<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let _input: EpochManagerGetProposalStatisticsInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::EpochManager(EpochManagerOffset::CurrentProposalStatistic),
+            LockFlags::read_only(),
+        )?;
+
+        let proposal_statistic: &ProposalStatisticSubstate = api.kernel_get_substate_ref(handle)?;
+
+        Ok(IndexedScryptoValue::from_typed(
+            &proposal_statistic.validator_statistics,
+        ))
+    }
+
     pub(crate) fn next_round<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
@@ -269,19 +397,66 @@ impl EpochManagerBlueprint {
             ));
         }
 
-        if input.round >= epoch_manager.rounds_per_epoch {
+        // Epoch durations are measured on the Clock, which the proposal can't predate
+        let clock_ms = Runtime::sys_current_time(api, TimePrecision::Minute)?
+            .seconds_since_unix_epoch
+            * SECONDS_TO_MS_FACTOR;
+        if input.proposer_timestamp_ms < clock_ms {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(
+                    EpochManagerError::ProposerTimestampBeforeClock {
+                        timestamp_ms: input.proposer_timestamp_ms,
+                        clock_ms,
+                    },
+                ),
+            ));
+        }
+
+        Self::update_proposal_statistic(receiver, &input.leader_proposal_history, api)?;
+
+        let config = {
+            let handle = api.sys_lock_substate(
+                receiver,
+                SubstateOffset::EpochManager(EpochManagerOffset::Config),
+                LockFlags::read_only(),
+            )?;
+            let config: &EpochManagerConfig = api.kernel_get_substate_ref(handle)?;
+            let config = config.clone();
+            api.sys_drop_lock(handle)?;
+            config
+        };
+
+        let epoch_manager: &mut EpochManagerSubstate =
+            api.kernel_get_substate_ref_mut(mgr_handle)?;
+        let epoch_start_ms = *epoch_manager.epoch_start_ms.get_or_insert(clock_ms);
+        let epoch_duration_elapsed = match config.target_epoch_duration_minutes {
+            Some(minutes) => {
+                let elapsed_ms = clock_ms.saturating_sub(epoch_start_ms);
+                elapsed_ms >= 0 && (elapsed_ms / MINUTES_TO_MS_FACTOR) as u64 >= minutes
+            }
+            None => false,
+        };
+        if input.round >= config.rounds_per_epoch || epoch_duration_elapsed {
+            let ending_epoch = epoch_manager.epoch;
+            let total_emission_xrd = config.total_emission_xrd_per_epoch;
+            if total_emission_xrd.is_positive() {
+                Self::apply_emissions(receiver, ending_epoch, total_emission_xrd, api)?;
+            }
+
             let offset = SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet);
             let handle = api.sys_lock_substate(receiver, offset, LockFlags::MUTABLE)?;
             let preparing_validator_set: &mut ValidatorSetSubstate =
                 api.kernel_get_substate_ref_mut(handle)?;
             let prepared_epoch = preparing_validator_set.epoch;
-            let next_validator_set = preparing_validator_set.validator_set.clone();
+            let next_validator_set = preparing_validator_set
+                .top_validators_by_stake(config.min_validator_stake, config.max_validators);
             preparing_validator_set.epoch = prepared_epoch + 1;
 
             let epoch_manager: &mut EpochManagerSubstate =
                 api.kernel_get_substate_ref_mut(mgr_handle)?;
             epoch_manager.epoch = prepared_epoch;
             epoch_manager.round = 0;
+            epoch_manager.epoch_start_ms = Some(clock_ms);
 
             let handle = api.sys_lock_substate(
                 receiver,
@@ -293,6 +468,16 @@ impl EpochManagerBlueprint {
             validator_set.epoch = prepared_epoch;
             validator_set.validator_set = next_validator_set.clone();
 
+            let handle = api.sys_lock_substate(
+                receiver,
+                SubstateOffset::EpochManager(EpochManagerOffset::CurrentProposalStatistic),
+                LockFlags::MUTABLE,
+            )?;
+            let proposal_statistic: &mut ProposalStatisticSubstate =
+                api.kernel_get_substate_ref_mut(handle)?;
+            *proposal_statistic =
+                ProposalStatisticSubstate::new(prepared_epoch, &next_validator_set);
+
             Runtime::emit_event(
                 api,
                 EpochChangeEvent {
@@ -309,6 +494,135 @@ impl EpochManagerBlueprint {
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
+    fn update_proposal_statistic<Y>(
+        receiver: RENodeId,
+        leader_proposal_history: &LeaderProposalHistory,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::EpochManager(EpochManagerOffset::CurrentProposalStatistic),
+            LockFlags::MUTABLE,
+        )?;
+        let proposal_statistic: &mut ProposalStatisticSubstate =
+            api.kernel_get_substate_ref_mut(handle)?;
+
+        let missed = leader_proposal_history
+            .gap_round_leaders
+            .iter()
+            .map(|leader| (leader, false));
+        let made = leader_proposal_history
+            .current_leader
+            .iter()
+            .map(|leader| (leader, true));
+        for (leader, is_made) in missed.chain(made) {
+            let statistic = proposal_statistic
+                .validator_statistics
+                .get_mut(leader)
+                .ok_or(RuntimeError::ApplicationError(
+                    ApplicationError::EpochManagerError(EpochManagerError::UnknownRoundLeader {
+                        leader: *leader,
+                    }),
+                ))?;
+            if is_made {
+                statistic.made += 1;
+            } else {
+                statistic.missed += 1;
+            }
+        }
+
+        api.sys_drop_lock(handle)?;
+
+        Ok(())
+    }
+
+    /// Mints the epoch's emissions and distributes them to the stake vaults of the epoch's
+    /// validators, pro rata to their stake and scaled by the fraction of their proposals they
+    /// made. The emissions forfeited by unreliable validators are not minted.
+    fn apply_emissions<Y>(
+        receiver: RENodeId,
+        epoch: u64,
+        total_emission_xrd: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let validators = {
+            let handle = api.sys_lock_substate(
+                receiver,
+                SubstateOffset::EpochManager(EpochManagerOffset::CurrentValidatorSet),
+                LockFlags::read_only(),
+            )?;
+            let validator_set: &ValidatorSetSubstate = api.kernel_get_substate_ref(handle)?;
+            let validators = validator_set.validator_set.clone();
+            api.sys_drop_lock(handle)?;
+            validators
+        };
+
+        let validator_statistics = {
+            let handle = api.sys_lock_substate(
+                receiver,
+                SubstateOffset::EpochManager(EpochManagerOffset::CurrentProposalStatistic),
+                LockFlags::read_only(),
+            )?;
+            let proposal_statistic: &ProposalStatisticSubstate =
+                api.kernel_get_substate_ref(handle)?;
+            let validator_statistics = proposal_statistic.validator_statistics.clone();
+            api.sys_drop_lock(handle)?;
+            validator_statistics
+        };
+
+        let total_stake: Decimal = validators.values().map(|validator| validator.stake).sum();
+        if !total_stake.is_positive() {
+            return Ok(());
+        }
+
+        let mut xrd_resman = ResourceManager(RADIX_TOKEN);
+        let mut emissions = BTreeMap::new();
+        for (validator_address, validator) in validators {
+            let reliability = validator_statistics
+                .get(&validator_address)
+                .map(|statistic| statistic.success_ratio())
+                .unwrap_or(Decimal::one());
+            let emission_xrd = total_emission_xrd * validator.stake / total_stake * reliability;
+            if !emission_xrd.is_positive() {
+                continue;
+            }
+
+            let xrd_bucket = xrd_resman.mint_fungible(emission_xrd, api)?;
+            api.call_method(
+                RENodeId::GlobalObject(validator_address.into()),
+                VALIDATOR_APPLY_EMISSION_IDENT,
+                scrypto_encode(&ValidatorApplyEmissionInput { xrd_bucket, epoch }).unwrap(),
+            )?;
+            emissions.insert(validator_address, emission_xrd);
+        }
+
+        // The emissions are staked, so the validators' stake in the next epochs grows accordingly
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet),
+            LockFlags::MUTABLE,
+        )?;
+        let preparing_validator_set: &mut ValidatorSetSubstate =
+            api.kernel_get_substate_ref_mut(handle)?;
+        for (validator_address, emission_xrd) in emissions {
+            if let Some(validator) = preparing_validator_set
+                .validator_set
+                .get_mut(&validator_address)
+            {
+                validator.stake += emission_xrd;
+            }
+        }
+        api.sys_drop_lock(handle)?;
+
+        Ok(())
+    }
+
     pub(crate) fn set_epoch<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
@@ -329,6 +643,33 @@ impl EpochManagerBlueprint {
 
         let epoch_manager: &mut EpochManagerSubstate = api.kernel_get_substate_ref_mut(handle)?;
         epoch_manager.epoch = input.epoch;
+        epoch_manager.epoch_start_ms = None;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub(crate) fn update_config<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: EpochManagerUpdateConfigInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+        validate_config(&input.config)?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::EpochManager(EpochManagerOffset::Config),
+            LockFlags::MUTABLE,
+        )?;
+
+        let config: &mut EpochManagerConfig = api.kernel_get_substate_ref_mut(handle)?;
+        *config = input.config;
+        api.sys_drop_lock(handle)?;
 
         Ok(IndexedScryptoValue::from_typed(&()))
     }
@@ -26,3 +26,40 @@ pub struct ClaimXrdEvent {
 pub struct UpdateAcceptingStakeDelegationStateEvent {
     pub accepts_delegation: bool,
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct ValidatorEmissionAppliedEvent {
+    pub epoch: u64,
+    pub starting_stake_pool_xrd: Decimal,
+    pub stake_pool_added_xrd: Decimal,
+    pub total_stake_unit_supply: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct ValidatorFeeChangeRequestEvent {
+    pub new_fee_factor: Decimal,
+    pub epoch_effective: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct ValidatorFeeCollectedEvent {
+    pub epoch: u64,
+    pub fee_xrd: Decimal,
+    pub locked_stake_units: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct LockOwnerStakeUnitsEvent {
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct StartUnlockOwnerStakeUnitsEvent {
+    pub amount: Decimal,
+    pub epoch_unlocked: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct FinishUnlockOwnerStakeUnitsEvent {
+    pub amount: Decimal,
+}
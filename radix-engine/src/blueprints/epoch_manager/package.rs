@@ -21,8 +21,10 @@ impl EpochManagerNativePackage {
 
         let mut substates = Vec::new();
         substates.push(aggregator.add_child_type_and_descendents::<EpochManagerSubstate>());
+        substates.push(aggregator.add_child_type_and_descendents::<EpochManagerConfig>());
         substates.push(aggregator.add_child_type_and_descendents::<ValidatorSetSubstate>());
         substates.push(aggregator.add_child_type_and_descendents::<ValidatorSetSubstate>());
+        substates.push(aggregator.add_child_type_and_descendents::<ProposalStatisticSubstate>());
 
         let mut functions = BTreeMap::new();
         functions.insert(
@@ -45,6 +47,17 @@ impl EpochManagerNativePackage {
                 export_name: EPOCH_MANAGER_GET_CURRENT_EPOCH_IDENT.to_string(),
             },
         );
+        functions.insert(
+            EPOCH_MANAGER_GET_PROPOSAL_STATISTICS_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRef),
+                input: aggregator
+                    .add_child_type_and_descendents::<EpochManagerGetProposalStatisticsInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<EpochManagerGetProposalStatisticsOutput>(),
+                export_name: EPOCH_MANAGER_GET_PROPOSAL_STATISTICS_IDENT.to_string(),
+            },
+        );
         functions.insert(
             EPOCH_MANAGER_SET_EPOCH_IDENT.to_string(),
             FunctionSchema {
@@ -63,6 +76,16 @@ impl EpochManagerNativePackage {
                 export_name: EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
             },
         );
+        functions.insert(
+            EPOCH_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<EpochManagerUpdateConfigInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<EpochManagerUpdateConfigOutput>(),
+                export_name: EPOCH_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            },
+        );
         functions.insert(
             EPOCH_MANAGER_CREATE_VALIDATOR_IDENT.to_string(),
             FunctionSchema {
@@ -173,6 +196,57 @@ impl EpochManagerNativePackage {
                 export_name: VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VALIDATOR_APPLY_EMISSION_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<ValidatorApplyEmissionInput>(),
+                output: aggregator.add_child_type_and_descendents::<ValidatorApplyEmissionOutput>(),
+                export_name: VALIDATOR_APPLY_EMISSION_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_UPDATE_FEE_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<ValidatorUpdateFeeInput>(),
+                output: aggregator.add_child_type_and_descendents::<ValidatorUpdateFeeOutput>(),
+                export_name: VALIDATOR_UPDATE_FEE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator
+                    .add_child_type_and_descendents::<ValidatorLockOwnerStakeUnitsInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<ValidatorLockOwnerStakeUnitsOutput>(),
+                export_name: VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator
+                    .add_child_type_and_descendents::<ValidatorStartUnlockOwnerStakeUnitsInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<ValidatorStartUnlockOwnerStakeUnitsOutput>(),
+                export_name: VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator
+                    .add_child_type_and_descendents::<ValidatorFinishUnlockOwnerStakeUnitsInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<ValidatorFinishUnlockOwnerStakeUnitsOutput>(),
+                export_name: VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
@@ -182,7 +256,13 @@ impl EpochManagerNativePackage {
                 StakeEvent,
                 UnstakeEvent,
                 ClaimXrdEvent,
-                UpdateAcceptingStakeDelegationStateEvent
+                UpdateAcceptingStakeDelegationStateEvent,
+                ValidatorEmissionAppliedEvent,
+                ValidatorFeeChangeRequestEvent,
+                ValidatorFeeCollectedEvent,
+                LockOwnerStakeUnitsEvent,
+                StartUnlockOwnerStakeUnitsEvent,
+                FinishUnlockOwnerStakeUnitsEvent
             ]
         };
 
@@ -242,6 +322,14 @@ impl EpochManagerNativePackage {
                 ))?;
                 EpochManagerBlueprint::get_current_epoch(receiver, input, api)
             }
+            EPOCH_MANAGER_GET_PROPOSAL_STATISTICS_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                EpochManagerBlueprint::get_proposal_statistics(receiver, input, api)
+            }
             EPOCH_MANAGER_SET_EPOCH_IDENT => {
                 api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
 
@@ -258,6 +346,14 @@ impl EpochManagerNativePackage {
                 ))?;
                 EpochManagerBlueprint::next_round(receiver, input, api)
             }
+            EPOCH_MANAGER_UPDATE_CONFIG_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                EpochManagerBlueprint::update_config(receiver, input, api)
+            }
             EPOCH_MANAGER_CREATE_VALIDATOR_IDENT => {
                 api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
 
@@ -330,6 +426,46 @@ impl EpochManagerNativePackage {
                 ))?;
                 ValidatorBlueprint::update_accept_delegated_stake(receiver, input, api)
             }
+            VALIDATOR_APPLY_EMISSION_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                ValidatorBlueprint::apply_emission(receiver, input, api)
+            }
+            VALIDATOR_UPDATE_FEE_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                ValidatorBlueprint::update_fee(receiver, input, api)
+            }
+            VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                ValidatorBlueprint::lock_owner_stake_units(receiver, input, api)
+            }
+            VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                ValidatorBlueprint::start_unlock_owner_stake_units(receiver, input, api)
+            }
+            VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                ValidatorBlueprint::finish_unlock_owner_stake_units(receiver, input, api)
+            }
             _ => Err(RuntimeError::InterpreterError(
                 InterpreterError::NativeExportDoesNotExist(export_name.to_string()),
             )),
@@ -19,8 +19,10 @@ use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::rule;
 
 use super::{
-    ClaimXrdEvent, RegisterValidatorEvent, StakeEvent, UnregisterValidatorEvent, UnstakeEvent,
-    UpdateAcceptingStakeDelegationStateEvent,
+    ClaimXrdEvent, FinishUnlockOwnerStakeUnitsEvent, LockOwnerStakeUnitsEvent,
+    RegisterValidatorEvent, StakeEvent, StartUnlockOwnerStakeUnitsEvent, UnregisterValidatorEvent,
+    UnstakeEvent, UpdateAcceptingStakeDelegationStateEvent, ValidatorEmissionAppliedEvent,
+    ValidatorFeeChangeRequestEvent, ValidatorFeeCollectedEvent,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -34,6 +36,32 @@ pub struct ValidatorSubstate {
     pub liquidity_token: ResourceAddress,
     pub stake_xrd_vault_id: ObjectId,
     pub pending_xrd_withdraw_vault_id: ObjectId,
+
+    /// The fraction of each emission taken by the owner as a fee, paid in stake units which are
+    /// locked in `locked_owner_stake_unit_vault_id`.
+    pub validator_fee_factor: Decimal,
+    pub validator_fee_change_request: Option<ValidatorFeeChangeRequest>,
+    pub locked_owner_stake_unit_vault_id: ObjectId,
+    pub pending_owner_stake_unit_unlock_vault_id: ObjectId,
+    /// Stake units being unlocked by the owner, keyed by the epoch they can be withdrawn in.
+    pub pending_owner_stake_unit_withdrawals: BTreeMap<u64, Decimal>,
+}
+
+impl ValidatorSubstate {
+    fn apply_due_fee_change(&mut self, epoch: u64) {
+        if let Some(request) = &self.validator_fee_change_request {
+            if request.epoch_effective <= epoch {
+                self.validator_fee_factor = request.new_fee_factor;
+                self.validator_fee_change_request = None;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ValidatorFeeChangeRequest {
+    pub epoch_effective: u64,
+    pub new_fee_factor: Decimal,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -50,6 +78,7 @@ impl NonFungibleData for UnstakeData {
 pub enum ValidatorError {
     InvalidClaimResource,
     EpochUnlockHasNotOccurredYet,
+    InvalidValidatorFeeFactor,
 }
 
 pub struct ValidatorBlueprint;
@@ -273,16 +302,9 @@ impl ValidatorBlueprint {
 
             lp_token_resman.burn(lp_tokens, api)?;
 
-            let manager_handle = api.sys_lock_substate(
-                RENodeId::GlobalObject(manager.into()),
-                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
-                LockFlags::read_only(),
-            )?;
-            let epoch_manager: &EpochManagerSubstate =
-                api.kernel_get_substate_ref(manager_handle)?;
-            let current_epoch = epoch_manager.epoch;
-            let epoch_unlocked = current_epoch + epoch_manager.num_unstake_epochs;
-            api.sys_drop_lock(manager_handle)?;
+            let (current_epoch, num_unstake_epochs) =
+                Self::read_epoch_and_unstake_delay(manager, api)?;
+            let epoch_unlocked = current_epoch + num_unstake_epochs;
 
             let data = UnstakeData {
                 epoch_unlocked,
@@ -479,6 +501,280 @@ impl ValidatorBlueprint {
 
         Ok(IndexedScryptoValue::from_typed(&()))
     }
+
+    /// Puts the validator's share of an epoch's emissions into its stake vault, which increases
+    /// the value of its stake units. The owner's fee is staked on their behalf and the resulting
+    /// stake units are locked. Only callable by the epoch manager, which also accounts for the
+    /// added stake in its validator sets.
+    pub fn apply_emission<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: ValidatorApplyEmissionInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Validator(ValidatorOffset::Validator),
+            LockFlags::MUTABLE,
+        )?;
+        let validator: &mut ValidatorSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        validator.apply_due_fee_change(input.epoch);
+        let fee_factor = validator.validator_fee_factor;
+        let mut stake_vault = Vault(validator.stake_xrd_vault_id);
+        let mut locked_owner_stake_unit_vault = Vault(validator.locked_owner_stake_unit_vault_id);
+        let mut lp_token_resman = ResourceManager(validator.liquidity_token);
+
+        let starting_stake_pool_xrd = stake_vault.sys_amount(api)?;
+        let stake_pool_added_xrd = input.xrd_bucket.sys_amount(api)?;
+        let starting_stake_unit_supply = lp_token_resman.total_supply(api)?;
+        stake_vault.sys_put(input.xrd_bucket, api)?;
+
+        // The fee is staked after the rest of the emission has been added to the pool, so that
+        // only the stake units' holders profit from the latter.
+        let fee_xrd = stake_pool_added_xrd * fee_factor;
+        let fee_event = if fee_xrd.is_positive() {
+            let stake_pool_xrd_before_fee =
+                starting_stake_pool_xrd + stake_pool_added_xrd - fee_xrd;
+            let lp_mint_amount = if stake_pool_xrd_before_fee.is_zero() {
+                fee_xrd
+            } else {
+                fee_xrd * starting_stake_unit_supply / stake_pool_xrd_before_fee
+            };
+            let lp_token_bucket = lp_token_resman.mint_fungible(lp_mint_amount, api)?;
+            locked_owner_stake_unit_vault.sys_put(lp_token_bucket, api)?;
+            Some(ValidatorFeeCollectedEvent {
+                epoch: input.epoch,
+                fee_xrd,
+                locked_stake_units: lp_mint_amount,
+            })
+        } else {
+            None
+        };
+        let total_stake_unit_supply = lp_token_resman.total_supply(api)?;
+
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(
+            api,
+            ValidatorEmissionAppliedEvent {
+                epoch: input.epoch,
+                starting_stake_pool_xrd,
+                stake_pool_added_xrd,
+                total_stake_unit_supply,
+            },
+        )?;
+        if let Some(fee_event) = fee_event {
+            Runtime::emit_event(api, fee_event)?;
+        }
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    /// Requests a change of the validator fee. A decrease takes effect from the next epoch,
+    /// while an increase only takes effect after the unstake delay, so that stakers can exit
+    /// before it applies.
+    pub fn update_fee<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: ValidatorUpdateFeeInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        if input.new_fee_factor.is_negative() || input.new_fee_factor > Decimal::one() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ValidatorError(ValidatorError::InvalidValidatorFeeFactor),
+            ));
+        }
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Validator(ValidatorOffset::Validator),
+            LockFlags::MUTABLE,
+        )?;
+        let validator: &ValidatorSubstate = api.kernel_get_substate_ref(handle)?;
+        let (current_epoch, num_unstake_epochs) =
+            Self::read_epoch_and_unstake_delay(validator.manager, api)?;
+
+        let validator: &mut ValidatorSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        validator.apply_due_fee_change(current_epoch);
+        let epoch_effective = if input.new_fee_factor > validator.validator_fee_factor {
+            current_epoch + num_unstake_epochs
+        } else {
+            current_epoch + 1
+        };
+        validator.validator_fee_change_request = Some(ValidatorFeeChangeRequest {
+            epoch_effective,
+            new_fee_factor: input.new_fee_factor,
+        });
+
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(
+            api,
+            ValidatorFeeChangeRequestEvent {
+                new_fee_factor: input.new_fee_factor,
+                epoch_effective,
+            },
+        )?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn lock_owner_stake_units<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: ValidatorLockOwnerStakeUnitsInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Validator(ValidatorOffset::Validator),
+            LockFlags::read_only(),
+        )?;
+        let validator: &ValidatorSubstate = api.kernel_get_substate_ref(handle)?;
+        let mut locked_owner_stake_unit_vault = Vault(validator.locked_owner_stake_unit_vault_id);
+
+        let amount = input.stake_unit_bucket.sys_amount(api)?;
+        locked_owner_stake_unit_vault.sys_put(input.stake_unit_bucket, api)?;
+
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(api, LockOwnerStakeUnitsEvent { amount })?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn start_unlock_owner_stake_units<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: ValidatorStartUnlockOwnerStakeUnitsInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Validator(ValidatorOffset::Validator),
+            LockFlags::MUTABLE,
+        )?;
+        let validator: &ValidatorSubstate = api.kernel_get_substate_ref(handle)?;
+        let mut locked_owner_stake_unit_vault = Vault(validator.locked_owner_stake_unit_vault_id);
+        let mut pending_unlock_vault = Vault(validator.pending_owner_stake_unit_unlock_vault_id);
+        let (current_epoch, num_unstake_epochs) =
+            Self::read_epoch_and_unstake_delay(validator.manager, api)?;
+
+        let amount = input.requested_stake_unit_amount;
+        let bucket = locked_owner_stake_unit_vault.sys_take(amount, api)?;
+        pending_unlock_vault.sys_put(bucket, api)?;
+
+        let epoch_unlocked = current_epoch + num_unstake_epochs;
+        let validator: &mut ValidatorSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        *validator
+            .pending_owner_stake_unit_withdrawals
+            .entry(epoch_unlocked)
+            .or_insert_with(Decimal::zero) += amount;
+
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(
+            api,
+            StartUnlockOwnerStakeUnitsEvent {
+                amount,
+                epoch_unlocked,
+            },
+        )?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn finish_unlock_owner_stake_units<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let _input: ValidatorFinishUnlockOwnerStakeUnitsInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Validator(ValidatorOffset::Validator),
+            LockFlags::MUTABLE,
+        )?;
+        let validator: &ValidatorSubstate = api.kernel_get_substate_ref(handle)?;
+        let mut pending_unlock_vault = Vault(validator.pending_owner_stake_unit_unlock_vault_id);
+        let (current_epoch, _) = Self::read_epoch_and_unstake_delay(validator.manager, api)?;
+
+        let validator: &mut ValidatorSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        let still_pending = validator
+            .pending_owner_stake_unit_withdrawals
+            .split_off(&(current_epoch + 1));
+        let unlocked = sbor::rust::mem::replace(
+            &mut validator.pending_owner_stake_unit_withdrawals,
+            still_pending,
+        );
+        let amount: Decimal = unlocked.values().cloned().sum();
+
+        let bucket = pending_unlock_vault.sys_take(amount, api)?;
+
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(api, FinishUnlockOwnerStakeUnitsEvent { amount })?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    fn read_epoch_and_unstake_delay<Y>(
+        manager: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<(u64, u64), RuntimeError>
+    where
+        Y: KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let manager_handle = api.sys_lock_substate(
+            RENodeId::GlobalObject(manager.into()),
+            SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+            LockFlags::read_only(),
+        )?;
+        let epoch_manager: &EpochManagerSubstate = api.kernel_get_substate_ref(manager_handle)?;
+        let epoch = epoch_manager.epoch;
+        api.sys_drop_lock(manager_handle)?;
+
+        let config_handle = api.sys_lock_substate(
+            RENodeId::GlobalObject(manager.into()),
+            SubstateOffset::EpochManager(EpochManagerOffset::Config),
+            LockFlags::read_only(),
+        )?;
+        let config: &EpochManagerConfig = api.kernel_get_substate_ref(config_handle)?;
+        let num_unstake_epochs = config.num_unstake_epochs;
+        api.sys_drop_lock(config_handle)?;
+
+        Ok((epoch, num_unstake_epochs))
+    }
 }
 
 pub(crate) struct ValidatorCreator;
@@ -614,6 +910,31 @@ impl ValidatorCreator {
             ),
             "owner".to_string(),
         );
+        access_rules.set_method_access_rule(
+            MethodKey::new(NodeModuleId::SELF, VALIDATOR_UPDATE_FEE_IDENT.to_string()),
+            "owner".to_string(),
+        );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            ),
+            "owner".to_string(),
+        );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            ),
+            "owner".to_string(),
+        );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT.to_string(),
+            ),
+            "owner".to_string(),
+        );
 
         let non_fungible_local_id =
             NonFungibleLocalId::bytes(scrypto_encode(&EPOCH_MANAGER_PACKAGE).unwrap()).unwrap();
@@ -621,6 +942,13 @@ impl ValidatorCreator {
         access_rules.set_group_and_mutability(
             MethodKey::new(NodeModuleId::SELF, VALIDATOR_STAKE_IDENT.to_string()),
             "owner".to_string(),
+            rule!(require(non_fungible_global_id.clone())),
+        );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                VALIDATOR_APPLY_EMISSION_IDENT.to_string(),
+            ),
             rule!(require(non_fungible_global_id)),
         );
 
@@ -660,6 +988,8 @@ impl ValidatorCreator {
         let unstake_nft = Self::create_unstake_nft(api)?;
         let (liquidity_token, liquidity_bucket) =
             Self::create_liquidity_token_with_initial_amount(initial_liquidity_amount, api)?;
+        let locked_owner_stake_unit_vault = Vault::sys_new(liquidity_token, api)?;
+        let pending_owner_stake_unit_unlock_vault = Vault::sys_new(liquidity_token, api)?;
 
         let substate = ValidatorSubstate {
             manager,
@@ -670,6 +1000,11 @@ impl ValidatorCreator {
             stake_xrd_vault_id: stake_vault.0,
             pending_xrd_withdraw_vault_id: unstake_vault.0,
             is_registered,
+            validator_fee_factor: Decimal::zero(),
+            validator_fee_change_request: None,
+            locked_owner_stake_unit_vault_id: locked_owner_stake_unit_vault.0,
+            pending_owner_stake_unit_unlock_vault_id: pending_owner_stake_unit_unlock_vault.0,
+            pending_owner_stake_unit_withdrawals: BTreeMap::new(),
         };
 
         let validator_id = api.new_object(
@@ -710,6 +1045,8 @@ impl ValidatorCreator {
         let unstake_vault = Vault::sys_new(RADIX_TOKEN, api)?;
         let unstake_nft = Self::create_unstake_nft(api)?;
         let liquidity_token = Self::create_liquidity_token(api)?;
+        let locked_owner_stake_unit_vault = Vault::sys_new(liquidity_token, api)?;
+        let pending_owner_stake_unit_unlock_vault = Vault::sys_new(liquidity_token, api)?;
 
         let substate = ValidatorSubstate {
             manager,
@@ -720,6 +1057,11 @@ impl ValidatorCreator {
             stake_xrd_vault_id: stake_vault.0,
             pending_xrd_withdraw_vault_id: unstake_vault.0,
             is_registered,
+            validator_fee_factor: Decimal::zero(),
+            validator_fee_change_request: None,
+            locked_owner_stake_unit_vault_id: locked_owner_stake_unit_vault.0,
+            pending_owner_stake_unit_unlock_vault_id: pending_owner_stake_unit_unlock_vault.0,
+            pending_owner_stake_unit_withdrawals: BTreeMap::new(),
         };
 
         let validator_id = api.new_object(
@@ -540,9 +540,45 @@ impl BucketBlueprint {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
+        let bucket = Self::take_internal(receiver, input.amount, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    pub fn take_advanced<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: BucketTakeAdvancedInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        // Round amount
+        let info = BucketInfoSubstate::of(receiver, api)?;
+        let amount = info
+            .resource_type
+            .round_amount(input.amount, input.rounding_mode);
+
+        let bucket = Self::take_internal(receiver, amount, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    fn take_internal<Y>(
+        receiver: RENodeId,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
         // Check amount
         let info = BucketInfoSubstate::of(receiver, api)?;
-        if !info.resource_type.check_amount(input.amount) {
+        if !info.resource_type.check_amount(amount) {
             return Err(RuntimeError::ApplicationError(
                 ApplicationError::BucketError(BucketError::InvalidAmount),
             ));
@@ -550,7 +586,7 @@ impl BucketBlueprint {
 
         let node_id = if info.resource_type.is_fungible() {
             // Take
-            let taken = FungibleBucket::take(receiver, input.amount, api)?;
+            let taken = FungibleBucket::take(receiver, amount, api)?;
 
             // Create node
             let bucket_id = api.new_object(
@@ -567,7 +603,7 @@ impl BucketBlueprint {
             RENodeId::Object(bucket_id)
         } else {
             // Take
-            let taken = NonFungibleBucket::take(receiver, input.amount, api)?;
+            let taken = NonFungibleBucket::take(receiver, amount, api)?;
 
             // Create node
             let bucket_id = api.new_object(
@@ -585,7 +621,7 @@ impl BucketBlueprint {
         };
         let bucket_id = node_id.into();
 
-        Ok(IndexedScryptoValue::from_typed(&Bucket(bucket_id)))
+        Ok(Bucket(bucket_id))
     }
 
     pub fn take_non_fungibles<Y>(
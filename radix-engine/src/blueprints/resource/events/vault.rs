@@ -1,4 +1,5 @@
 use crate::types::*;
+use radix_engine_interface::blueprints::resource::VaultFreezeFlags;
 
 #[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
 pub struct LockFeeEvent {
@@ -22,3 +23,13 @@ pub enum RecallResourceEvent {
     Amount(Decimal),
     Ids(BTreeSet<NonFungibleLocalId>),
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct FreezeVaultEvent {
+    pub frozen: VaultFreezeFlags,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct UnfreezeVaultEvent {
+    pub unfrozen: VaultFreezeFlags,
+}
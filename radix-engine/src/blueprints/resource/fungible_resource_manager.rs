@@ -369,6 +369,7 @@ impl FungibleResourceManagerBlueprint {
                 scrypto_encode(&LockedFungibleResource::default()).unwrap(),
                 scrypto_encode(&LiquidNonFungibleResource::default()).unwrap(),
                 scrypto_encode(&LockedNonFungibleResource::default()).unwrap(),
+                scrypto_encode(&VaultFrozenFlagSubstate::default()).unwrap(),
             ],
         )?;
 
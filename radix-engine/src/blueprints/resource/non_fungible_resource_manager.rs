@@ -26,6 +26,9 @@ pub enum NonFungibleResourceManagerError {
     MismatchingBucketResource,
     NonFungibleIdTypeDoesNotMatch(NonFungibleIdType, NonFungibleIdType),
     InvalidNonFungibleIdType,
+    InvalidNonFungibleSchema(SchemaValidationError),
+    InvalidMutableField(String),
+    NonFungibleDataSchemaNotMatch(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -35,7 +38,63 @@ pub struct NonFungibleResourceManagerSubstate {
     pub id_type: NonFungibleIdType,
     pub non_fungible_type_index: LocalTypeIndex,
     pub non_fungible_table: KeyValueStoreId,
-    pub mutable_fields: BTreeSet<String>, // TODO: Integrate with KeyValueStore schema check?
+    pub mutable_fields: BTreeSet<String>,
+}
+
+fn validate_non_fungible_schema(
+    non_fungible_schema: &NonFungibleDataSchema,
+) -> Result<(), RuntimeError> {
+    non_fungible_schema.schema.validate().map_err(|e| {
+        RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+            NonFungibleResourceManagerError::InvalidNonFungibleSchema(e),
+        ))
+    })?;
+
+    if non_fungible_schema
+        .schema
+        .resolve_type_kind(non_fungible_schema.non_fungible)
+        .is_none()
+    {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::InvalidNonFungibleSchema(
+                    SchemaValidationError::TypeKindInvalidSchemaLocalIndex,
+                ),
+            ),
+        ));
+    }
+
+    for field in &non_fungible_schema.mutable_fields {
+        let schema_path = SchemaPath(vec![SchemaSubPath::Field(field.clone())]);
+        if schema_path
+            .to_sbor_path(
+                &non_fungible_schema.schema,
+                non_fungible_schema.non_fungible,
+            )
+            .is_none()
+        {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::InvalidMutableField(field.clone()),
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_non_fungible_data(
+    schema: &ScryptoSchema,
+    non_fungible_type_index: LocalTypeIndex,
+    data: &ScryptoValue,
+) -> Result<(), RuntimeError> {
+    let payload = scrypto_encode(data).unwrap();
+    validate_payload_against_schema(&payload, schema, non_fungible_type_index).map_err(|e| {
+        RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+            NonFungibleResourceManagerError::NonFungibleDataSchemaNotMatch(e.error_message(schema)),
+        ))
+    })
 }
 
 fn build_non_fungible_resource_manager_substate<Y>(
@@ -48,6 +107,8 @@ fn build_non_fungible_resource_manager_substate<Y>(
 where
     Y: ClientApi<RuntimeError>,
 {
+    validate_non_fungible_schema(&non_fungible_schema)?;
+
     let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
     let non_fungible_type = aggregator.add_child_type_and_descendents::<NonFungibleLocalId>();
     let key_schema = generate_full_schema(aggregator);
@@ -115,12 +176,15 @@ fn build_non_fungible_bucket<Y>(
     resource_address: ResourceAddress,
     id_type: NonFungibleIdType,
     nf_store_id: KeyValueStoreId,
+    non_fungible_type_index: LocalTypeIndex,
     entries: BTreeMap<NonFungibleLocalId, ScryptoValue>,
     api: &mut Y,
 ) -> Result<Bucket, RuntimeError>
 where
     Y: ClientApi<RuntimeError>,
 {
+    let kv_schema = api.get_key_value_store_info(RENodeId::KeyValueStore(nf_store_id))?;
+
     let bucket = {
         let mut ids = BTreeSet::new();
         for (non_fungible_local_id, value) in entries {
@@ -135,6 +199,8 @@ where
                 ));
             }
 
+            validate_non_fungible_data(&kv_schema.schema, non_fungible_type_index, &value)?;
+
             let non_fungible_handle = api.sys_lock_substate(
                 RENodeId::KeyValueStore(nf_store_id),
                 SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
@@ -266,8 +332,14 @@ impl NonFungibleResourceManagerBlueprint {
             .map(|(id, (value,))| (id, value))
             .collect();
 
-        let bucket =
-            build_non_fungible_bucket(resource_address, id_type, nf_store_id, entries, api)?;
+        let bucket = build_non_fungible_bucket(
+            resource_address,
+            id_type,
+            nf_store_id,
+            resource_manager.non_fungible_type_index,
+            entries,
+            api,
+        )?;
 
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -312,6 +384,7 @@ impl NonFungibleResourceManagerBlueprint {
             resource_address,
             NonFungibleIdType::UUID,
             nf_store_id,
+            resource_manager.non_fungible_type_index,
             non_fungible_entries,
             api,
         )?;
@@ -394,16 +467,21 @@ impl NonFungibleResourceManagerBlueprint {
             (bucket_id, non_fungibles)
         };
 
-        let (nf_store_id, resource_address) = {
+        let (nf_store_id, resource_address, non_fungible_type_index) = {
             let resource_manager: &NonFungibleResourceManagerSubstate =
                 api.kernel_get_substate_ref(resman_handle)?;
             (
                 resource_manager.non_fungible_table,
                 resource_manager.resource_address,
+                resource_manager.non_fungible_type_index,
             )
         };
 
+        let kv_schema = api.get_key_value_store_info(RENodeId::KeyValueStore(nf_store_id))?;
+
         for (id, non_fungible) in non_fungibles {
+            validate_non_fungible_data(&kv_schema.schema, non_fungible_type_index, &non_fungible)?;
+
             let non_fungible_handle = api.sys_lock_substate(
                 RENodeId::KeyValueStore(nf_store_id),
                 SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
@@ -461,6 +539,7 @@ impl NonFungibleResourceManagerBlueprint {
                 api.kernel_get_substate_ref_mut(resman_handle)?;
             let resource_address = resource_manager.resource_address;
             let nf_store_id = resource_manager.non_fungible_table;
+            let non_fungible_type_index = resource_manager.non_fungible_type_index;
             let id_type = resource_manager.id_type;
 
             if id_type != NonFungibleIdType::UUID {
@@ -473,9 +552,14 @@ impl NonFungibleResourceManagerBlueprint {
 
             let amount: Decimal = entries.len().into();
             resource_manager.total_supply += amount;
+
+            let kv_schema = api.get_key_value_store_info(RENodeId::KeyValueStore(nf_store_id))?;
+
             // Allocate non-fungibles
             let mut ids = BTreeSet::new();
             for (value,) in entries {
+                validate_non_fungible_data(&kv_schema.schema, non_fungible_type_index, &value)?;
+
                 // TODO: Is this enough bits to prevent hash collisions?
                 // TODO: Possibly use an always incrementing timestamp
                 let uuid = Runtime::generate_uuid(api)?;
@@ -576,6 +660,7 @@ impl NonFungibleResourceManagerBlueprint {
         if let Some(ref mut non_fungible) = non_fungible_entry {
             let value = sbor_path.get_from_value_mut(non_fungible).unwrap();
             *value = data;
+            validate_non_fungible_data(&kv_schema.schema, non_fungible_type_index, non_fungible)?;
 
             api.sys_write_typed_substate(non_fungible_handle, &non_fungible_entry)?;
         } else {
@@ -734,8 +819,8 @@ impl NonFungibleResourceManagerBlueprint {
                 // Check if resource matches
                 // TODO: Move this check into actor check
                 {
-                    let resource_manager: &mut NonFungibleResourceManagerSubstate =
-                        api.kernel_get_substate_ref_mut(resman_handle)?;
+                    let resource_manager: &NonFungibleResourceManagerSubstate =
+                        api.kernel_get_substate_ref(resman_handle)?;
                     if dropped_bucket.info.resource_address != resource_manager.resource_address {
                         return Err(RuntimeError::ApplicationError(
                             ApplicationError::NonFungibleResourceManagerError(
@@ -743,29 +828,75 @@ impl NonFungibleResourceManagerBlueprint {
                             ),
                         ));
                     }
+                }
 
-                    // Update total supply
-                    // TODO: there might be better for maintaining total supply, especially for non-fungibles
-                    // Update total supply
-                    resource_manager.total_supply -= resource.amount();
+                Self::burn_internal(resman_handle, resource.into_ids(), api)?;
+            }
+        }
 
-                    // Burn non-fungible
-                    let node_id = RENodeId::KeyValueStore(resource_manager.non_fungible_table);
+        Ok(())
+    }
 
-                    for id in resource.into_ids() {
-                        let non_fungible_handle = api.sys_lock_substate(
-                            node_id,
-                            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
-                                scrypto_encode(&id).unwrap(),
-                            )),
-                            LockFlags::MUTABLE,
-                        )?;
+    /// Burns non-fungibles by id without taking them from a bucket. Auth guards this with the
+    /// resource's burn rule, the same as burning a bucket.
+    pub(crate) fn burn_non_fungibles<Y>(
+        receiver: RENodeId,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let resman_handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
+            LockFlags::MUTABLE,
+        )?;
 
-                        api.sys_write_typed_substate(non_fungible_handle, None::<ScryptoValue>)?;
-                        api.sys_drop_lock(non_fungible_handle)?;
-                    }
-                }
-            }
+        Runtime::emit_event(
+            api,
+            BurnNonFungibleResourceEvent {
+                ids: non_fungible_local_ids.clone(),
+            },
+        )?;
+
+        Self::burn_internal(resman_handle, non_fungible_local_ids, api)?;
+
+        api.sys_drop_lock(resman_handle)?;
+
+        Ok(())
+    }
+
+    fn burn_internal<Y>(
+        resman_handle: LockHandle,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let resource_manager: &mut NonFungibleResourceManagerSubstate =
+            api.kernel_get_substate_ref_mut(resman_handle)?;
+
+        // Update total supply
+        // TODO: there might be better for maintaining total supply, especially for non-fungibles
+        let amount: Decimal = non_fungible_local_ids.len().into();
+        resource_manager.total_supply -= amount;
+
+        // Burn non-fungible
+        let node_id = RENodeId::KeyValueStore(resource_manager.non_fungible_table);
+
+        for id in non_fungible_local_ids {
+            let non_fungible_handle = api.sys_lock_substate(
+                node_id,
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
+                    scrypto_encode(&id).unwrap(),
+                )),
+                LockFlags::MUTABLE,
+            )?;
+
+            api.sys_write_typed_substate(non_fungible_handle, None::<ScryptoValue>)?;
+            api.sys_drop_lock(non_fungible_handle)?;
         }
 
         Ok(())
@@ -797,6 +928,7 @@ impl NonFungibleResourceManagerBlueprint {
                 scrypto_encode(&LockedFungibleResource::default()).unwrap(),
                 scrypto_encode(&LiquidNonFungibleResource::default()).unwrap(),
                 scrypto_encode(&LockedNonFungibleResource::default()).unwrap(),
+                scrypto_encode(&VaultFrozenFlagSubstate::default()).unwrap(),
             ],
         )?;
 
@@ -34,6 +34,8 @@ const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str = "create_NonFungib
 const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME: &str =
     "create_with_initial_supply_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME: &str = "burn_NonFungibleResourceManager";
+const NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_EXPORT_NAME: &str =
+    "burn_non_fungibles_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME: &str = "mint_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_UUID_EXPORT_NAME: &str =
     "mint_uuid_NonFungibleResourceManager";
@@ -316,6 +318,20 @@ impl ResourceManagerNativePackage {
                     export_name: NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_IDENT.to_string(),
+                FunctionSchema {
+                    receiver: Some(Receiver::SelfRefMut),
+                    input: aggregator.add_child_type_and_descendents::<
+                        NonFungibleResourceManagerBurnNonFungiblesInput,
+                    >(),
+                    output: aggregator.add_child_type_and_descendents::<
+                        NonFungibleResourceManagerBurnNonFungiblesOutput,
+                    >(),
+                    export_name: NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_EXPORT_NAME
+                        .to_string(),
+                },
+            );
             functions.insert(
                 RESOURCE_MANAGER_CREATE_VAULT_IDENT.to_string(),
                 FunctionSchema {
@@ -377,6 +393,7 @@ impl ResourceManagerNativePackage {
         substates.push(aggregator.add_child_type_and_descendents::<LockedFungibleResource>());
         substates.push(aggregator.add_child_type_and_descendents::<LiquidNonFungibleResource>());
         substates.push(aggregator.add_child_type_and_descendents::<LockedNonFungibleResource>());
+        substates.push(aggregator.add_child_type_and_descendents::<VaultFrozenFlagSubstate>());
 
         let mut functions = BTreeMap::new();
         functions.insert(
@@ -397,6 +414,15 @@ impl ResourceManagerNativePackage {
                 export_name: VAULT_TAKE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VAULT_TAKE_ADVANCED_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<VaultTakeAdvancedInput>(),
+                output: aggregator.add_child_type_and_descendents::<VaultTakeAdvancedOutput>(),
+                export_name: VAULT_TAKE_ADVANCED_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VAULT_TAKE_NON_FUNGIBLES_IDENT.to_string(),
             FunctionSchema {
@@ -425,6 +451,42 @@ impl ResourceManagerNativePackage {
                 export_name: VAULT_RECALL_NON_FUNGIBLES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VAULT_BURN_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<VaultBurnInput>(),
+                output: aggregator.add_child_type_and_descendents::<VaultBurnOutput>(),
+                export_name: VAULT_BURN_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_BURN_NON_FUNGIBLES_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<VaultBurnNonFungiblesInput>(),
+                output: aggregator.add_child_type_and_descendents::<VaultBurnNonFungiblesOutput>(),
+                export_name: VAULT_BURN_NON_FUNGIBLES_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_FREEZE_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<VaultFreezeInput>(),
+                output: aggregator.add_child_type_and_descendents::<VaultFreezeOutput>(),
+                export_name: VAULT_FREEZE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VAULT_UNFREEZE_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<VaultUnfreezeInput>(),
+                output: aggregator.add_child_type_and_descendents::<VaultUnfreezeOutput>(),
+                export_name: VAULT_UNFREEZE_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VAULT_PUT_IDENT.to_string(),
             FunctionSchema {
@@ -536,7 +598,9 @@ impl ResourceManagerNativePackage {
                 LockFeeEvent,
                 WithdrawResourceEvent,
                 DepositResourceEvent,
-                RecallResourceEvent
+                RecallResourceEvent,
+                FreezeVaultEvent,
+                UnfreezeVaultEvent
             ]
         };
 
@@ -594,6 +658,15 @@ impl ResourceManagerNativePackage {
                 export_name: BUCKET_TAKE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            BUCKET_TAKE_ADVANCED_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<BucketTakeAdvancedInput>(),
+                output: aggregator.add_child_type_and_descendents::<BucketTakeAdvancedOutput>(),
+                export_name: BUCKET_TAKE_ADVANCED_IDENT.to_string(),
+            },
+        );
         functions.insert(
             BUCKET_TAKE_NON_FUNGIBLES_IDENT.to_string(),
             FunctionSchema {
@@ -785,6 +858,15 @@ impl ResourceManagerNativePackage {
                 export_name: WORKTOP_TAKE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            WORKTOP_TAKE_ADVANCED_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<WorktopTakeAdvancedInput>(),
+                output: aggregator.add_child_type_and_descendents::<WorktopTakeAdvancedOutput>(),
+                export_name: WORKTOP_TAKE_ADVANCED_IDENT.to_string(),
+            },
+        );
         functions.insert(
             WORKTOP_TAKE_NON_FUNGIBLES_IDENT.to_string(),
             FunctionSchema {
@@ -1145,6 +1227,23 @@ impl ResourceManagerNativePackage {
                 let rtn = NonFungibleResourceManagerBlueprint::burn(receiver, input.bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_EXPORT_NAME => {
+                api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                let input: NonFungibleResourceManagerBurnNonFungiblesInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::burn_non_fungibles(
+                    receiver,
+                    input.non_fungible_local_ids,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_BUCKET_EXPORT_NAME => {
                 api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
 
@@ -1260,6 +1359,14 @@ impl ResourceManagerNativePackage {
                 ))?;
                 VaultBlueprint::take(receiver, input, api)
             }
+            VAULT_TAKE_ADVANCED_IDENT => {
+                api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                VaultBlueprint::take_advanced(receiver, input, api)
+            }
             VAULT_TAKE_NON_FUNGIBLES_IDENT => {
                 api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
 
@@ -1284,6 +1391,38 @@ impl ResourceManagerNativePackage {
                 ))?;
                 VaultBlueprint::recall_non_fungibles(receiver, input, api)
             }
+            VAULT_BURN_IDENT => {
+                api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                VaultBlueprint::burn(receiver, input, api)
+            }
+            VAULT_BURN_NON_FUNGIBLES_IDENT => {
+                api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                VaultBlueprint::burn_non_fungibles(receiver, input, api)
+            }
+            VAULT_FREEZE_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                VaultBlueprint::freeze(receiver, input, api)
+            }
+            VAULT_UNFREEZE_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                VaultBlueprint::unfreeze(receiver, input, api)
+            }
             VAULT_PUT_IDENT => {
                 api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
 
@@ -1456,6 +1595,14 @@ impl ResourceManagerNativePackage {
                 ))?;
                 BucketBlueprint::take(receiver, input, api)
             }
+            BUCKET_TAKE_ADVANCED_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                BucketBlueprint::take_advanced(receiver, input, api)
+            }
             BUCKET_TAKE_NON_FUNGIBLES_IDENT => {
                 api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
 
@@ -1555,6 +1702,14 @@ impl ResourceManagerNativePackage {
                 ))?;
                 WorktopBlueprint::take(receiver, input, api)
             }
+            WORKTOP_TAKE_ADVANCED_IDENT => {
+                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                WorktopBlueprint::take_advanced(receiver, input, api)
+            }
             WORKTOP_TAKE_NON_FUNGIBLES_IDENT => {
                 api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
 
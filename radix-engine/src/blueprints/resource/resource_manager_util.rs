@@ -67,11 +67,24 @@ fn build_access_rules(
         DenyAll,
     );
 
-    resman_access_rules.set_access_rule_and_mutability(
-        MethodKey::new(NodeModuleId::SELF, RESOURCE_MANAGER_BURN_IDENT.to_string()),
+    resman_access_rules.set_group_access_rule_and_mutability(
+        "burn".to_string(),
         burn_access_rule,
         burn_mutability,
     );
+    resman_access_rules.set_group_and_mutability(
+        MethodKey::new(NodeModuleId::SELF, RESOURCE_MANAGER_BURN_IDENT.to_string()),
+        "burn".to_string(),
+        DenyAll,
+    );
+    resman_access_rules.set_group_and_mutability(
+        MethodKey::new(
+            NodeModuleId::SELF,
+            NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_IDENT.to_string(),
+        ),
+        "burn".to_string(),
+        DenyAll,
+    );
     resman_access_rules.set_access_rule_and_mutability(
         MethodKey::new(
             NodeModuleId::SELF,
@@ -138,6 +151,9 @@ fn build_access_rules(
     let (recall_access_rule, recall_mutability) = access_rules_map
         .remove(&ResourceMethodAuthKey::Recall)
         .unwrap_or((DenyAll, rule!(deny_all)));
+    let (freeze_access_rule, freeze_mutability) = access_rules_map
+        .remove(&ResourceMethodAuthKey::Freeze)
+        .unwrap_or((DenyAll, rule!(deny_all)));
 
     let mut vault_access_rules = AccessRulesConfig::new();
     vault_access_rules.set_group_access_rule_and_mutability(
@@ -150,11 +166,21 @@ fn build_access_rules(
         recall_access_rule,
         recall_mutability,
     );
+    vault_access_rules.set_group_access_rule_and_mutability(
+        "freeze".to_string(),
+        freeze_access_rule,
+        freeze_mutability,
+    );
     vault_access_rules.set_group_and_mutability(
         MethodKey::new(NodeModuleId::SELF, VAULT_TAKE_IDENT.to_string()),
         "withdraw".to_string(),
         DenyAll,
     );
+    vault_access_rules.set_group_and_mutability(
+        MethodKey::new(NodeModuleId::SELF, VAULT_TAKE_ADVANCED_IDENT.to_string()),
+        "withdraw".to_string(),
+        DenyAll,
+    );
     vault_access_rules.set_group_and_mutability(
         MethodKey::new(
             NodeModuleId::SELF,
@@ -168,6 +194,16 @@ fn build_access_rules(
         "withdraw".to_string(),
         DenyAll,
     );
+    vault_access_rules.set_group_and_mutability(
+        MethodKey::new(NodeModuleId::SELF, VAULT_FREEZE_IDENT.to_string()),
+        "freeze".to_string(),
+        DenyAll,
+    );
+    vault_access_rules.set_group_and_mutability(
+        MethodKey::new(NodeModuleId::SELF, VAULT_UNFREEZE_IDENT.to_string()),
+        "freeze".to_string(),
+        DenyAll,
+    );
 
     vault_access_rules.set_access_rule_and_mutability(
         MethodKey::new(NodeModuleId::SELF, VAULT_PUT_IDENT.to_string()),
@@ -5,6 +5,7 @@ use crate::kernel::heap::{DroppedBucket, DroppedBucketResource};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::system::kernel_modules::costing::CostingError;
 use crate::types::*;
+use native_sdk::resource::ResourceManager;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::substate_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
@@ -18,6 +19,7 @@ pub enum VaultError {
     NonFungibleOperationNotSupported,
     MismatchingResource,
     InvalidAmount,
+    VaultIsFrozen,
 
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance,
@@ -47,6 +49,48 @@ impl VaultInfoSubstate {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct VaultFrozenFlagSubstate {
+    pub frozen: VaultFreezeFlags,
+}
+
+impl Default for VaultFrozenFlagSubstate {
+    fn default() -> Self {
+        Self {
+            frozen: VaultFreezeFlags::empty(),
+        }
+    }
+}
+
+impl VaultFrozenFlagSubstate {
+    /// Fails if any of the given operations are frozen on the vault.
+    pub fn assert_not_frozen<Y>(
+        node_id: RENodeId,
+        flags: VaultFreezeFlags,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientSubstateApi<RuntimeError>,
+    {
+        let handle = api.sys_lock_substate(
+            node_id,
+            SubstateOffset::Vault(VaultOffset::FrozenFlag),
+            LockFlags::read_only(),
+        )?;
+        let substate_ref: &VaultFrozenFlagSubstate = api.kernel_get_substate_ref(handle)?;
+        let is_frozen = substate_ref.frozen.intersects(flags);
+        api.sys_drop_lock(handle)?;
+
+        if is_frozen {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::VaultIsFrozen),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 pub struct FungibleVault;
 
 impl FungibleVault {
@@ -521,9 +565,47 @@ impl VaultBlueprint {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
+        let bucket = Self::take_internal(receiver, input.amount, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    pub fn take_advanced<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: VaultTakeAdvancedInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        // Round amount
+        let info = VaultInfoSubstate::of(receiver, api)?;
+        let amount = info
+            .resource_type
+            .round_amount(input.amount, input.rounding_mode);
+
+        let bucket = Self::take_internal(receiver, amount, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    fn take_internal<Y>(
+        receiver: RENodeId,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        VaultFrozenFlagSubstate::assert_not_frozen(receiver, VaultFreezeFlags::WITHDRAW, api)?;
+
         // Check amount
         let info = VaultInfoSubstate::of(receiver, api)?;
-        if !info.resource_type.check_amount(input.amount) {
+        if !info.resource_type.check_amount(amount) {
             return Err(RuntimeError::ApplicationError(
                 ApplicationError::VaultError(VaultError::InvalidAmount),
             ));
@@ -531,7 +613,7 @@ impl VaultBlueprint {
 
         let node_id = if info.resource_type.is_fungible() {
             // Take
-            let taken = FungibleVault::take(receiver, input.amount, api)?;
+            let taken = FungibleVault::take(receiver, amount, api)?;
 
             // Create node
             let bucket_id = api.new_object(
@@ -552,7 +634,7 @@ impl VaultBlueprint {
             RENodeId::Object(bucket_id)
         } else {
             // Take
-            let taken = NonFungibleVault::take(receiver, input.amount, api)?;
+            let taken = NonFungibleVault::take(receiver, amount, api)?;
 
             // Create node
             let bucket_id = api.new_object(
@@ -574,7 +656,7 @@ impl VaultBlueprint {
         };
         let bucket_id = node_id.into();
 
-        Ok(IndexedScryptoValue::from_typed(&Bucket(bucket_id)))
+        Ok(Bucket(bucket_id))
     }
 
     pub fn take_non_fungibles<Y>(
@@ -589,6 +671,8 @@ impl VaultBlueprint {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
+        VaultFrozenFlagSubstate::assert_not_frozen(receiver, VaultFreezeFlags::WITHDRAW, api)?;
+
         let info = VaultInfoSubstate::of(receiver, api)?;
 
         if info.resource_type.is_fungible() {
@@ -632,6 +716,8 @@ impl VaultBlueprint {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
+        VaultFrozenFlagSubstate::assert_not_frozen(receiver, VaultFreezeFlags::DEPOSIT, api)?;
+
         // Drop other bucket
         let other_bucket: DroppedBucket = api
             .kernel_drop_node(RENodeId::Object(input.bucket.0))?
@@ -736,6 +822,8 @@ impl VaultBlueprint {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
+        VaultFrozenFlagSubstate::assert_not_frozen(receiver, VaultFreezeFlags::WITHDRAW, api)?;
+
         // Check resource address
         let info = VaultInfoSubstate::of(receiver, api)?;
         if info.resource_address != RADIX_TOKEN {
@@ -895,6 +983,145 @@ impl VaultBlueprint {
         }
     }
 
+    pub fn burn<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: VaultBurnInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        VaultFrozenFlagSubstate::assert_not_frozen(receiver, VaultFreezeFlags::BURN, api)?;
+
+        let info = VaultInfoSubstate::of(receiver, api)?;
+        if !info.resource_type.check_amount(input.amount) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::InvalidAmount),
+            ));
+        }
+
+        if info.resource_type.is_fungible() {
+            let taken = FungibleVault::take(receiver, input.amount, api)?;
+            let bucket_id = api.new_object(
+                BUCKET_BLUEPRINT,
+                vec![
+                    scrypto_encode(&BucketInfoSubstate {
+                        resource_address: info.resource_address,
+                        resource_type: info.resource_type,
+                    })
+                    .unwrap(),
+                    scrypto_encode(&taken).unwrap(),
+                    scrypto_encode(&LockedFungibleResource::default()).unwrap(),
+                    scrypto_encode(&LiquidNonFungibleResource::default()).unwrap(),
+                    scrypto_encode(&LockedNonFungibleResource::default()).unwrap(),
+                ],
+            )?;
+
+            ResourceManager(info.resource_address).burn(Bucket(bucket_id), api)?;
+        } else {
+            let taken = NonFungibleVault::take(receiver, input.amount, api)?;
+
+            ResourceManager(info.resource_address).burn_non_fungibles(taken.into_ids(), api)?;
+        }
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn burn_non_fungibles<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: VaultBurnNonFungiblesInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        VaultFrozenFlagSubstate::assert_not_frozen(receiver, VaultFreezeFlags::BURN, api)?;
+
+        let info = VaultInfoSubstate::of(receiver, api)?;
+        if info.resource_type.is_fungible() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::NonFungibleOperationNotSupported),
+            ));
+        }
+
+        let taken =
+            NonFungibleVault::take_non_fungibles(receiver, &input.non_fungible_local_ids, api)?;
+
+        ResourceManager(info.resource_address).burn_non_fungibles(taken.into_ids(), api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn freeze<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: VaultFreezeInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Vault(VaultOffset::FrozenFlag),
+            LockFlags::MUTABLE,
+        )?;
+        let frozen_flag: &mut VaultFrozenFlagSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        frozen_flag.frozen.insert(input.to_freeze);
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(
+            api,
+            FreezeVaultEvent {
+                frozen: input.to_freeze,
+            },
+        )?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn unfreeze<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: VaultUnfreezeInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Vault(VaultOffset::FrozenFlag),
+            LockFlags::MUTABLE,
+        )?;
+        let frozen_flag: &mut VaultFrozenFlagSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        frozen_flag.frozen.remove(input.to_unfreeze);
+        api.sys_drop_lock(handle)?;
+
+        Runtime::emit_event(
+            api,
+            UnfreezeVaultEvent {
+                unfrozen: input.to_unfreeze,
+            },
+        )?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
     pub fn create_proof<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
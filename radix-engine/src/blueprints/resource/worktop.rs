@@ -110,12 +110,43 @@ impl WorktopBlueprint {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
-        let resource_address = input.resource_address;
-        let amount = input.amount;
+        let bucket = Self::take_internal(receiver, input.resource_address, input.amount, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    pub(crate) fn take_advanced<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: WorktopTakeAdvancedInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
 
+        // Round amount
+        let resource_type = ResourceManager(input.resource_address).resource_type(api)?;
+        let amount = resource_type.round_amount(input.amount, input.rounding_mode);
+
+        let bucket = Self::take_internal(receiver, input.resource_address, amount, api)?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    fn take_internal<Y>(
+        receiver: RENodeId,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
         if amount.is_zero() {
-            let bucket = ResourceManager(resource_address).new_empty_bucket(api)?;
-            Ok(IndexedScryptoValue::from_typed(&bucket))
+            ResourceManager(resource_address).new_empty_bucket(api)
         } else {
             let worktop_handle = api.sys_lock_substate(
                 receiver,
@@ -145,11 +176,11 @@ impl WorktopBlueprint {
                 worktop = api.kernel_get_substate_ref_mut(worktop_handle)?;
                 worktop.resources.remove(&resource_address);
                 api.sys_drop_lock(worktop_handle)?;
-                Ok(IndexedScryptoValue::from_typed(&existing_bucket))
+                Ok(existing_bucket)
             } else {
                 let bucket = existing_bucket.sys_take(amount, api)?;
                 api.sys_drop_lock(worktop_handle)?;
-                Ok(IndexedScryptoValue::from_typed(&bucket))
+                Ok(bucket)
             }
         }
     }
@@ -125,6 +125,16 @@ impl TransactionProcessorBlueprint {
                     processor.create_manifest_bucket(bucket)?;
                     InstructionOutput::None
                 }
+                Instruction::TakeFromWorktopAdvanced {
+                    amount,
+                    resource_address,
+                    rounding_mode,
+                } => {
+                    let bucket =
+                        worktop.sys_take_advanced(resource_address, amount, rounding_mode, api)?;
+                    processor.create_manifest_bucket(bucket)?;
+                    InstructionOutput::None
+                }
                 Instruction::ReturnToWorktop { bucket_id } => {
                     let bucket = processor.take_bucket(&bucket_id)?;
                     worktop.sys_put(bucket, api)?;
@@ -326,6 +336,22 @@ impl TransactionProcessorBlueprint {
                     )?;
                     InstructionOutput::CallReturn(result.into())
                 }
+                Instruction::BurnNonFungibles {
+                    resource_address,
+                    ids,
+                } => {
+                    let rtn = api.call_method(
+                        RENodeId::GlobalObject(resource_address.into()),
+                        NON_FUNGIBLE_RESOURCE_MANAGER_BURN_NON_FUNGIBLES_IDENT,
+                        scrypto_encode(&NonFungibleResourceManagerBurnNonFungiblesInput {
+                            non_fungible_local_ids: ids,
+                        })
+                        .unwrap(),
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn).unwrap();
+                    InstructionOutput::CallReturn(result.into())
+                }
                 Instruction::MintFungible {
                     resource_address,
                     amount,
@@ -401,6 +427,26 @@ impl TransactionProcessorBlueprint {
                     )?;
                     InstructionOutput::CallReturn(result.into())
                 }
+                Instruction::FreezeVault { vault_id, flags } => {
+                    let rtn = api.call_method(
+                        RENodeId::Object(vault_id),
+                        VAULT_FREEZE_IDENT,
+                        scrypto_encode(&VaultFreezeInput { to_freeze: flags }).unwrap(),
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn).unwrap();
+                    InstructionOutput::CallReturn(result.into())
+                }
+                Instruction::UnfreezeVault { vault_id, flags } => {
+                    let rtn = api.call_method(
+                        RENodeId::Object(vault_id),
+                        VAULT_UNFREEZE_IDENT,
+                        scrypto_encode(&VaultUnfreezeInput { to_unfreeze: flags }).unwrap(),
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn).unwrap();
+                    InstructionOutput::CallReturn(result.into())
+                }
                 Instruction::SetMetadata {
                     entity_address,
                     key,
@@ -201,6 +201,8 @@ pub enum SystemError {
     NotAKeyValueStore,
     InvalidSubstateWrite,
     InvalidKeyValueStoreOwnership,
+    CannotRemoveKeyValueStoreEntryWithOwnedNodes,
+    KeyValueStoreScanLimitExceeded(u32),
     InvalidLockFlags,
     InvalidKeyValueStoreSchema(SchemaValidationError),
     CannotGlobalize,
@@ -295,6 +295,48 @@ impl CallFrame {
         Ok(())
     }
 
+    /// Returns up to `limit` non-empty entries of a visible key value store, starting at
+    /// `start_key` (inclusive), together with the number of entries read. Global references in
+    /// the returned values become visible to this frame; owned nodes do not, and can only be
+    /// accessed by locking the entry.
+    pub fn scan_key_value_store<'s>(
+        &mut self,
+        heap: &mut Heap,
+        track: &mut Track<'s>,
+        node_id: RENodeId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<(Vec<(Vec<u8>, ScryptoValue)>, usize), RuntimeError> {
+        self.check_node_visibility(&node_id)?;
+
+        let (entries, read_count) = if heap.contains_node(&node_id) {
+            heap.scan_key_value_store(node_id, start_key, limit)?
+        } else {
+            match node_id {
+                RENodeId::KeyValueStore(kv_store_id) => {
+                    track.scan_key_value_store(&kv_store_id, start_key, limit)
+                }
+                _ => (Vec::new(), 0),
+            }
+        };
+
+        for (_, value) in &entries {
+            let value = IndexedScryptoValue::from_scrypto_value(value.clone());
+            for node_id in value.references() {
+                if matches!(node_id, RENodeId::GlobalObject(_)) {
+                    self.immortal_node_refs.insert(
+                        *node_id,
+                        RENodeRefData {
+                            visibility: RENodeVisibilityOrigin::Normal,
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok((entries, read_count))
+    }
+
     pub fn get_lock_info(&self, lock_handle: LockHandle) -> Result<LockInfo, RuntimeError> {
         let substate_lock = self
             .locks
@@ -1,16 +1,18 @@
 use super::track::Track;
 use crate::blueprints::resource::*;
 use crate::errors::{CallFrameError, OffsetDoesNotExist};
+use crate::ledger::kv_store_entry_scan_key;
 use crate::system::node_modules::access_rules::AuthZoneStackSubstate;
 use crate::system::node_substates::{RuntimeSubstate, SubstateRef, SubstateRefMut};
 use crate::types::HashMap;
 use radix_engine_interface::api::types::{
-    AuthZoneStackOffset, BucketOffset, NodeModuleId, ProofOffset, RENodeId, SubstateId,
-    SubstateOffset,
+    AuthZoneStackOffset, BucketOffset, KeyValueStoreOffset, NodeModuleId, ProofOffset, RENodeId,
+    SubstateId, SubstateOffset,
 };
 use radix_engine_interface::blueprints::resource::{
     LiquidFungibleResource, LiquidNonFungibleResource, ResourceType,
 };
+use radix_engine_interface::data::scrypto::ScryptoValue;
 use radix_engine_interface::math::Decimal;
 use sbor::rust::boxed::Box;
 use sbor::rust::collections::BTreeMap;
@@ -97,6 +99,58 @@ impl Heap {
         }
     }
 
+    /// Returns up to `limit` non-empty entries of a key value store in scan order (see
+    /// [`kv_store_entry_scan_key`]), starting at `start_key` (inclusive), together with the
+    /// number of entries read.
+    pub fn scan_key_value_store(
+        &self,
+        node_id: RENodeId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<(Vec<(Vec<u8>, ScryptoValue)>, usize), CallFrameError> {
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or(CallFrameError::RENodeNotOwned(node_id))?;
+
+        // Substates are ordered by offset, i.e. entries are ordered by key rather than scan key
+        let start_scan_key = start_key.map(kv_store_entry_scan_key).unwrap_or_default();
+        let first_entry = (
+            NodeModuleId::SELF,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(Vec::new())),
+        );
+        let mut entries: Vec<(Vec<u8>, &Vec<u8>, &Option<ScryptoValue>)> = node
+            .substates
+            .range(first_entry..)
+            .map_while(
+                |((module_id, offset), substate)| match (module_id, offset, substate) {
+                    (
+                        NodeModuleId::SELF,
+                        SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+                        RuntimeSubstate::KeyValueStoreEntry(value),
+                    ) => Some((kv_store_entry_scan_key(key), key, value)),
+                    _ => None,
+                },
+            )
+            .filter(|(scan_key, _, _)| scan_key >= &start_scan_key)
+            .collect();
+        entries.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+        let mut selected = Vec::new();
+        let mut read_count = 0;
+        for (_, key, value) in entries {
+            if selected.len() == limit {
+                break;
+            }
+            read_count += 1;
+            if let Some(value) = value {
+                selected.push((key.clone(), value.clone()));
+            }
+        }
+
+        Ok((selected, read_count))
+    }
+
     pub fn create_node(&mut self, node_id: RENodeId, node: HeapRENode) {
         self.nodes.insert(node_id, node);
     }
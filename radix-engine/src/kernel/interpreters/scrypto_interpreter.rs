@@ -148,6 +148,9 @@ impl ExecutableInvocation for MethodInvocation {
         {
             if package_address.eq(&PACKAGE_PACKAGE) {
                 node_refs_to_copy.insert(RENodeId::GlobalObject(RADIX_TOKEN.into()));
+            } else if package_address.eq(&EPOCH_MANAGER_PACKAGE) {
+                // Required for time-based epoch changes
+                node_refs_to_copy.insert(RENodeId::GlobalObject(CLOCK.into()));
             } else {
                 let handle = api.kernel_lock_substate(
                     RENodeId::GlobalObject(fn_identifier.package_address.into()),
@@ -205,6 +205,37 @@ where
         Ok(())
     }
 
+    fn remove_key_value_entry(
+        &mut self,
+        node_id: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id =
+            scrypto_decode::<RENodeId>(&node_id).map_err(WasmRuntimeError::InvalidNodeId)?;
+        let removed = self.api.sys_remove_key_value_entry(node_id, key)?;
+
+        let buffer = scrypto_encode(&removed).expect("Failed to encode removed entry");
+        self.allocate_buffer(buffer)
+    }
+
+    fn scan_key_value_store(
+        &mut self,
+        node_id: Vec<u8>,
+        start_key: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id =
+            scrypto_decode::<RENodeId>(&node_id).map_err(WasmRuntimeError::InvalidNodeId)?;
+        let start_key = scrypto_decode::<Option<Vec<u8>>>(&start_key)
+            .map_err(WasmRuntimeError::InvalidStartKey)?;
+        let entries = self
+            .api
+            .sys_scan_key_value_store(node_id, start_key, limit)?;
+
+        let buffer = scrypto_encode(&entries).expect("Failed to encode entries");
+        self.allocate_buffer(buffer)
+    }
+
     fn get_actor(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let actor = self.api.get_fn_identifier()?;
 
@@ -271,6 +302,88 @@ where
 
         self.allocate_buffer(scrypto_encode(&uuid).expect("Failed to encode UUID"))
     }
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let output = self.api.blake2b_256_hash(data)?;
+
+        self.allocate_buffer(scrypto_encode(&output).expect("Failed to encode hash"))
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let output = self.api.sha256_hash(data)?;
+
+        self.allocate_buffer(scrypto_encode(&output).expect("Failed to encode hash"))
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let output = self.api.keccak256_hash(data)?;
+
+        self.allocate_buffer(scrypto_encode(&output).expect("Failed to encode hash"))
+    }
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>> {
+        let message_hash =
+            scrypto_decode::<Hash>(&message_hash).map_err(WasmRuntimeError::InvalidHash)?;
+        let public_key = scrypto_decode::<EcdsaSecp256k1PublicKey>(&public_key)
+            .map_err(WasmRuntimeError::InvalidPublicKey)?;
+        let signature = scrypto_decode::<EcdsaSecp256k1Signature>(&signature)
+            .map_err(WasmRuntimeError::InvalidSignature)?;
+        let output = self
+            .api
+            .verify_ecdsa_secp256k1(message_hash, public_key, signature)?;
+
+        Ok(output)
+    }
+
+    fn recover_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let message_hash =
+            scrypto_decode::<Hash>(&message_hash).map_err(WasmRuntimeError::InvalidHash)?;
+        let signature = scrypto_decode::<EcdsaSecp256k1Signature>(&signature)
+            .map_err(WasmRuntimeError::InvalidSignature)?;
+        let output = self.api.recover_ecdsa_secp256k1(message_hash, signature)?;
+
+        self.allocate_buffer(scrypto_encode(&output).expect("Failed to encode public key"))
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>> {
+        let public_key = scrypto_decode::<EddsaEd25519PublicKey>(&public_key)
+            .map_err(WasmRuntimeError::InvalidPublicKey)?;
+        let signature = scrypto_decode::<EddsaEd25519Signature>(&signature)
+            .map_err(WasmRuntimeError::InvalidSignature)?;
+        let output = self
+            .api
+            .verify_eddsa_ed25519(message, public_key, signature)?;
+
+        Ok(output)
+    }
+
+    fn verify_bls12381_aggregate(
+        &mut self,
+        messages: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>> {
+        let messages = scrypto_decode::<Vec<(Bls12381G1PublicKey, Vec<u8>)>>(&messages)
+            .map_err(WasmRuntimeError::InvalidMessages)?;
+        let signature = scrypto_decode::<Bls12381G2Signature>(&signature)
+            .map_err(WasmRuntimeError::InvalidSignature)?;
+        let output = self.api.verify_bls12381_aggregate(messages, signature)?;
+
+        Ok(output)
+    }
 }
 
 /// A `Nop` runtime accepts any external function calls by doing nothing and returning void.
@@ -368,6 +481,23 @@ impl WasmRuntime for NopWasmRuntime {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn remove_key_value_entry(
+        &mut self,
+        node_id: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn scan_key_value_store(
+        &mut self,
+        node_id: Vec<u8>,
+        start_key: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn drop_lock(&mut self, handle: u32) -> Result<(), InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
@@ -419,4 +549,50 @@ impl WasmRuntime for NopWasmRuntime {
     fn generate_uuid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn recover_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn verify_bls12381_aggregate(
+        &mut self,
+        messages: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
 }
@@ -11,7 +11,7 @@ use super::kernel_api::{
 use super::module::KernelModule;
 use super::module_mixer::KernelModuleMixer;
 use super::track::{Track, TrackError};
-use crate::blueprints::account::AccountSubstate;
+use crate::blueprints::account::{access_rules_from_withdraw_rule, AccountSubstate};
 use crate::blueprints::identity::IdentityBlueprint;
 use crate::blueprints::resource::*;
 use crate::errors::*;
@@ -31,9 +31,7 @@ use radix_engine_interface::api::types::{
     LockHandle, ProofOffset, RENodeId, SubstateId, SubstateOffset,
 };
 use radix_engine_interface::api::ClientObjectApi;
-use radix_engine_interface::blueprints::account::{
-    ACCOUNT_BLUEPRINT, ACCOUNT_DEPOSIT_BATCH_IDENT, ACCOUNT_DEPOSIT_IDENT,
-};
+use radix_engine_interface::blueprints::account::{AccountDepositRule, ACCOUNT_BLUEPRINT};
 use radix_engine_interface::blueprints::package::PackageCodeSubstate;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::rule;
@@ -164,6 +162,7 @@ where
 
                 let account_substate = AccountSubstate {
                     vaults: Own::KeyValueStore(kv_store_id.into()),
+                    deposit_rule: AccountDepositRule::AcceptAll,
                 };
 
                 let node_id = self.kernel_allocate_node_id(AllocateEntityType::Object)?;
@@ -176,22 +175,8 @@ where
             node_id
         };
 
-        let access_rules = {
-            let mut access_rules = AccessRulesConfig::new();
-            access_rules.set_access_rule_and_mutability(
-                MethodKey::new(NodeModuleId::SELF, ACCOUNT_DEPOSIT_IDENT.to_string()),
-                AccessRule::AllowAll,
-                AccessRule::DenyAll,
-            );
-            access_rules.set_access_rule_and_mutability(
-                MethodKey::new(NodeModuleId::SELF, ACCOUNT_DEPOSIT_BATCH_IDENT.to_string()),
-                AccessRule::AllowAll,
-                AccessRule::DenyAll,
-            );
-            access_rules.default(access_rule.clone(), access_rule)
-        };
-
-        let access_rules = AccessRulesObject::sys_new(access_rules, self)?;
+        let access_rules =
+            AccessRulesObject::sys_new(access_rules_from_withdraw_rule(access_rule), self)?;
         let metadata = Metadata::sys_create(self)?;
         let royalty = ComponentRoyalty::sys_create(RoyaltyConfig::default(), self)?;
 
@@ -666,7 +651,8 @@ where
         &mut self,
         node_type: AllocateEntityType,
     ) -> Result<RENodeId, RuntimeError> {
-        // TODO: Add costing
+        KernelModuleMixer::on_allocate_node_id(self, &node_type)?;
+
         let node_id = self.id_allocator.allocate_node_id(node_type)?;
 
         Ok(node_id)
@@ -1028,6 +1014,48 @@ where
         Ok(substate_ref.into())
     }
 
+    fn kernel_scan_key_value_store(
+        &mut self,
+        kv_store_id: KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<(Vec<(Vec<u8>, ScryptoValue)>, usize), RuntimeError> {
+        let node_id = RENodeId::KeyValueStore(kv_store_id);
+
+        // Authorization, as for reading the entry at the start key
+        if let Some(actor) = &self.current_frame.actor {
+            let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
+                start_key.map(|key| key.to_vec()).unwrap_or_default(),
+            ));
+            let flags = LockFlags::read_only();
+            if !VisibilityProperties::check_substate_access(
+                self.execution_mode,
+                actor,
+                node_id,
+                offset.clone(),
+                flags,
+            ) {
+                return Err(RuntimeError::KernelError(
+                    KernelError::InvalidSubstateAccess(Box::new(InvalidSubstateAccess {
+                        mode: self.execution_mode,
+                        actor: actor.clone(),
+                        node_id,
+                        offset,
+                        flags,
+                    })),
+                ));
+            }
+        }
+
+        self.current_frame.scan_key_value_store(
+            &mut self.heap,
+            &mut self.track,
+            node_id,
+            start_key,
+            limit,
+        )
+    }
+
     fn kernel_get_substate_ref_mut<'a, 'b, S>(
         &'b mut self,
         lock_handle: LockHandle,
@@ -72,6 +72,16 @@ pub trait KernelSubstateApi {
         &'a S: From<SubstateRef<'a>>,
         'b: 'a;
 
+    /// Returns up to `limit` non-empty entries of a visible key value store in scan order,
+    /// starting at `start_key` (inclusive), together with the number of entries read to find
+    /// them (including removed ones)
+    fn kernel_scan_key_value_store(
+        &mut self,
+        kv_store_id: KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<(Vec<(Vec<u8>, ScryptoValue)>, usize), RuntimeError>;
+
     fn kernel_get_substate_ref_mut<'a, 'b, S>(
         &'b mut self,
         lock_handle: LockHandle,
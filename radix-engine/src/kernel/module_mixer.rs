@@ -5,8 +5,8 @@ use crate::kernel::call_frame::CallFrameUpdate;
 use crate::kernel::module::KernelModule;
 use crate::system::kernel_modules::auth::AuthModule;
 use crate::system::kernel_modules::costing::CostingModule;
-use crate::system::kernel_modules::costing::FeeTable;
 use crate::system::kernel_modules::costing::SystemLoanFeeReserve;
+use crate::system::kernel_modules::costing::{CostTreeRecorder, CostingReason, FeeTable};
 use crate::system::kernel_modules::events::EventsModule;
 use crate::system::kernel_modules::execution_trace::ExecutionTraceModule;
 use crate::system::kernel_modules::kernel_trace::KernelTraceModule;
@@ -93,6 +93,7 @@ impl KernelModuleMixer {
                 fee_reserve,
                 fee_table,
                 max_call_depth: execution_config.max_call_depth,
+                cost_tree: CostTreeRecorder::default(),
             },
             node_move: NodeMoveModule {},
             auth: AuthModule {
@@ -117,6 +118,24 @@ impl KernelModuleMixer {
             events: EventsModule::default(),
         }
     }
+
+    /// Applies an execution cost, unless costing is disabled (e.g. during genesis).
+    pub fn apply_execution_cost<F>(
+        &mut self,
+        reason: CostingReason,
+        base_price: F,
+        multiplier: usize,
+    ) -> Result<(), RuntimeError>
+    where
+        F: Fn(&FeeTable) -> u32,
+    {
+        if self.enabled_modules.contains(EnabledModules::COSTING) {
+            self.costing
+                .apply_execution_cost(reason, base_price, multiplier)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 //====================================================================
@@ -3,7 +3,7 @@ use crate::errors::*;
 use crate::ledger::*;
 use crate::state_manager::StateDiff;
 use crate::system::kernel_modules::costing::FinalizingFeeReserve;
-use crate::system::kernel_modules::costing::{CostingError, FeeReserveError};
+use crate::system::kernel_modules::costing::{CostingError, ExecutionCostTree, FeeReserveError};
 use crate::system::kernel_modules::costing::{FeeSummary, SystemLoanFeeReserve};
 use crate::system::node_modules::type_info::TypeInfoSubstate;
 use crate::system::node_substates::{
@@ -63,6 +63,9 @@ pub struct LoadedSubstate {
 pub struct Track<'s> {
     substate_store: &'s dyn ReadableSubstateStore,
     loaded_substates: IndexMap<SubstateId, LoadedSubstate>,
+    /// The keys of the loaded key value store entries, by their scan key (see
+    /// [`kv_store_entry_scan_key`]), so that scans don't need to go through all loaded substates.
+    loaded_kv_store_entries: HashMap<KeyValueStoreId, BTreeMap<Vec<u8>, Vec<u8>>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -84,9 +87,25 @@ impl<'s> Track<'s> {
         Self {
             substate_store,
             loaded_substates: index_map_new(),
+            loaded_kv_store_entries: HashMap::new(),
         }
     }
 
+    fn insert_loaded_substate(&mut self, substate_id: SubstateId, loaded_substate: LoadedSubstate) {
+        if let SubstateId(
+            RENodeId::KeyValueStore(kv_store_id),
+            NodeModuleId::SELF,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+        ) = &substate_id
+        {
+            self.loaded_kv_store_entries
+                .entry(*kv_store_id)
+                .or_default()
+                .insert(kv_store_entry_scan_key(key), key.clone());
+        }
+        self.loaded_substates.insert(substate_id, loaded_substate);
+    }
+
     /// Returns a copy of the substate associated with the given address, if exists
     fn load_substate(&mut self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.substate_store.get_substate(substate_id)
@@ -101,7 +120,7 @@ impl<'s> Track<'s> {
         if !self.loaded_substates.contains_key(&substate_id) {
             let maybe_substate = self.load_substate(&substate_id);
             if let Some(output) = maybe_substate {
-                self.loaded_substates.insert(
+                self.insert_loaded_substate(
                     substate_id.clone(),
                     LoadedSubstate {
                         substate: output.substate.to_runtime(),
@@ -279,7 +298,7 @@ impl<'s> Track<'s> {
             return Err(TrackError::InternalRefNotAllowed);
         }
 
-        self.loaded_substates.insert(
+        self.insert_loaded_substate(
             substate_id,
             LoadedSubstate {
                 substate,
@@ -291,6 +310,56 @@ impl<'s> Track<'s> {
         Ok(())
     }
 
+    /// Returns up to `limit` non-empty entries of a key value store in scan order (see
+    /// [`kv_store_entry_scan_key`]), starting at `start_key` (inclusive) and including the
+    /// changes made so far, together with the number of entries read from the substate store.
+    pub fn scan_key_value_store(
+        &mut self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> (Vec<(Vec<u8>, ScryptoValue)>, usize) {
+        let start_scan_key = start_key.map(kv_store_entry_scan_key).unwrap_or_default();
+        let mut loaded_entries = BTreeMap::new();
+        if let Some(keys) = self.loaded_kv_store_entries.get(kv_store_id) {
+            for (scan_key, key) in keys.range(start_scan_key..) {
+                let substate_id = SubstateId(
+                    RENodeId::KeyValueStore(*kv_store_id),
+                    NodeModuleId::SELF,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key.clone())),
+                );
+                if let RuntimeSubstate::KeyValueStoreEntry(value) =
+                    &self.loaded_substates[&substate_id].substate
+                {
+                    loaded_entries.insert(scan_key.clone(), (key.clone(), value.clone()));
+                }
+            }
+        }
+
+        // Each loaded entry can hide at most one non-empty stored entry
+        let stored_entries = self.substate_store.scan_kv_store_entries(
+            kv_store_id,
+            start_key,
+            limit.saturating_add(loaded_entries.len()),
+        );
+        let read_count = stored_entries.len();
+
+        let mut entries = BTreeMap::new();
+        for (key, output) in stored_entries {
+            if let PersistedSubstate::KeyValueStoreEntry(value) = output.substate {
+                entries.insert(kv_store_entry_scan_key(&key), (key, value));
+            }
+        }
+        entries.extend(loaded_entries);
+
+        let entries = entries
+            .into_values()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .take(limit)
+            .collect();
+        (entries, read_count)
+    }
+
     /// Returns the value of a key value pair
     fn read_key_value(
         &mut self,
@@ -307,7 +376,7 @@ impl<'s> Track<'s> {
                         .map(|o| (o.substate.to_runtime(), o.version))
                         .unwrap_or((RuntimeSubstate::KeyValueStoreEntry(Option::None), 0));
 
-                    self.loaded_substates.insert(
+                    self.insert_loaded_substate(
                         substate_id.clone(),
                         LoadedSubstate {
                             substate,
@@ -341,7 +410,7 @@ impl<'s> Track<'s> {
                         .map(|o| (o.substate.to_runtime(), o.version))
                         .unwrap_or((RuntimeSubstate::KeyValueStoreEntry(Option::None), 0));
 
-                    self.loaded_substates.insert(
+                    self.insert_loaded_substate(
                         substate_id.clone(),
                         LoadedSubstate {
                             substate,
@@ -368,6 +437,7 @@ impl<'s> Track<'s> {
         mut self,
         mut invoke_result: Result<Vec<InstructionOutput>, RuntimeError>,
         mut fee_reserve: SystemLoanFeeReserve,
+        execution_cost_tree: ExecutionCostTree,
         application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
         application_logs: Vec<(Level, String)>,
     ) -> TransactionResult {
@@ -423,12 +493,14 @@ impl<'s> Track<'s> {
                     substate_store: self.substate_store,
                     loaded_substates: self.loaded_substates.into_iter().collect(),
                 };
-                TransactionResult::Commit(finalizing_track.calculate_commit_result(
+                let mut commit_result = finalizing_track.calculate_commit_result(
                     invoke_result,
                     application_events,
                     application_logs,
                     fee_reserve,
-                ))
+                );
+                commit_result.fee_summary.execution_cost_tree = execution_cost_tree;
+                TransactionResult::Commit(commit_result)
             }
             TransactionResultType::Reject(rejection_error) => {
                 TransactionResult::Reject(RejectResult {
@@ -43,8 +43,7 @@ pub fn create_genesis(
     validator_set_and_stake_owners: BTreeMap<EcdsaSecp256k1PublicKey, (Decimal, ComponentAddress)>,
     account_xrd_allocations: BTreeMap<EcdsaSecp256k1PublicKey, Decimal>,
     initial_epoch: u64,
-    rounds_per_epoch: u64,
-    num_unstake_epochs: u64,
+    config: EpochManagerConfig,
 ) -> SystemTransaction {
     // NOTES
     // * Create resources before packages to avoid circular dependencies.
@@ -174,6 +173,13 @@ pub fn create_genesis(
 
         let mut access_rules = BTreeMap::new();
         access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
+        // The epoch manager mints the validators' emissions
+        {
+            let non_fungible_local_id =
+                NonFungibleLocalId::bytes(scrypto_encode(&EPOCH_MANAGER_PACKAGE).unwrap()).unwrap();
+            let global_id = NonFungibleGlobalId::new(PACKAGE_TOKEN, non_fungible_local_id);
+            access_rules.insert(Mint, (rule!(require(global_id)), rule!(deny_all)));
+        }
         let initial_supply: Decimal = XRD_MAX_SUPPLY.into();
         let resource_address = RADIX_TOKEN.to_array_without_entity_id();
         pre_allocated_ids.insert(RENodeId::GlobalObject(RADIX_TOKEN.into()));
@@ -500,8 +506,7 @@ pub fn create_genesis(
                 component_address,
                 validators,
                 initial_epoch,
-                rounds_per_epoch,
-                num_unstake_epochs
+                config
             ),
         });
     }
@@ -579,8 +584,7 @@ where
         BTreeMap::new(),
         BTreeMap::new(),
         1u64,
-        1u64,
-        1u64,
+        EpochManagerConfig::default(),
     )
 }
 
@@ -590,8 +594,7 @@ pub fn bootstrap_with_validator_set<S, W>(
     validator_set_and_stake_owners: BTreeMap<EcdsaSecp256k1PublicKey, (Decimal, ComponentAddress)>,
     account_xrd_allocations: BTreeMap<EcdsaSecp256k1PublicKey, Decimal>,
     initial_epoch: u64,
-    rounds_per_epoch: u64,
-    num_unstake_epochs: u64,
+    config: EpochManagerConfig,
 ) -> Option<TransactionReceipt>
 where
     S: ReadableSubstateStore + WriteableSubstateStore,
@@ -609,8 +612,7 @@ where
             validator_set_and_stake_owners,
             account_xrd_allocations,
             initial_epoch,
-            rounds_per_epoch,
-            num_unstake_epochs,
+            config,
         );
 
         let transaction_receipt = execute_transaction(
@@ -647,8 +649,12 @@ mod tests {
             EcdsaSecp256k1PublicKey([0; 33]),
             (Decimal::one(), account_address),
         );
-        let genesis_transaction =
-            create_genesis(initial_validator_set, BTreeMap::new(), 1u64, 1u64, 1u64);
+        let genesis_transaction = create_genesis(
+            initial_validator_set,
+            BTreeMap::new(),
+            1u64,
+            EpochManagerConfig::default(),
+        );
 
         let transaction_receipt = execute_transaction(
             &substate_store,
@@ -684,8 +690,12 @@ mod tests {
         let allocation_amount = dec!("100");
         let mut account_xrd_allocations = BTreeMap::new();
         account_xrd_allocations.insert(account_public_key, allocation_amount);
-        let genesis_transaction =
-            create_genesis(BTreeMap::new(), account_xrd_allocations, 1u64, 1u64, 1u64);
+        let genesis_transaction = create_genesis(
+            BTreeMap::new(),
+            account_xrd_allocations,
+            1u64,
+            EpochManagerConfig::default(),
+        );
 
         let transaction_receipt = execute_transaction(
             &substate_store,
@@ -1,6 +1,7 @@
 use crate::kernel::interpreters::ScryptoInterpreter;
 use crate::ledger::*;
 use crate::ledger::{OutputValue, WriteableSubstateStore};
+use crate::state_manager::{CommitReceipt, StateDiff};
 use crate::system::node_substates::PersistedSubstate;
 use crate::types::*;
 use crate::wasm::WasmEngine;
@@ -11,13 +12,13 @@ use radix_engine_interface::api::types::{
 /// A substate store that stores all typed substates in host memory.
 #[derive(Debug, PartialEq, Eq)]
 pub struct TypedInMemorySubstateStore {
-    substates: HashMap<SubstateId, OutputValue>,
+    substates: BTreeMap<SubstateId, OutputValue>,
 }
 
 impl TypedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
-            substates: HashMap::new(),
+            substates: BTreeMap::new(),
         }
     }
 
@@ -63,6 +64,22 @@ impl ReadableSubstateStore for TypedInMemorySubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.substates.get(substate_id).cloned()
     }
+
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let entries = self
+            .substates
+            .range(kv_store_first_entry_id(kv_store_id)..)
+            .map_while(|(substate_id, substate_value)| {
+                kv_store_entry_key(kv_store_id, substate_id)
+                    .map(|key| (key.clone(), substate_value.clone()))
+            });
+        select_kv_store_entries(entries, start_key, limit)
+    }
 }
 
 impl WriteableSubstateStore for TypedInMemorySubstateStore {
@@ -97,3 +114,149 @@ impl QueryableSubstateStore for TypedInMemorySubstateStore {
             .collect()
     }
 }
+
+/// A substate store that stores all typed substates in host memory, together with their previous
+/// values (see `ReadableVersionedSubstateStore`).
+#[derive(Debug, PartialEq, Eq)]
+pub struct TypedInMemoryVersionedSubstateStore {
+    substates: BTreeMap<SubstateId, BTreeMap<StateVersion, OutputValue>>,
+    state_version: StateVersion,
+}
+
+impl TypedInMemoryVersionedSubstateStore {
+    pub fn new() -> Self {
+        Self {
+            substates: BTreeMap::new(),
+            state_version: 0,
+        }
+    }
+
+    /// Creates a store with the genesis transaction committed as state version 1.
+    pub fn with_bootstrap<W: WasmEngine>(scrypto_interpreter: &ScryptoInterpreter<W>) -> Self {
+        let mut substate_store = Self::new();
+        if bootstrap(&mut substate_store, scrypto_interpreter).is_some() {
+            substate_store.state_version += 1;
+        }
+        substate_store
+    }
+
+    /// Commits the state updates of a transaction as the next state version.
+    pub fn commit(&mut self, state_updates: &StateDiff) -> CommitReceipt {
+        let commit_receipt = state_updates.commit(self);
+        self.state_version += 1;
+        commit_receipt
+    }
+}
+
+impl Default for TypedInMemoryVersionedSubstateStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReadableSubstateStore for TypedInMemoryVersionedSubstateStore {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.substates
+            .get(substate_id)
+            .and_then(|versions| versions.values().next_back())
+            .cloned()
+    }
+
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let entries = self
+            .substates
+            .range(kv_store_first_entry_id(kv_store_id)..)
+            .map_while(|(substate_id, versions)| {
+                kv_store_entry_key(kv_store_id, substate_id).map(|key| (key, versions))
+            })
+            .filter_map(|(key, versions)| {
+                versions
+                    .values()
+                    .next_back()
+                    .map(|substate_value| (key.clone(), substate_value.clone()))
+            });
+        select_kv_store_entries(entries, start_key, limit)
+    }
+}
+
+impl WriteableSubstateStore for TypedInMemoryVersionedSubstateStore {
+    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        self.substates
+            .entry(substate_id)
+            .or_default()
+            .insert(self.state_version + 1, substate);
+    }
+}
+
+impl ReadableVersionedSubstateStore for TypedInMemoryVersionedSubstateStore {
+    fn get_state_version(&self) -> StateVersion {
+        self.state_version
+    }
+
+    fn get_substate_at_version(
+        &self,
+        substate_id: &SubstateId,
+        state_version: StateVersion,
+    ) -> Option<OutputValue> {
+        self.substates
+            .get(substate_id)
+            .and_then(|versions| versions.range(..=state_version).next_back())
+            .map(|(_, substate)| substate.clone())
+    }
+
+    fn scan_kv_store_entries_at_version(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+        state_version: StateVersion,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let entries = self
+            .substates
+            .range(kv_store_first_entry_id(kv_store_id)..)
+            .map_while(|(substate_id, versions)| {
+                kv_store_entry_key(kv_store_id, substate_id).map(|key| (key, versions))
+            })
+            .filter_map(|(key, versions)| {
+                versions
+                    .range(..=state_version)
+                    .next_back()
+                    .map(|(_, substate_value)| (key.clone(), substate_value.clone()))
+            });
+        select_kv_store_entries(entries, start_key, limit)
+    }
+}
+
+impl QueryableSubstateStore for TypedInMemoryVersionedSubstateStore {
+    fn get_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+    ) -> HashMap<Vec<u8>, PersistedSubstate> {
+        self.substates
+            .iter()
+            .filter_map(|(substate_id, versions)| {
+                if let SubstateId(
+                    RENodeId::KeyValueStore(id),
+                    NodeModuleId::SELF,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(entry_id)),
+                ) = substate_id
+                {
+                    if id == kv_store_id {
+                        versions.values().next_back().map(|substate_value| {
+                            (entry_id.clone(), substate_value.substate.clone())
+                        })
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
@@ -4,6 +4,6 @@ mod query;
 mod traits;
 
 pub use bootstrap::*;
-pub use memory::TypedInMemorySubstateStore;
+pub use memory::{TypedInMemorySubstateStore, TypedInMemoryVersionedSubstateStore};
 pub use query::*;
 pub use traits::*;
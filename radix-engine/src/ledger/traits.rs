@@ -22,6 +22,124 @@ pub struct OutputValue {
 
 pub trait ReadableSubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue>;
+
+    /// Returns the entries of a key value store in scan order (see [`kv_store_entry_scan_key`]),
+    /// starting at `start_key` (inclusive) and stopping once `limit` non-empty entries have been
+    /// returned.
+    ///
+    /// Removed entries are returned too, as they have to be read to be skipped and may hide an
+    /// entry of an underlying store.
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)>;
+}
+
+/// Returns the position of a key value store entry in a scan.
+///
+/// Entries are scanned in the order of their encoded substate ids, i.e. the order in which a
+/// database keyed by the encoded substate id stores them, so that a scan can seek to its start
+/// key. The key of an entry is encoded as a byte array within its substate id, so it is ordered
+/// by the encoding of the key as a byte array.
+pub fn kv_store_entry_scan_key(key: &[u8]) -> Vec<u8> {
+    scrypto_encode(&key.to_vec()).expect("Could not encode key value store entry key")
+}
+
+/// Takes the entries to be returned by [`ReadableSubstateStore::scan_kv_store_entries`] out of
+/// the entries of a key value store from the start key onwards, given in scan order.
+pub fn take_kv_store_entries<I: IntoIterator<Item = (Vec<u8>, OutputValue)>>(
+    entries: I,
+    limit: usize,
+) -> Vec<(Vec<u8>, OutputValue)> {
+    let mut taken = Vec::new();
+    let mut remaining = limit;
+    for (key, output) in entries {
+        if remaining == 0 {
+            break;
+        }
+        if !matches!(output.substate, PersistedSubstate::KeyValueStoreEntry(None)) {
+            remaining -= 1;
+        }
+        taken.push((key, output));
+    }
+    taken
+}
+
+/// Sorts the entries of a key value store into scan order and takes the ones to be returned by
+/// [`ReadableSubstateStore::scan_kv_store_entries`].
+pub fn select_kv_store_entries<I: IntoIterator<Item = (Vec<u8>, OutputValue)>>(
+    entries: I,
+    start_key: Option<&[u8]>,
+    limit: usize,
+) -> Vec<(Vec<u8>, OutputValue)> {
+    let start_scan_key = start_key.map(kv_store_entry_scan_key);
+    let mut selected: Vec<(Vec<u8>, (Vec<u8>, OutputValue))> = entries
+        .into_iter()
+        .map(|(key, output)| (kv_store_entry_scan_key(&key), (key, output)))
+        .filter(|(scan_key, _)| {
+            start_scan_key
+                .as_ref()
+                .map_or(true, |start_scan_key| scan_key >= start_scan_key)
+        })
+        .collect();
+    selected.sort_by(|(a, _), (b, _)| a.cmp(b));
+    take_kv_store_entries(selected.into_iter().map(|(_, entry)| entry), limit)
+}
+
+/// Returns the id of the first possible entry of a key value store, after which the ids of all
+/// entries of the store follow in `SubstateId` order.
+pub fn kv_store_first_entry_id(kv_store_id: &KeyValueStoreId) -> SubstateId {
+    SubstateId(
+        RENodeId::KeyValueStore(*kv_store_id),
+        NodeModuleId::SELF,
+        SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(Vec::new())),
+    )
+}
+
+/// Returns the encoded substate id of a key value store entry, i.e. the key under which a
+/// database keyed by the encoded substate id persists the entry.
+pub fn encode_kv_store_entry_id(kv_store_id: &KeyValueStoreId, key: &[u8]) -> Vec<u8> {
+    scrypto_encode(&SubstateId(
+        RENodeId::KeyValueStore(*kv_store_id),
+        NodeModuleId::SELF,
+        SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key.to_vec())),
+    ))
+    .expect("Could not encode substate id")
+}
+
+/// Returns the prefix shared by the encoded substate ids of all entries of a key value store.
+pub fn encode_kv_store_entries_prefix(kv_store_id: &KeyValueStoreId) -> Vec<u8> {
+    // The entry key is encoded last, as a length-prefixed byte array, so the encoded ids of
+    // all entries of the store start with the encoding of an empty key without its length
+    let mut prefix = encode_kv_store_entry_id(kv_store_id, &[]);
+    prefix.pop();
+    prefix
+}
+
+/// Returns the key of a key value store entry, given its encoded substate id.
+pub fn decode_kv_store_entry_key(encoded_id: &[u8]) -> Vec<u8> {
+    match scrypto_decode(encoded_id).expect("Could not decode substate id") {
+        SubstateId(_, _, SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key))) => key,
+        substate_id => panic!("Not a key value store entry: {:?}", substate_id),
+    }
+}
+
+/// Returns the key of the entry identified by the substate id, if it is an entry of the given
+/// key value store.
+pub fn kv_store_entry_key<'a>(
+    kv_store_id: &KeyValueStoreId,
+    substate_id: &'a SubstateId,
+) -> Option<&'a Vec<u8>> {
+    match substate_id {
+        SubstateId(
+            RENodeId::KeyValueStore(id),
+            NodeModuleId::SELF,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+        ) if id == kv_store_id => Some(key),
+        _ => None,
+    }
 }
 
 pub trait WriteableSubstateStore {
@@ -31,3 +149,77 @@ pub trait WriteableSubstateStore {
 pub trait SubstateStore: ReadableSubstateStore + WriteableSubstateStore {}
 
 impl<T: ReadableSubstateStore + WriteableSubstateStore> SubstateStore for T {}
+
+/// The number of transactions committed to a substate store so far.
+pub type StateVersion = u64;
+
+/// A substate store which retains the previous values of substates.
+///
+/// Substates put after the current state version was reached are considered written at the next
+/// state version (i.e. they are only visible to the "latest" reads until the store completes that
+/// version, typically when a transaction's state updates have been committed).
+pub trait ReadableVersionedSubstateStore {
+    /// Returns the current state version.
+    fn get_state_version(&self) -> StateVersion;
+
+    /// Returns the value the substate had right after the given state version was reached.
+    fn get_substate_at_version(
+        &self,
+        substate_id: &SubstateId,
+        state_version: StateVersion,
+    ) -> Option<OutputValue>;
+
+    /// Scans a key value store as it was right after the given state version was reached (see
+    /// [`ReadableSubstateStore::scan_kv_store_entries`]).
+    fn scan_kv_store_entries_at_version(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+        state_version: StateVersion,
+    ) -> Vec<(Vec<u8>, OutputValue)>;
+}
+
+/// A read-only view of a versioned substate store, as of a specific state version.
+/// It can be used in place of the latest state, e.g. to execute or preview a transaction against
+/// the ledger as it was in the past.
+pub struct SubstateStoreAtVersion<'s, S: ReadableVersionedSubstateStore> {
+    substate_store: &'s S,
+    state_version: StateVersion,
+}
+
+impl<'s, S: ReadableVersionedSubstateStore> SubstateStoreAtVersion<'s, S> {
+    pub fn new(substate_store: &'s S, state_version: StateVersion) -> Self {
+        Self {
+            substate_store,
+            state_version,
+        }
+    }
+
+    pub fn state_version(&self) -> StateVersion {
+        self.state_version
+    }
+}
+
+impl<'s, S: ReadableVersionedSubstateStore> ReadableSubstateStore
+    for SubstateStoreAtVersion<'s, S>
+{
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.substate_store
+            .get_substate_at_version(substate_id, self.state_version)
+    }
+
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        self.substate_store.scan_kv_store_entries_at_version(
+            kv_store_id,
+            start_key,
+            limit,
+            self.state_version,
+        )
+    }
+}
@@ -136,6 +136,53 @@ impl<'t, 's, S: ReadableSubstateStore> ReadableSubstateStore for StagedSubstateS
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.get_substate_recurse(substate_id, self.id)
     }
+
+    fn scan_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        // Collect the staged entries from the start key onwards, in scan order, the most recent
+        // output of an entry taking precedence
+        let start_scan_key = start_key.map(kv_store_entry_scan_key);
+        let mut staged = BTreeMap::new();
+        let mut id = self.id;
+        while id != 0 {
+            let node = self.stores.nodes.get(&id).unwrap();
+            let node_entries = node
+                .outputs
+                .range(kv_store_first_entry_id(kv_store_id)..)
+                .map_while(|(substate_id, output)| {
+                    kv_store_entry_key(kv_store_id, substate_id).map(|key| (key, output))
+                });
+            for (key, output) in node_entries {
+                let scan_key = kv_store_entry_scan_key(key);
+                if start_scan_key
+                    .as_ref()
+                    .map_or(true, |start_scan_key| &scan_key >= start_scan_key)
+                {
+                    staged
+                        .entry(scan_key)
+                        .or_insert_with(|| (key.clone(), output.clone()));
+                }
+            }
+            id = node.parent_id;
+        }
+
+        // Each staged entry can hide at most one non-empty entry of the root store
+        let root_entries = self.stores.root.scan_kv_store_entries(
+            kv_store_id,
+            start_key,
+            limit.saturating_add(staged.len()),
+        );
+        let mut entries: BTreeMap<Vec<u8>, (Vec<u8>, OutputValue)> = root_entries
+            .into_iter()
+            .map(|(key, output)| (kv_store_entry_scan_key(&key), (key, output)))
+            .collect();
+        entries.extend(staged);
+        take_kv_store_entries(entries.into_values(), limit)
+    }
 }
 
 impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstateStore<'t, 's, S> {
@@ -152,7 +199,9 @@ impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstate
 #[cfg(test)]
 mod tests {
     use super::StagedSubstateStoreManager;
-    use crate::ledger::TypedInMemorySubstateStore;
+    use crate::ledger::*;
+    use crate::system::node_substates::PersistedSubstate;
+    use crate::types::*;
 
     #[test]
     fn test_complicated_merge() {
@@ -182,4 +231,59 @@ mod tests {
         let node = stores.nodes.get(&child_node10).expect("Should exist");
         assert_eq!(node.parent_id, child_node9);
     }
+
+    #[test]
+    fn test_scan_kv_store_entries_merges_staged_entries_in_scan_order() {
+        // Arrange
+        let kv_store_id = [1u8; OBJECT_ID_LENGTH];
+        let mut store = TypedInMemorySubstateStore::new();
+        // Longer keys are scanned after shorter ones
+        put_entry(&mut store, kv_store_id, vec![1, 0], Some(10));
+        put_entry(&mut store, kv_store_id, vec![2], Some(2));
+        put_entry(&mut store, kv_store_id, vec![3], None);
+        put_entry(&mut store, kv_store_id, vec![4], Some(4));
+        put_entry(&mut store, [2u8; OBJECT_ID_LENGTH], vec![0], Some(0));
+        let root_page = store.scan_kv_store_entries(&kv_store_id, None, 2);
+        let root_last_page = store.scan_kv_store_entries(&kv_store_id, Some(&[4]), 10);
+        let mut stores = StagedSubstateStoreManager::new(&mut store);
+        let child_node = stores.new_child_node(0);
+        let mut staged_store = stores.get_output_store(child_node);
+        put_entry(&mut staged_store, kv_store_id, vec![2], None);
+        put_entry(&mut staged_store, kv_store_id, vec![5], Some(5));
+
+        // Act
+        let staged_page = staged_store.scan_kv_store_entries(&kv_store_id, None, 2);
+        let staged_last_page = staged_store.scan_kv_store_entries(&kv_store_id, Some(&[5]), 10);
+
+        // Assert
+        assert_eq!(keys(&root_page), vec![vec![2], vec![3], vec![4]]);
+        assert_eq!(keys(&root_last_page), vec![vec![4], vec![1, 0]]);
+        assert_eq!(keys(&staged_page), vec![vec![2], vec![3], vec![4], vec![5]]);
+        assert_eq!(keys(&staged_last_page), vec![vec![5], vec![1, 0]]);
+    }
+
+    fn put_entry<S: WriteableSubstateStore>(
+        store: &mut S,
+        kv_store_id: KeyValueStoreId,
+        key: Vec<u8>,
+        value: Option<u8>,
+    ) {
+        store.put_substate(
+            SubstateId(
+                RENodeId::KeyValueStore(kv_store_id),
+                NodeModuleId::SELF,
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+            ),
+            OutputValue {
+                substate: PersistedSubstate::KeyValueStoreEntry(
+                    value.map(|value| scrypto_decode(&scrypto_encode(&value).unwrap()).unwrap()),
+                ),
+                version: 0,
+            },
+        );
+    }
+
+    fn keys(entries: &[(Vec<u8>, OutputValue)]) -> Vec<Vec<u8>> {
+        entries.iter().map(|(key, _)| key.clone()).collect()
+    }
 }
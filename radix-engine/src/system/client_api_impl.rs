@@ -5,7 +5,7 @@ use crate::errors::{
 use crate::kernel::actor::{Actor, ActorIdentifier};
 use crate::kernel::kernel::Kernel;
 use crate::kernel::kernel_api::*;
-use crate::system::kernel_modules::costing::FIXED_LOW_FEE;
+use crate::system::kernel_modules::costing::CostingEntry;
 use crate::system::kernel_modules::events::EventError;
 use crate::system::node::RENodeInit;
 use crate::system::node::RENodeModuleInit;
@@ -13,14 +13,14 @@ use crate::system::node_modules::access_rules::MethodAccessRulesSubstate;
 use crate::system::node_modules::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::system::node_substates::RuntimeSubstate;
 use crate::types::*;
-use crate::wasm::WasmEngine;
+use crate::wasm::{WasmEngine, WASM_MEMORY_PAGE_SIZE};
 use radix_engine_interface::api::component::{
     ComponentRoyaltyAccumulatorSubstate, ComponentRoyaltyConfigSubstate, ComponentStateSubstate,
 };
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::node_modules::royalty::*;
-use radix_engine_interface::api::substate_api::LockFlags;
+use radix_engine_interface::api::substate_api::{LockFlags, MAX_KEY_VALUE_STORE_SCAN_LIMIT};
 use radix_engine_interface::api::types::ClientCostingReason;
 use radix_engine_interface::api::types::Level;
 use radix_engine_interface::api::types::*;
@@ -34,6 +34,10 @@ use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::schema::KeyValueStoreSchema;
 use sbor::rust::string::ToString;
 use sbor::rust::vec::Vec;
+use transaction::validation::{
+    recover_ecdsa_secp256k1, verify_bls12381_aggregate, verify_ecdsa_secp256k1,
+    verify_eddsa_ed25519_message,
+};
 
 use super::kernel_modules::costing::CostingReason;
 
@@ -137,6 +141,95 @@ where
 
         self.kernel_drop_lock(lock_handle)
     }
+
+    fn sys_remove_key_value_entry(
+        &mut self,
+        node_id: RENodeId,
+        key: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.get_key_value_store_info(node_id)?;
+
+        let handle = self.sys_lock_substate(
+            node_id,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+            LockFlags::MUTABLE,
+        )?;
+        let entry: &mut Option<ScryptoValue> = self.kernel_get_substate_ref_mut(handle)?;
+
+        // Owned nodes can't be removed along with the entry, as they would be orphaned
+        let owns_nodes = match entry {
+            Some(value) => !IndexedScryptoValue::from_scrypto_value(value.clone())
+                .owned_node_ids()
+                .is_empty(),
+            None => false,
+        };
+        if owns_nodes {
+            self.kernel_drop_lock(handle)?;
+            return Err(RuntimeError::SystemError(
+                SystemError::CannotRemoveKeyValueStoreEntryWithOwnedNodes,
+            ));
+        }
+
+        let removed = entry.take().map(|value| scrypto_encode(&value).unwrap());
+        self.kernel_drop_lock(handle)?;
+
+        Ok(removed)
+    }
+
+    fn sys_scan_key_value_store(
+        &mut self,
+        node_id: RENodeId,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, RuntimeError> {
+        let kv_store_id = match node_id {
+            RENodeId::KeyValueStore(kv_store_id) => kv_store_id,
+            _ => return Err(RuntimeError::SystemError(SystemError::NotAKeyValueStore)),
+        };
+
+        if limit > MAX_KEY_VALUE_STORE_SCAN_LIMIT {
+            return Err(RuntimeError::SystemError(
+                SystemError::KeyValueStoreScanLimitExceeded(limit),
+            ));
+        }
+
+        // Costing the entries to be scanned up front, so that they are paid for before being read
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::ScanSubstates,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::ScanSubstates { entries: limit }),
+            1,
+        )?;
+
+        let (entries, read_count) = self.kernel_scan_key_value_store(
+            kv_store_id,
+            start_key.as_ref().map(|key| key.as_slice()),
+            limit as usize,
+        )?;
+
+        // Costing the removed entries read on top of the limit
+        let read_count = read_count as u32;
+        if read_count > limit {
+            self.kernel_get_module_state().apply_execution_cost(
+                CostingReason::ScanSubstates,
+                |fee_table| {
+                    fee_table
+                        .kernel_api_cost(CostingEntry::ScanSubstates {
+                            entries: read_count,
+                        })
+                        .saturating_sub(
+                            fee_table
+                                .kernel_api_cost(CostingEntry::ScanSubstates { entries: limit }),
+                        )
+                },
+                1,
+            )?;
+        }
+
+        Ok(entries
+            .into_iter()
+            .map(|(key, value)| (key, scrypto_encode(&value).unwrap()))
+            .collect())
+    }
 }
 
 impl<'g, 's, W> ClientActorApi<RuntimeError> for Kernel<'g, 's, W>
@@ -275,6 +368,7 @@ where
                         SubstateOffset::Vault(VaultOffset::LockedFungible) => RuntimeSubstate::VaultLockedFungible(parser.decode_next()),
                         SubstateOffset::Vault(VaultOffset::LiquidNonFungible) => RuntimeSubstate::VaultLiquidNonFungible(parser.decode_next()),
                         SubstateOffset::Vault(VaultOffset::LockedNonFungible) => RuntimeSubstate::VaultLockedNonFungible(parser.decode_next()),
+                        SubstateOffset::Vault(VaultOffset::FrozenFlag) => RuntimeSubstate::VaultFrozenFlag(parser.decode_next()),
                     )),
                     AllocateEntityType::Vault,
                 ),
@@ -307,8 +401,10 @@ where
                 EPOCH_MANAGER_BLUEPRINT => (
                     RENodeInit::Object(btreemap!(
                         SubstateOffset::EpochManager(EpochManagerOffset::EpochManager) => RuntimeSubstate::EpochManager(parser.decode_next()),
+                        SubstateOffset::EpochManager(EpochManagerOffset::Config) => RuntimeSubstate::EpochManagerConfig(parser.decode_next()),
                         SubstateOffset::EpochManager(EpochManagerOffset::CurrentValidatorSet) => RuntimeSubstate::ValidatorSet(parser.decode_next()),
-                        SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet) => RuntimeSubstate::ValidatorSet(parser.decode_next())
+                        SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet) => RuntimeSubstate::ValidatorSet(parser.decode_next()),
+                        SubstateOffset::EpochManager(EpochManagerOffset::CurrentProposalStatistic) => RuntimeSubstate::ProposalStatistic(parser.decode_next())
                     )),
                     AllocateEntityType::Object,
                 ),
@@ -723,9 +819,27 @@ where
     W: WasmEngine,
 {
     fn update_wasm_memory_usage(&mut self, consumed_memory: usize) -> Result<(), RuntimeError> {
-        // No costing applied
-
         let current_depth = self.kernel_get_current_depth();
+
+        // Costing memory growth of the current instance
+        let previous_memory = self
+            .kernel_get_module_state()
+            .transaction_limits
+            .wasm_memory_usage(current_depth);
+        if consumed_memory > previous_memory {
+            let page_size = WASM_MEMORY_PAGE_SIZE as usize;
+            let pages = (consumed_memory / page_size).saturating_sub(previous_memory / page_size);
+            self.kernel_get_module_state().apply_execution_cost(
+                CostingReason::GrowWasmMemory,
+                |fee_table| {
+                    fee_table.kernel_api_cost(CostingEntry::GrowWasmMemory {
+                        pages: pages as u32,
+                    })
+                },
+                1,
+            )?;
+        }
+
         self.kernel_get_module_state()
             .transaction_limits
             .update_wasm_memory_usage(current_depth, consumed_memory)
@@ -742,6 +856,10 @@ where
         self.kernel_get_module_state()
             .execution_trace
             .update_instruction_index(new_index);
+        self.kernel_get_module_state()
+            .costing
+            .cost_tree
+            .update_instruction_index(new_index);
         Ok(())
     }
 }
@@ -752,7 +870,15 @@ where
 {
     fn emit_event(&mut self, event_name: String, event_data: Vec<u8>) -> Result<(), RuntimeError> {
         // Costing event emission.
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::EmitEvent,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::EmitEvent {
+                    size: event_data.len() as u32,
+                })
+            },
+            1,
+        )?;
 
         let actor = self.kernel_get_current_actor();
 
@@ -883,7 +1009,15 @@ where
     W: WasmEngine,
 {
     fn log_message(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::EmitLog,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::EmitLog {
+                    size: message.len() as u32,
+                })
+            },
+            1,
+        )?;
 
         self.kernel_get_module_state()
             .logger
@@ -897,7 +1031,11 @@ where
     W: WasmEngine,
 {
     fn get_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::ReadTransactionHash,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::ReadTransactionHash),
+            1,
+        )?;
 
         Ok(self
             .kernel_get_module_state()
@@ -906,7 +1044,11 @@ where
     }
 
     fn generate_uuid(&mut self) -> Result<u128, RuntimeError> {
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::GenerateUuid,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::GenerateUuid),
+            1,
+        )?;
 
         Ok(self
             .kernel_get_module_state()
@@ -915,4 +1057,129 @@ where
     }
 }
 
+impl<'g, 's, W> ClientCryptoUtilsApi<RuntimeError> for Kernel<'g, 's, W>
+where
+    W: WasmEngine,
+{
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::Blake2b256Hash {
+                    size: data.len() as u32,
+                })
+            },
+            1,
+        )?;
+
+        Ok(blake2b_256_hash(data))
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::Sha256Hash {
+                    size: data.len() as u32,
+                })
+            },
+            1,
+        )?;
+
+        Ok(sha256_hash(data))
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::Keccak256Hash {
+                    size: data.len() as u32,
+                })
+            },
+            1,
+        )?;
+
+        Ok(keccak256_hash(data))
+    }
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Hash,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::VerifyEcdsaSecp256k1),
+            1,
+        )?;
+
+        Ok(verify_ecdsa_secp256k1(
+            &message_hash,
+            &public_key,
+            &signature,
+        ))
+    }
+
+    fn recover_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Hash,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Result<Option<EcdsaSecp256k1PublicKey>, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::RecoverEcdsaSecp256k1),
+            1,
+        )?;
+
+        Ok(recover_ecdsa_secp256k1(&message_hash, &signature))
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EddsaEd25519PublicKey,
+        signature: EddsaEd25519Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::VerifyEddsaEd25519 {
+                    size: message.len() as u32,
+                })
+            },
+            1,
+        )?;
+
+        Ok(verify_eddsa_ed25519_message(
+            &message,
+            &public_key,
+            &signature,
+        ))
+    }
+
+    fn verify_bls12381_aggregate(
+        &mut self,
+        messages: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CryptoUtils,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::VerifyBls12381Aggregate {
+                    keys: messages.len() as u32,
+                    size: messages
+                        .iter()
+                        .map(|(_, message)| message.len())
+                        .sum::<usize>() as u32,
+                })
+            },
+            1,
+        )?;
+
+        Ok(verify_bls12381_aggregate(&messages, &signature))
+    }
+}
+
 impl<'g, 's, W> ClientApi<RuntimeError> for Kernel<'g, 's, W> where W: WasmEngine {}
@@ -12,6 +12,7 @@ use crate::kernel::call_frame::RENodeVisibilityOrigin;
 use crate::kernel::kernel_api::KernelModuleApi;
 use crate::kernel::module::KernelModule;
 use crate::system::kernel_modules::auth::convert;
+use crate::system::kernel_modules::costing::{CostingEntry, CostingReason};
 use crate::system::node::{RENodeInit, RENodeModuleInit};
 use crate::system::node_modules::access_rules::{
     AccessRulesNativePackage, AuthZoneStackSubstate, FunctionAccessRulesSubstate,
@@ -161,12 +162,28 @@ impl AuthModule {
                         let method_key = identifier.method_key();
                         let auth = match visibility {
                             RENodeVisibilityOrigin::Normal => {
-                                Self::method_authorization_contextless(
-                                    RENodeId::GlobalObject(resource_address.into()),
-                                    NodeModuleId::AccessRules1,
-                                    method_key,
-                                    api,
-                                )?
+                                // Burning from a vault is guarded by the resource manager's burn rule
+                                if method_key.node_module_id.eq(&NodeModuleId::SELF)
+                                    && (method_key.ident.eq(VAULT_BURN_IDENT)
+                                        || method_key.ident.eq(VAULT_BURN_NON_FUNGIBLES_IDENT))
+                                {
+                                    Self::method_authorization_contextless(
+                                        RENodeId::GlobalObject(resource_address.into()),
+                                        NodeModuleId::AccessRules,
+                                        MethodKey::new(
+                                            NodeModuleId::SELF,
+                                            RESOURCE_MANAGER_BURN_IDENT.to_string(),
+                                        ),
+                                        api,
+                                    )?
+                                } else {
+                                    Self::method_authorization_contextless(
+                                        RENodeId::GlobalObject(resource_address.into()),
+                                        NodeModuleId::AccessRules1,
+                                        method_key,
+                                        api,
+                                    )?
+                                }
                             }
                             RENodeVisibilityOrigin::DirectAccess => {
                                 let handle = api.kernel_lock_substate(
@@ -188,6 +205,12 @@ impl AuthModule {
                                     let access_rule = substate.access_rules.get_group("recall");
                                     let authorization = convert_contextless(access_rule);
                                     authorization
+                                } else if method_key.node_module_id.eq(&NodeModuleId::SELF)
+                                    && (method_key.ident.eq(VAULT_FREEZE_IDENT)
+                                        || method_key.ident.eq(VAULT_UNFREEZE_IDENT))
+                                {
+                                    let access_rule = substate.access_rules.get_group("freeze");
+                                    convert_contextless(access_rule)
                                 } else {
                                     return Err(RuntimeError::ModuleError(ModuleError::AuthError(
                                         AuthError::VisibilityError(node_id),
@@ -388,6 +411,13 @@ impl KernelModule for AuthModule {
             MethodAuthorization::AllowAll
         };
 
+        // Costing the authorization check
+        api.kernel_get_module_state().apply_execution_cost(
+            CostingReason::CheckAuth,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::CheckAuth),
+            1,
+        )?;
+
         let handle = api.kernel_lock_substate(
             RENodeId::AuthZoneStack,
             NodeModuleId::SELF,
@@ -0,0 +1,279 @@
+use super::CostingReason;
+use crate::kernel::actor::Actor;
+use crate::types::*;
+use radix_engine_interface::address::AddressDisplayContext;
+use sbor::rust::collections::BTreeMap;
+use sbor::rust::fmt::Write;
+use utils::ContextualDisplay;
+
+/// The cost units consumed during execution, attributed to the call frames they were consumed in.
+///
+/// Only the costs applied by the `CostingModule` are included, i.e. the pre-execution costs
+/// (transaction base cost, payload and signatures) and royalties are not part of the tree.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct ExecutionCostTree {
+    /// The root call frame, whose children are the transaction processor call frames.
+    pub root: CostTreeNode,
+    /// The cost units consumed while executing each manifest instruction.
+    pub instruction_costs: BTreeMap<usize, u32>,
+}
+
+/// The cost units consumed within a single call frame.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct CostTreeNode {
+    /// The actor of the call frame, or `None` for the root call frame.
+    pub actor: Option<Actor>,
+    /// The index of the manifest instruction being executed when the call frame was entered.
+    pub instruction_index: Option<usize>,
+    /// The cost units consumed by the call frame itself, excluding its children.
+    pub own_costs: BTreeMap<CostingReason, u32>,
+    /// The call frames entered from this call frame, in call order.
+    pub children: Vec<CostTreeNode>,
+}
+
+impl CostTreeNode {
+    /// Returns the cost units consumed by this call frame itself.
+    pub fn own_cost(&self) -> u32 {
+        self.own_costs
+            .values()
+            .fold(0u32, |sum, cost| sum.saturating_add(*cost))
+    }
+
+    /// Returns the cost units consumed by this call frame and all its descendants.
+    pub fn total_cost(&self) -> u32 {
+        self.children.iter().fold(self.own_cost(), |sum, child| {
+            sum.saturating_add(child.total_cost())
+        })
+    }
+}
+
+impl ExecutionCostTree {
+    /// Returns the total cost units consumed during execution.
+    pub fn total_cost(&self) -> u32 {
+        self.root.total_cost()
+    }
+
+    /// Returns the cost units consumed by each package, blueprint and function, excluding the
+    /// costs of the functions they call.
+    pub fn cost_by_function(&self) -> BTreeMap<(PackageAddress, String, String), u32> {
+        let mut costs = BTreeMap::new();
+        let mut nodes = vec![&self.root];
+        while let Some(node) = nodes.pop() {
+            if let Some(actor) = &node.actor {
+                let key = (
+                    actor.fn_identifier.package_address,
+                    actor.fn_identifier.blueprint_name.clone(),
+                    actor.fn_identifier.ident.clone(),
+                );
+                let cost = costs.entry(key).or_insert(0u32);
+                *cost = cost.saturating_add(node.own_cost());
+            }
+            nodes.extend(node.children.iter());
+        }
+        costs
+    }
+
+    /// Outputs the tree as cost-weighted folded stacks (one `frame;frame;frame cost` line per
+    /// distinct stack), as consumed by standard flamegraph tooling.
+    ///
+    /// The frames are the call frames' functions, preceded by the manifest instruction they were
+    /// entered for (if any), and followed by the reason for the cost.
+    pub fn to_folded_stacks<'a, C: Into<AddressDisplayContext<'a>>>(&self, context: C) -> String {
+        let context = context.into();
+        let mut stacks: IndexMap<String, u32> = index_map_new();
+        let mut nodes = vec![(&self.root, "Transaction".to_string(), None)];
+        while let Some((node, stack, parent_instruction_index)) = nodes.pop() {
+            for (reason, cost) in &node.own_costs {
+                let cost_sum = stacks.entry(format!("{};{}", stack, reason)).or_insert(0);
+                *cost_sum = cost_sum.saturating_add(*cost);
+            }
+            // Pushed in reverse, for the stacks to be output in call order
+            for child in node.children.iter().rev() {
+                let mut child_stack = stack.clone();
+                if let (Some(index), None) = (child.instruction_index, parent_instruction_index) {
+                    write!(child_stack, ";Instruction #{}", index).unwrap();
+                }
+                if let Some(actor) = &child.actor {
+                    write!(
+                        child_stack,
+                        ";{}::{} ({})",
+                        actor.fn_identifier.blueprint_name,
+                        actor.fn_identifier.ident,
+                        actor.fn_identifier.package_address.display(context)
+                    )
+                    .unwrap();
+                }
+                nodes.push((child, child_stack, child.instruction_index));
+            }
+        }
+
+        let mut folded = String::new();
+        for (stack, cost) in stacks {
+            writeln!(folded, "{} {}", stack, cost).unwrap();
+        }
+        folded
+    }
+}
+
+/// Builds an [`ExecutionCostTree`] as call frames are pushed and popped.
+#[derive(Debug, Clone, Default)]
+pub struct CostTreeRecorder {
+    tree: ExecutionCostTree,
+    /// The child indices leading from the root to the current call frame.
+    path: Vec<usize>,
+    current_instruction_index: Option<usize>,
+}
+
+impl CostTreeRecorder {
+    /// Records entering a call frame of the given `actor` from the call frame at `caller_depth`.
+    pub fn enter(&mut self, caller_depth: usize, actor: Option<Actor>) {
+        self.path.truncate(caller_depth);
+        let node = CostTreeNode {
+            actor,
+            instruction_index: self.current_instruction_index,
+            ..Default::default()
+        };
+        let parent = self.current_node();
+        parent.children.push(node);
+        let index = parent.children.len() - 1;
+        self.path.push(index);
+    }
+
+    /// Records returning to the call frame at `depth`.
+    pub fn exit(&mut self, depth: usize) {
+        self.path.truncate(depth);
+        if depth == 0 {
+            self.current_instruction_index = None;
+        }
+    }
+
+    pub fn update_instruction_index(&mut self, new_index: usize) {
+        self.current_instruction_index = Some(new_index);
+    }
+
+    /// Attributes the cost units to the current call frame and manifest instruction.
+    pub fn record(&mut self, reason: CostingReason, cost_units: u32) {
+        if let Some(index) = self.current_instruction_index {
+            let cost = self.tree.instruction_costs.entry(index).or_insert(0);
+            *cost = cost.saturating_add(cost_units);
+        }
+        let cost = self.current_node().own_costs.entry(reason).or_insert(0);
+        *cost = cost.saturating_add(cost_units);
+    }
+
+    pub fn finish(self) -> ExecutionCostTree {
+        self.tree
+    }
+
+    fn current_node(&mut self) -> &mut CostTreeNode {
+        let mut node = &mut self.tree.root;
+        for index in &self.path {
+            node = &mut node.children[*index];
+        }
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::actor::ActorIdentifier;
+
+    fn actor(ident: &str) -> Option<Actor> {
+        Some(Actor::function(FnIdentifier::new(
+            FAUCET_PACKAGE,
+            "Faucet".to_string(),
+            ident.to_string(),
+        )))
+    }
+
+    #[test]
+    fn test_costs_are_attributed_to_call_frames_and_instructions() {
+        let mut recorder = CostTreeRecorder::default();
+        recorder.enter(0, actor("run"));
+        recorder.record(CostingReason::Invoke, 10);
+        recorder.update_instruction_index(0);
+        recorder.enter(1, actor("free"));
+        recorder.record(CostingReason::RunNative, 5);
+        recorder.record(CostingReason::RunNative, 5);
+        recorder.exit(1);
+        recorder.update_instruction_index(1);
+        recorder.record(CostingReason::DropLock, 3);
+        recorder.exit(0);
+        recorder.record(CostingReason::DropNode, 1);
+        let tree = recorder.finish();
+
+        assert_eq!(tree.total_cost(), 24);
+        assert_eq!(tree.root.own_cost(), 1);
+        let run = &tree.root.children[0];
+        assert_eq!(run.instruction_index, None);
+        assert_eq!(run.own_cost(), 13);
+        assert_eq!(run.children[0].instruction_index, Some(0));
+        assert_eq!(
+            run.children[0].own_costs,
+            btreemap!(CostingReason::RunNative => 10)
+        );
+        assert!(matches!(
+            run.children[0].actor,
+            Some(Actor {
+                identifier: ActorIdentifier::Function(..),
+                ..
+            })
+        ));
+        assert_eq!(tree.instruction_costs, btreemap!(0 => 10, 1 => 3));
+        assert_eq!(
+            tree.cost_by_function().get(&(
+                FAUCET_PACKAGE,
+                "Faucet".to_string(),
+                "free".to_string()
+            )),
+            Some(&10)
+        );
+    }
+
+    #[test]
+    fn test_folded_stacks() {
+        let mut recorder = CostTreeRecorder::default();
+        recorder.enter(0, actor("run"));
+        recorder.record(CostingReason::Invoke, 10);
+        recorder.update_instruction_index(0);
+        recorder.enter(1, actor("free"));
+        recorder.record(CostingReason::RunNative, 5);
+        recorder.enter(2, actor("nested"));
+        recorder.record(CostingReason::RunNative, 2);
+        recorder.exit(2);
+        recorder.record(CostingReason::RunNative, 5);
+        recorder.exit(1);
+        recorder.enter(1, actor("free"));
+        recorder.record(CostingReason::RunNative, 1);
+        recorder.exit(1);
+        let tree = recorder.finish();
+
+        let encoder = Bech32Encoder::for_simulator();
+        let package = FAUCET_PACKAGE.display(&encoder);
+        assert_eq!(
+            tree.to_folded_stacks(&encoder),
+            format!(
+                "Transaction;Faucet::run ({0});Invoke 10\n\
+                 Transaction;Faucet::run ({0});Instruction #0;Faucet::free ({0});RunNative 11\n\
+                 Transaction;Faucet::run ({0});Instruction #0;Faucet::free ({0});Faucet::nested ({0});RunNative 2\n",
+                package
+            )
+        );
+    }
+
+    #[test]
+    fn test_unbalanced_frames_are_recovered_by_depth() {
+        let mut recorder = CostTreeRecorder::default();
+        recorder.enter(0, actor("run"));
+        recorder.enter(1, actor("failed"));
+        // The failed call frame is never exited
+        recorder.enter(1, actor("next"));
+        recorder.record(CostingReason::Invoke, 7);
+        let tree = recorder.finish();
+
+        let run = &tree.root.children[0];
+        assert_eq!(run.children.len(), 2);
+        assert_eq!(run.children[1].own_cost(), 7);
+    }
+}
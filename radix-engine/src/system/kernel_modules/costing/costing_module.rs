@@ -42,6 +42,7 @@ pub struct CostingModule {
     pub fee_reserve: SystemLoanFeeReserve,
     pub fee_table: FeeTable,
     pub max_call_depth: usize,
+    pub cost_tree: CostTreeRecorder,
 }
 
 impl CostingModule {
@@ -49,6 +50,10 @@ impl CostingModule {
         self.fee_reserve
     }
 
+    pub fn take_fee_reserve_and_cost_tree(self) -> (SystemLoanFeeReserve, ExecutionCostTree) {
+        (self.fee_reserve, self.cost_tree.finish())
+    }
+
     pub fn apply_execution_cost<F>(
         &mut self,
         reason: CostingReason,
@@ -60,12 +65,15 @@ impl CostingModule {
     {
         let cost_units = base_price(&self.fee_table);
         self.fee_reserve
-            .consume_multiplied_execution(cost_units, multiplier, reason)
+            .consume_multiplied_execution(cost_units, multiplier, reason.clone())
             .map_err(|e| {
                 RuntimeError::ModuleError(ModuleError::CostingError(CostingError::FeeReserveError(
                     e,
                 )))
-            })
+            })?;
+        self.cost_tree
+            .record(reason, cost_units.saturating_mul(multiplier as u32));
+        Ok(())
     }
 
     pub fn credit_cost_units(
@@ -133,6 +141,12 @@ impl KernelModule for CostingModule {
         _nodes_and_refs: &mut CallFrameUpdate,
         _args: &IndexedScryptoValue,
     ) -> Result<(), RuntimeError> {
+        let caller_depth = api.kernel_get_current_depth();
+        api.kernel_get_module_state()
+            .costing
+            .cost_tree
+            .enter(caller_depth, callee.clone());
+
         // Identify the function, and optional component address
         let (fn_identifier, optional_component) = match &callee {
             Some(Actor {
@@ -235,6 +249,24 @@ impl KernelModule for CostingModule {
         Ok(())
     }
 
+    fn on_allocate_node_id<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        _node_type: &AllocateEntityType,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_module_state().costing.apply_execution_cost(
+            CostingReason::AllocateNodeId,
+            |fee_table| fee_table.kernel_api_cost(CostingEntry::AllocateNodeId),
+            1,
+        )?;
+        Ok(())
+    }
+
+    fn after_pop_frame<Y: KernelModuleApi<RuntimeError>>(api: &mut Y) -> Result<(), RuntimeError> {
+        let depth = api.kernel_get_current_depth();
+        api.kernel_get_module_state().costing.cost_tree.exit(depth);
+        Ok(())
+    }
+
     fn before_create_node<Y: KernelModuleApi<RuntimeError>>(
         api: &mut Y,
         _node_id: &RENodeId,
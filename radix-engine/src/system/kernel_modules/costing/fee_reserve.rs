@@ -1,4 +1,4 @@
-use super::FeeSummary;
+use super::{ExecutionCostTree, FeeSummary};
 use crate::{errors::CanBeAbortion, transaction::AbortReason, types::*};
 use radix_engine_constants::{
     DEFAULT_COST_UNIT_LIMIT, DEFAULT_COST_UNIT_PRICE, DEFAULT_SYSTEM_LOAN,
@@ -99,10 +99,19 @@ pub enum CostingReason {
     Invoke,
     DropNode,
     CreateNode,
+    AllocateNodeId,
     LockSubstate,
     ReadSubstate,
+    ScanSubstates,
     WriteSubstate,
     DropLock,
+    CheckAuth,
+    EmitEvent,
+    EmitLog,
+    ReadTransactionHash,
+    GenerateUuid,
+    CryptoUtils,
+    GrowWasmMemory,
     RunWasm,
     RunNative,
     RunSystem,
@@ -444,6 +453,7 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             locked_fees: self.payments,
             execution_cost_breakdown,
             execution_cost_sum: self.execution_committed_sum,
+            execution_cost_tree: ExecutionCostTree::default(),
             royalty_cost_breakdown,
         }
     }
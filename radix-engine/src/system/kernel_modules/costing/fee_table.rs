@@ -1,4 +1,10 @@
 use crate::types::*;
+#[cfg(feature = "serde")]
+use sbor::serde_serialization::{
+    json_to_payload, JsonValue, SborPayloadWithSchema, SerializationContext, SerializationMode,
+};
+#[cfg(feature = "serde")]
+use utils::ContextualSerialize;
 
 pub const FIXED_LOW_FEE: u32 = 500;
 pub const FIXED_MEDIUM_FEE: u32 = 2500;
@@ -12,20 +18,125 @@ pub enum CostingEntry {
     CreateNode { size: u32 },
     DropNode { size: u32 },
 
+    AllocateNodeId,
+
     /* substate */
     LockSubstate,
     ReadSubstate { size: u32 },
     WriteSubstate { size: u32 },
     DropLock,
-    // TODO: more costing after API becomes stable.
+    ScanSubstates { entries: u32 },
+
+    /* auth */
+    CheckAuth,
+
+    /* system */
+    EmitEvent { size: u32 },
+    EmitLog { size: u32 },
+    ReadTransactionHash,
+    GenerateUuid,
+
+    /* crypto */
+    Blake2b256Hash { size: u32 },
+    Sha256Hash { size: u32 },
+    Keccak256Hash { size: u32 },
+    VerifyEcdsaSecp256k1,
+    RecoverEcdsaSecp256k1,
+    VerifyEddsaEd25519 { size: u32 },
+    VerifyBls12381Aggregate { keys: u32, size: u32 },
+
+    /* wasm */
+    GrowWasmMemory { pages: u32 },
+}
+
+/// A cost consisting of a fixed part and a part proportional to the number of units involved,
+/// such as bytes, entries or keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct LinearCost {
+    pub base: u32,
+    pub per_unit: u32,
+}
+
+impl LinearCost {
+    pub const fn new(base: u32, per_unit: u32) -> Self {
+        Self { base, per_unit }
+    }
+
+    pub const fn fixed(base: u32) -> Self {
+        Self { base, per_unit: 0 }
+    }
+
+    pub fn cost(&self, units: u32) -> u32 {
+        self.base
+            .saturating_add(self.per_unit.saturating_mul(units))
+    }
 }
 
-#[derive(Debug, Clone, ScryptoSbor)]
+/// The fee schedule, in cost units.
+///
+/// Any fee table can be used for a transaction through `FeeReserveConfig`, and it can be loaded
+/// from its SBOR or JSON representation, which allows fee schedule changes to be evaluated
+/// without recompiling the engine.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct FeeTable {
-    tx_base_fee: u32,
-    tx_payload_cost_per_byte: u32,
-    tx_signature_verification_per_sig: u32,
-    tx_blob_price_per_byte: u32,
+    /* transaction */
+    pub tx_base_fee: u32,
+    pub tx_payload_cost_per_byte: u32,
+    pub tx_signature_verification_per_sig: u32,
+    pub tx_blob_price_per_byte: u32,
+
+    /* invoke */
+    pub invoke: LinearCost,
+
+    /* node */
+    pub create_node: LinearCost,
+    pub drop_node: LinearCost,
+    pub allocate_node_id: LinearCost,
+
+    /* substate */
+    pub lock_substate: LinearCost,
+    pub read_substate: LinearCost,
+    pub write_substate: LinearCost,
+    pub drop_lock: LinearCost,
+    /// The cost of scanning substates, where the variable part is per entry read.
+    pub scan_substates: LinearCost,
+
+    /* auth */
+    pub check_auth: LinearCost,
+
+    /* system */
+    pub emit_event: LinearCost,
+    pub emit_log: LinearCost,
+    pub read_transaction_hash: LinearCost,
+    pub generate_uuid: LinearCost,
+
+    /* crypto */
+    pub blake2b_256_hash: LinearCost,
+    pub sha256_hash: LinearCost,
+    pub keccak256_hash: LinearCost,
+    pub verify_ecdsa_secp256k1: LinearCost,
+    pub recover_ecdsa_secp256k1: LinearCost,
+    pub verify_eddsa_ed25519: LinearCost,
+    /// The cost of verifying an aggregate BLS signature, where the variable part is per public key.
+    pub verify_bls12381_aggregate: LinearCost,
+    /// The cost of each byte of the messages an aggregate BLS signature is verified against.
+    pub verify_bls12381_aggregate_per_byte: u32,
+
+    /* wasm */
+    /// The cost of each WASM memory page (64 KiB) an instance grows by.
+    pub grow_wasm_memory_per_page: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeeTableLoadError {
+    DecodeError(DecodeError),
+    InvalidJson(String),
+}
+
+impl Default for FeeTable {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl FeeTable {
@@ -35,9 +146,75 @@ impl FeeTable {
             tx_payload_cost_per_byte: 5,
             tx_signature_verification_per_sig: 100_000,
             tx_blob_price_per_byte: 5,
+            invoke: LinearCost::new(FIXED_LOW_FEE, 10),
+            create_node: LinearCost::new(FIXED_MEDIUM_FEE, 100),
+            drop_node: LinearCost::new(FIXED_MEDIUM_FEE, 100),
+            allocate_node_id: LinearCost::fixed(FIXED_LOW_FEE),
+            lock_substate: LinearCost::fixed(FIXED_LOW_FEE),
+            read_substate: LinearCost::new(FIXED_LOW_FEE, 10),
+            write_substate: LinearCost::new(FIXED_LOW_FEE, 1000),
+            drop_lock: LinearCost::fixed(FIXED_LOW_FEE),
+            scan_substates: LinearCost::new(FIXED_LOW_FEE, 100),
+            check_auth: LinearCost::fixed(FIXED_LOW_FEE),
+            emit_event: LinearCost::new(FIXED_LOW_FEE, 10),
+            emit_log: LinearCost::new(FIXED_LOW_FEE, 10),
+            read_transaction_hash: LinearCost::fixed(FIXED_LOW_FEE),
+            generate_uuid: LinearCost::fixed(FIXED_LOW_FEE),
+            blake2b_256_hash: LinearCost::new(FIXED_LOW_FEE, 2),
+            sha256_hash: LinearCost::new(FIXED_LOW_FEE, 2),
+            keccak256_hash: LinearCost::new(FIXED_LOW_FEE, 2),
+            verify_ecdsa_secp256k1: LinearCost::fixed(50_000),
+            recover_ecdsa_secp256k1: LinearCost::fixed(50_000),
+            verify_eddsa_ed25519: LinearCost::new(50_000, 2),
+            verify_bls12381_aggregate: LinearCost::new(50_000, 150_000),
+            verify_bls12381_aggregate_per_byte: 2,
+            grow_wasm_memory_per_page: FIXED_HIGH_FEE,
         }
     }
 
+    /// Decodes a fee table from its SBOR representation.
+    pub fn from_sbor(payload: &[u8]) -> Result<Self, FeeTableLoadError> {
+        scrypto_decode(payload).map_err(FeeTableLoadError::DecodeError)
+    }
+
+    /// Encodes this fee table into its SBOR representation.
+    pub fn to_sbor(&self) -> Vec<u8> {
+        scrypto_encode(self).expect("Failed to encode fee table")
+    }
+
+    /// Parses a fee table from its (simple) JSON representation, as produced by [`Self::to_json`].
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, FeeTableLoadError> {
+        let json: JsonValue = serde_json::from_str(json)
+            .map_err(|error| FeeTableLoadError::InvalidJson(error.to_string()))?;
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<Self, ScryptoCustomTypeExtension>();
+        let context = SerializationContext {
+            schema: &schema,
+            mode: SerializationMode::Simple,
+            custom_context: ScryptoValueDisplayContext::no_context(),
+        };
+        let payload = json_to_payload(&json, &context, type_index)
+            .map_err(|error| FeeTableLoadError::InvalidJson(error.error_message()))?;
+        Self::from_sbor(&payload)
+    }
+
+    /// Outputs this fee table in its (simple) JSON representation.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<Self, ScryptoCustomTypeExtension>();
+        let payload = self.to_sbor();
+        let payload =
+            SborPayloadWithSchema::<ScryptoCustomTypeExtension>::new(&payload, type_index);
+        let serializable = payload.serializable(SerializationContext {
+            schema: &schema,
+            mode: SerializationMode::Simple,
+            custom_context: ScryptoValueDisplayContext::no_context(),
+        });
+        serde_json::to_string_pretty(&serializable).expect("Failed to serialize fee table")
+    }
+
     pub fn tx_base_fee(&self) -> u32 {
         self.tx_base_fee
     }
@@ -56,15 +233,131 @@ impl FeeTable {
 
     pub fn kernel_api_cost(&self, entry: CostingEntry) -> u32 {
         match entry {
-            CostingEntry::Invoke { input_size } => FIXED_LOW_FEE + (10 * input_size) as u32,
+            CostingEntry::Invoke { input_size } => self.invoke.cost(input_size),
+
+            CostingEntry::CreateNode { size } => self.create_node.cost(size),
+            CostingEntry::DropNode { size } => self.drop_node.cost(size),
+            CostingEntry::AllocateNodeId => self.allocate_node_id.cost(0),
+
+            CostingEntry::LockSubstate => self.lock_substate.cost(0),
+            CostingEntry::ReadSubstate { size } => self.read_substate.cost(size),
+            CostingEntry::WriteSubstate { size } => self.write_substate.cost(size),
+            CostingEntry::DropLock => self.drop_lock.cost(0),
+            CostingEntry::ScanSubstates { entries } => self.scan_substates.cost(entries),
 
-            CostingEntry::CreateNode { size } => FIXED_MEDIUM_FEE + (100 * size) as u32,
-            CostingEntry::DropNode { size } => FIXED_MEDIUM_FEE + (100 * size) as u32,
+            CostingEntry::CheckAuth => self.check_auth.cost(0),
 
-            CostingEntry::LockSubstate => FIXED_LOW_FEE,
-            CostingEntry::ReadSubstate { size } => FIXED_LOW_FEE + 10 * size,
-            CostingEntry::WriteSubstate { size } => FIXED_LOW_FEE + 1000 * size,
-            CostingEntry::DropLock => FIXED_LOW_FEE,
+            CostingEntry::EmitEvent { size } => self.emit_event.cost(size),
+            CostingEntry::EmitLog { size } => self.emit_log.cost(size),
+            CostingEntry::ReadTransactionHash => self.read_transaction_hash.cost(0),
+            CostingEntry::GenerateUuid => self.generate_uuid.cost(0),
+
+            CostingEntry::Blake2b256Hash { size } => self.blake2b_256_hash.cost(size),
+            CostingEntry::Sha256Hash { size } => self.sha256_hash.cost(size),
+            CostingEntry::Keccak256Hash { size } => self.keccak256_hash.cost(size),
+            CostingEntry::VerifyEcdsaSecp256k1 => self.verify_ecdsa_secp256k1.cost(0),
+            CostingEntry::RecoverEcdsaSecp256k1 => self.recover_ecdsa_secp256k1.cost(0),
+            CostingEntry::VerifyEddsaEd25519 { size } => self.verify_eddsa_ed25519.cost(size),
+            CostingEntry::VerifyBls12381Aggregate { keys, size } => self
+                .verify_bls12381_aggregate
+                .cost(keys)
+                .saturating_add(self.verify_bls12381_aggregate_per_byte.saturating_mul(size)),
+
+            CostingEntry::GrowWasmMemory { pages } => {
+                self.grow_wasm_memory_per_page.saturating_mul(pages)
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_kernel_api_costs() {
+        let fee_table = FeeTable::new();
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::Invoke { input_size: 3 }),
+            FIXED_LOW_FEE + 30
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::CreateNode { size: 2 }),
+            FIXED_MEDIUM_FEE + 200
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::LockSubstate),
+            FIXED_LOW_FEE
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::WriteSubstate { size: 4 }),
+            FIXED_LOW_FEE + 4000
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::EmitEvent { size: 6 }),
+            FIXED_LOW_FEE + 60
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::ScanSubstates { entries: 3 }),
+            FIXED_LOW_FEE + 300
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::Keccak256Hash { size: 32 }),
+            FIXED_LOW_FEE + 64
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::VerifyBls12381Aggregate { keys: 2, size: 100 }),
+            50_000 + 300_000 + 200
+        );
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::GrowWasmMemory { pages: 2 }),
+            2 * FIXED_HIGH_FEE
+        );
+    }
+
+    #[test]
+    fn test_custom_kernel_api_costs() {
+        let fee_table = FeeTable {
+            read_substate: LinearCost::new(7, 2),
+            drop_lock: LinearCost::fixed(0),
+            ..FeeTable::new()
+        };
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::ReadSubstate { size: 5 }),
+            17
+        );
+        assert_eq!(fee_table.kernel_api_cost(CostingEntry::DropLock), 0);
+        assert_eq!(
+            fee_table.kernel_api_cost(CostingEntry::WriteSubstate { size: u32::MAX }),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn test_sbor_round_trip() {
+        let fee_table = FeeTable {
+            tx_base_fee: 1,
+            ..FeeTable::new()
+        };
+        assert_eq!(FeeTable::from_sbor(&fee_table.to_sbor()), Ok(fee_table));
+        assert!(matches!(
+            FeeTable::from_sbor(&[0u8; 3]),
+            Err(FeeTableLoadError::DecodeError(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_json_round_trip() {
+        let fee_table = FeeTable {
+            invoke: LinearCost::new(1, 2),
+            ..FeeTable::new()
+        };
+        let json = fee_table.to_json();
+        assert_eq!(FeeTable::from_json(&json), Ok(fee_table));
+        assert!(matches!(
+            FeeTable::from_json("{\"tx_base_fee\": 1}"),
+            Err(FeeTableLoadError::InvalidJson(_))
+        ));
+    }
+}
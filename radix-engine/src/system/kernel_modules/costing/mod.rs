@@ -1,8 +1,10 @@
+mod cost_tree;
 mod costing_module;
 mod fee_reserve;
 mod fee_summary;
 mod fee_table;
 
+pub use cost_tree::*;
 pub use costing_module::*;
 pub use fee_reserve::*;
 pub use fee_summary::*;
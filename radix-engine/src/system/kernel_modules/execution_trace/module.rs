@@ -207,6 +207,7 @@ impl ExecutionTrace {
                             .push(WorktopChange::Put(bucket_snapshot.into()))
                     }
                 } else if fn_identifier.ident == WORKTOP_TAKE_IDENT
+                    || fn_identifier.ident == WORKTOP_TAKE_ADVANCED_IDENT
                     || fn_identifier.ident == WORKTOP_TAKE_ALL_IDENT
                     || fn_identifier.ident == WORKTOP_TAKE_NON_FUNGIBLES_IDENT
                     || fn_identifier.ident == WORKTOP_DRAIN_IDENT
@@ -515,7 +516,7 @@ impl ExecutionTraceModule {
                     ActorIdentifier::Method(MethodIdentifier(RENodeId::Object(vault_id), ..)),
             }) if package_address.eq(&RESOURCE_MANAGER_PACKAGE)
                 && blueprint_name.eq(VAULT_BLUEPRINT)
-                && ident.eq(VAULT_TAKE_IDENT) =>
+                && (ident.eq(VAULT_TAKE_IDENT) || ident.eq(VAULT_TAKE_ADVANCED_IDENT)) =>
             {
                 self.handle_vault_take_output(&resource_summary, caller, vault_id)
             }
@@ -159,6 +159,14 @@ impl TransactionLimitsModule {
         }
     }
 
+    /// Returns the WASM memory usage last reported by the call frame at the given depth.
+    pub fn wasm_memory_usage(&self, depth: usize) -> usize {
+        self.call_frames_stack
+            .get(depth)
+            .map(|info| info.wasm_memory_usage)
+            .unwrap_or(0)
+    }
+
     // This event handler is called from two places:
     //  1. Before wasm nested function call
     //  2. After wasm invocation
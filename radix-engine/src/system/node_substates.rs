@@ -4,6 +4,7 @@ use crate::blueprints::access_controller::AccessControllerSubstate;
 use crate::blueprints::account::AccountSubstate;
 use crate::blueprints::clock::ClockSubstate;
 use crate::blueprints::epoch_manager::EpochManagerSubstate;
+use crate::blueprints::epoch_manager::ProposalStatisticSubstate;
 use crate::blueprints::epoch_manager::ValidatorSetSubstate;
 use crate::blueprints::epoch_manager::ValidatorSubstate;
 use crate::blueprints::package::PackageCodeTypeSubstate;
@@ -16,6 +17,7 @@ use radix_engine_interface::api::component::*;
 use radix_engine_interface::api::types::{
     ComponentOffset, KeyValueStoreOffset, RENodeId, SubstateOffset,
 };
+use radix_engine_interface::blueprints::epoch_manager::EpochManagerConfig;
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::blueprints::resource::LiquidNonFungibleResource;
@@ -25,7 +27,9 @@ use radix_engine_interface::blueprints::resource::LockedNonFungibleResource;
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum PersistedSubstate {
     EpochManager(EpochManagerSubstate),
+    EpochManagerConfig(EpochManagerConfig),
     ValidatorSet(ValidatorSetSubstate),
+    ProposalStatistic(ProposalStatisticSubstate),
     Validator(ValidatorSubstate),
     CurrentTimeRoundedToMinutes(ClockSubstate),
     ResourceManager(FungibleResourceManagerSubstate),
@@ -43,6 +47,7 @@ pub enum PersistedSubstate {
     VaultLiquidNonFungible(LiquidNonFungibleResource),
     VaultLockedFungible(LockedFungibleResource),
     VaultLockedNonFungible(LockedNonFungibleResource),
+    VaultFrozenFlag(VaultFrozenFlagSubstate),
 
     /* Type info */
     TypeInfo(TypeInfoSubstate),
@@ -166,6 +171,16 @@ impl Into<VaultInfoSubstate> for PersistedSubstate {
     }
 }
 
+impl Into<VaultFrozenFlagSubstate> for PersistedSubstate {
+    fn into(self) -> VaultFrozenFlagSubstate {
+        if let PersistedSubstate::VaultFrozenFlag(vault) = self {
+            vault
+        } else {
+            panic!("Not a vault");
+        }
+    }
+}
+
 impl Into<LiquidFungibleResource> for PersistedSubstate {
     fn into(self) -> LiquidFungibleResource {
         if let PersistedSubstate::VaultLiquidFungible(vault) = self {
@@ -190,7 +205,13 @@ impl PersistedSubstate {
     pub fn to_runtime(self) -> RuntimeSubstate {
         match self {
             PersistedSubstate::EpochManager(value) => RuntimeSubstate::EpochManager(value),
+            PersistedSubstate::EpochManagerConfig(value) => {
+                RuntimeSubstate::EpochManagerConfig(value)
+            }
             PersistedSubstate::ValidatorSet(value) => RuntimeSubstate::ValidatorSet(value),
+            PersistedSubstate::ProposalStatistic(value) => {
+                RuntimeSubstate::ProposalStatistic(value)
+            }
             PersistedSubstate::Validator(value) => RuntimeSubstate::Validator(value),
             PersistedSubstate::CurrentTimeRoundedToMinutes(value) => {
                 RuntimeSubstate::CurrentTimeRoundedToMinutes(value)
@@ -220,6 +241,7 @@ impl PersistedSubstate {
             PersistedSubstate::VaultLockedNonFungible(value) => {
                 RuntimeSubstate::VaultLockedNonFungible(value)
             }
+            PersistedSubstate::VaultFrozenFlag(value) => RuntimeSubstate::VaultFrozenFlag(value),
             PersistedSubstate::KeyValueStoreEntry(value) => {
                 RuntimeSubstate::KeyValueStoreEntry(value)
             }
@@ -244,7 +266,9 @@ impl PersistedSubstate {
 #[derive(Debug)]
 pub enum RuntimeSubstate {
     EpochManager(EpochManagerSubstate),
+    EpochManagerConfig(EpochManagerConfig),
     ValidatorSet(ValidatorSetSubstate),
+    ProposalStatistic(ProposalStatisticSubstate),
     Validator(ValidatorSubstate),
     CurrentTimeRoundedToMinutes(ClockSubstate),
     ResourceManager(FungibleResourceManagerSubstate),
@@ -267,6 +291,7 @@ pub enum RuntimeSubstate {
     VaultLiquidNonFungible(LiquidNonFungibleResource),
     VaultLockedFungible(LockedFungibleResource),
     VaultLockedNonFungible(LockedNonFungibleResource),
+    VaultFrozenFlag(VaultFrozenFlagSubstate),
 
     BucketInfo(BucketInfoSubstate),
     BucketLiquidFungible(LiquidFungibleResource),
@@ -296,7 +321,13 @@ impl RuntimeSubstate {
     pub fn clone_to_persisted(&self) -> PersistedSubstate {
         match self {
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value.clone()),
+            RuntimeSubstate::EpochManagerConfig(value) => {
+                PersistedSubstate::EpochManagerConfig(value.clone())
+            }
             RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value.clone()),
+            RuntimeSubstate::ProposalStatistic(value) => {
+                PersistedSubstate::ProposalStatistic(value.clone())
+            }
             RuntimeSubstate::Validator(value) => PersistedSubstate::Validator(value.clone()),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value.clone())
@@ -331,6 +362,9 @@ impl RuntimeSubstate {
             RuntimeSubstate::VaultLiquidNonFungible(value) => {
                 PersistedSubstate::VaultLiquidNonFungible(value.clone())
             }
+            RuntimeSubstate::VaultFrozenFlag(value) => {
+                PersistedSubstate::VaultFrozenFlag(value.clone())
+            }
             RuntimeSubstate::Account(value) => PersistedSubstate::Account(value.clone()),
             RuntimeSubstate::AccessController(value) => {
                 PersistedSubstate::AccessController(value.clone())
@@ -368,7 +402,13 @@ impl RuntimeSubstate {
     pub fn to_persisted(self) -> PersistedSubstate {
         match self {
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value),
+            RuntimeSubstate::EpochManagerConfig(value) => {
+                PersistedSubstate::EpochManagerConfig(value)
+            }
             RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value),
+            RuntimeSubstate::ProposalStatistic(value) => {
+                PersistedSubstate::ProposalStatistic(value)
+            }
             RuntimeSubstate::Validator(value) => PersistedSubstate::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value)
@@ -401,6 +441,7 @@ impl RuntimeSubstate {
             RuntimeSubstate::VaultLockedNonFungible(value) => {
                 PersistedSubstate::VaultLockedNonFungible(value)
             }
+            RuntimeSubstate::VaultFrozenFlag(value) => PersistedSubstate::VaultFrozenFlag(value),
             RuntimeSubstate::Account(value) => PersistedSubstate::Account(value),
             RuntimeSubstate::AccessController(value) => PersistedSubstate::AccessController(value),
 
@@ -459,7 +500,9 @@ impl RuntimeSubstate {
     pub fn to_ref_mut(&mut self) -> SubstateRefMut {
         match self {
             RuntimeSubstate::EpochManager(value) => SubstateRefMut::EpochManager(value),
+            RuntimeSubstate::EpochManagerConfig(value) => SubstateRefMut::EpochManagerConfig(value),
             RuntimeSubstate::ValidatorSet(value) => SubstateRefMut::ValidatorSet(value),
+            RuntimeSubstate::ProposalStatistic(value) => SubstateRefMut::ProposalStatistic(value),
             RuntimeSubstate::Validator(value) => SubstateRefMut::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRefMut::CurrentTimeRoundedToMinutes(value)
@@ -497,6 +540,7 @@ impl RuntimeSubstate {
             RuntimeSubstate::VaultLockedNonFungible(value) => {
                 SubstateRefMut::VaultLockedNonFungible(value)
             }
+            RuntimeSubstate::VaultFrozenFlag(value) => SubstateRefMut::VaultFrozenFlag(value),
             RuntimeSubstate::BucketInfo(value) => SubstateRefMut::BucketInfo(value),
             RuntimeSubstate::BucketLiquidFungible(value) => {
                 SubstateRefMut::BucketLiquidFungible(value)
@@ -525,7 +569,9 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::TypeInfo(value) => SubstateRef::TypeInfo(value),
             RuntimeSubstate::EpochManager(value) => SubstateRef::EpochManager(value),
+            RuntimeSubstate::EpochManagerConfig(value) => SubstateRef::EpochManagerConfig(value),
             RuntimeSubstate::ValidatorSet(value) => SubstateRef::ValidatorSet(value),
+            RuntimeSubstate::ProposalStatistic(value) => SubstateRef::ProposalStatistic(value),
             RuntimeSubstate::Validator(value) => SubstateRef::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRef::CurrentTimeRoundedToMinutes(value)
@@ -556,6 +602,7 @@ impl RuntimeSubstate {
             RuntimeSubstate::VaultLockedNonFungible(value) => {
                 SubstateRef::VaultLockedNonFungible(value)
             }
+            RuntimeSubstate::VaultFrozenFlag(value) => SubstateRef::VaultFrozenFlag(value),
             RuntimeSubstate::BucketInfo(value) => SubstateRef::BucketInfo(value),
             RuntimeSubstate::BucketLiquidFungible(value) => {
                 SubstateRef::BucketLiquidFungible(value)
@@ -657,12 +704,24 @@ impl Into<RuntimeSubstate> for EpochManagerSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for EpochManagerConfig {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::EpochManagerConfig(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for ValidatorSetSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::ValidatorSet(self)
     }
 }
 
+impl Into<RuntimeSubstate> for ProposalStatisticSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::ProposalStatistic(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for ValidatorSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::Validator(self)
@@ -734,6 +793,12 @@ impl Into<RuntimeSubstate> for VaultInfoSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for VaultFrozenFlagSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::VaultFrozenFlag(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for Option<ScryptoValue> {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::KeyValueStoreEntry(self)
@@ -858,6 +923,16 @@ impl Into<Option<ScryptoValue>> for RuntimeSubstate {
     }
 }
 
+impl Into<VaultFrozenFlagSubstate> for RuntimeSubstate {
+    fn into(self) -> VaultFrozenFlagSubstate {
+        if let RuntimeSubstate::VaultFrozenFlag(vault) = self {
+            vault
+        } else {
+            panic!("Not a vault frozen flag");
+        }
+    }
+}
+
 impl Into<VaultInfoSubstate> for RuntimeSubstate {
     fn into(self) -> VaultInfoSubstate {
         if let RuntimeSubstate::VaultInfo(vault) = self {
@@ -912,6 +987,16 @@ impl Into<EpochManagerSubstate> for RuntimeSubstate {
     }
 }
 
+impl Into<EpochManagerConfig> for RuntimeSubstate {
+    fn into(self) -> EpochManagerConfig {
+        if let RuntimeSubstate::EpochManagerConfig(substate) = self {
+            substate
+        } else {
+            panic!("Not an epoch manager config");
+        }
+    }
+}
+
 impl Into<ValidatorSubstate> for RuntimeSubstate {
     fn into(self) -> ValidatorSubstate {
         if let RuntimeSubstate::Validator(validator) = self {
@@ -972,6 +1057,16 @@ impl Into<ValidatorSetSubstate> for RuntimeSubstate {
     }
 }
 
+impl Into<ProposalStatisticSubstate> for RuntimeSubstate {
+    fn into(self) -> ProposalStatisticSubstate {
+        if let RuntimeSubstate::ProposalStatistic(substate) = self {
+            substate
+        } else {
+            panic!("Not a proposal statistic");
+        }
+    }
+}
+
 impl Into<AuthZoneStackSubstate> for RuntimeSubstate {
     fn into(self) -> AuthZoneStackSubstate {
         if let RuntimeSubstate::AuthZoneStack(substate) = self {
@@ -1000,6 +1095,7 @@ pub enum SubstateRef<'a> {
     VaultLiquidNonFungible(&'a LiquidNonFungibleResource),
     VaultLockedFungible(&'a LockedFungibleResource),
     VaultLockedNonFungible(&'a LockedNonFungibleResource),
+    VaultFrozenFlag(&'a VaultFrozenFlagSubstate),
     BucketInfo(&'a BucketInfoSubstate),
     BucketLiquidFungible(&'a LiquidFungibleResource),
     BucketLiquidNonFungible(&'a LiquidNonFungibleResource),
@@ -1011,7 +1107,9 @@ pub enum SubstateRef<'a> {
     FungibleResourceManager(&'a FungibleResourceManagerSubstate),
     NonFungibleResourceManager(&'a NonFungibleResourceManagerSubstate),
     EpochManager(&'a EpochManagerSubstate),
+    EpochManagerConfig(&'a EpochManagerConfig),
     ValidatorSet(&'a ValidatorSetSubstate),
+    ProposalStatistic(&'a ProposalStatisticSubstate),
     Validator(&'a ValidatorSubstate),
     CurrentTimeRoundedToMinutes(&'a ClockSubstate),
     MethodAccessRules(&'a MethodAccessRulesSubstate),
@@ -1059,6 +1157,15 @@ impl<'a> From<SubstateRef<'a>> for &'a LockedFungibleResource {
     }
 }
 
+impl<'a> From<SubstateRef<'a>> for &'a VaultFrozenFlagSubstate {
+    fn from(value: SubstateRef<'a>) -> Self {
+        match value {
+            SubstateRef::VaultFrozenFlag(value) => value,
+            _ => panic!("Not a vault frozen flag"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRef<'a>> for &'a LockedNonFungibleResource {
     fn from(value: SubstateRef<'a>) -> Self {
         match value {
@@ -1123,6 +1230,33 @@ impl<'a> From<SubstateRef<'a>> for &'a EpochManagerSubstate {
     }
 }
 
+impl<'a> From<SubstateRef<'a>> for &'a EpochManagerConfig {
+    fn from(value: SubstateRef<'a>) -> Self {
+        match value {
+            SubstateRef::EpochManagerConfig(value) => value,
+            _ => panic!("Not an epoch manager config"),
+        }
+    }
+}
+
+impl<'a> From<SubstateRef<'a>> for &'a ValidatorSetSubstate {
+    fn from(value: SubstateRef<'a>) -> Self {
+        match value {
+            SubstateRef::ValidatorSet(value) => value,
+            _ => panic!("Not a validator set"),
+        }
+    }
+}
+
+impl<'a> From<SubstateRef<'a>> for &'a ProposalStatisticSubstate {
+    fn from(value: SubstateRef<'a>) -> Self {
+        match value {
+            SubstateRef::ProposalStatistic(value) => value,
+            _ => panic!("Not a proposal statistic"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRef<'a>> for &'a ValidatorSubstate {
     fn from(value: SubstateRef<'a>) -> Self {
         match value {
@@ -1290,6 +1424,7 @@ impl<'a> SubstateRef<'a> {
         match self {
             SubstateRef::PackageCodeType(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::EpochManager(value) => IndexedScryptoValue::from_typed(*value),
+            SubstateRef::EpochManagerConfig(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::CurrentTimeRoundedToMinutes(value) => {
                 IndexedScryptoValue::from_typed(*value)
             }
@@ -1392,8 +1527,26 @@ impl<'a> SubstateRef<'a> {
                 references.insert(RENodeId::GlobalObject(substate.liquidity_token.into()));
                 owned_nodes.push(RENodeId::Object(substate.stake_xrd_vault_id));
                 owned_nodes.push(RENodeId::Object(substate.pending_xrd_withdraw_vault_id));
+                owned_nodes.push(RENodeId::Object(substate.locked_owner_stake_unit_vault_id));
+                owned_nodes.push(RENodeId::Object(
+                    substate.pending_owner_stake_unit_unlock_vault_id,
+                ));
                 (references, owned_nodes)
             }
+            SubstateRef::ValidatorSet(substate) => {
+                let mut references = HashSet::new();
+                for validator_address in substate.validator_set.keys() {
+                    references.insert(RENodeId::GlobalObject((*validator_address).into()));
+                }
+                (references, Vec::new())
+            }
+            SubstateRef::ProposalStatistic(substate) => {
+                let mut references = HashSet::new();
+                for validator_address in substate.validator_statistics.keys() {
+                    references.insert(RENodeId::GlobalObject((*validator_address).into()));
+                }
+                (references, Vec::new())
+            }
             SubstateRef::MethodAccessRules(substate) => {
                 let (_, owns, refs) = IndexedScryptoValue::from_typed(&substate).unpack();
                 (refs, owns)
@@ -1459,6 +1612,7 @@ pub enum SubstateRefMut<'a> {
     VaultLiquidNonFungible(&'a mut LiquidNonFungibleResource),
     VaultLockedFungible(&'a mut LockedFungibleResource),
     VaultLockedNonFungible(&'a mut LockedNonFungibleResource),
+    VaultFrozenFlag(&'a mut VaultFrozenFlagSubstate),
     BucketInfo(&'a mut BucketInfoSubstate),
     BucketLiquidFungible(&'a mut LiquidFungibleResource),
     BucketLiquidNonFungible(&'a mut LiquidNonFungibleResource),
@@ -1467,7 +1621,9 @@ pub enum SubstateRefMut<'a> {
     ResourceManager(&'a mut FungibleResourceManagerSubstate),
     NonFungibleResourceManager(&'a mut NonFungibleResourceManagerSubstate),
     EpochManager(&'a mut EpochManagerSubstate),
+    EpochManagerConfig(&'a mut EpochManagerConfig),
     ValidatorSet(&'a mut ValidatorSetSubstate),
+    ProposalStatistic(&'a mut ProposalStatisticSubstate),
     Validator(&'a mut ValidatorSubstate),
     CurrentTimeRoundedToMinutes(&'a mut ClockSubstate),
     MethodAccessRules(&'a mut MethodAccessRulesSubstate),
@@ -1571,6 +1727,15 @@ impl<'a> From<SubstateRefMut<'a>> for &'a mut EpochManagerSubstate {
     }
 }
 
+impl<'a> From<SubstateRefMut<'a>> for &'a mut EpochManagerConfig {
+    fn from(value: SubstateRefMut<'a>) -> Self {
+        match value {
+            SubstateRefMut::EpochManagerConfig(value) => value,
+            _ => panic!("Not an epoch manager config"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRefMut<'a>> for &'a mut ValidatorSubstate {
     fn from(value: SubstateRefMut<'a>) -> Self {
         match value {
@@ -1589,6 +1754,15 @@ impl<'a> From<SubstateRefMut<'a>> for &'a mut ValidatorSetSubstate {
     }
 }
 
+impl<'a> From<SubstateRefMut<'a>> for &'a mut ProposalStatisticSubstate {
+    fn from(value: SubstateRefMut<'a>) -> Self {
+        match value {
+            SubstateRefMut::ProposalStatistic(value) => value,
+            _ => panic!("Not a proposal statistic"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRefMut<'a>> for &'a mut ClockSubstate {
     fn from(value: SubstateRefMut<'a>) -> Self {
         match value {
@@ -1616,6 +1790,15 @@ impl<'a> From<SubstateRefMut<'a>> for &'a mut AccessControllerSubstate {
     }
 }
 
+impl<'a> From<SubstateRefMut<'a>> for &'a mut AccountSubstate {
+    fn from(value: SubstateRefMut<'a>) -> Self {
+        match value {
+            SubstateRefMut::Account(value) => value,
+            _ => panic!("Not an account"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRefMut<'a>> for &'a mut ProofInfoSubstate {
     fn from(value: SubstateRefMut<'a>) -> Self {
         match value {
@@ -1664,6 +1847,15 @@ impl<'a> From<SubstateRefMut<'a>> for &'a mut LockedFungibleResource {
     }
 }
 
+impl<'a> From<SubstateRefMut<'a>> for &'a mut VaultFrozenFlagSubstate {
+    fn from(value: SubstateRefMut<'a>) -> Self {
+        match value {
+            SubstateRefMut::VaultFrozenFlag(value) => value,
+            _ => panic!("Not a vault frozen flag"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRefMut<'a>> for &'a mut LockedNonFungibleResource {
     fn from(value: SubstateRefMut<'a>) -> Self {
         match value {
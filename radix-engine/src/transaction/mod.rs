@@ -1,9 +1,11 @@
 mod preview_executor;
 mod reference_extractor; // TODO: merge with TransactionValidator
+mod replay;
 mod transaction_executor;
 mod transaction_receipt;
 
 pub use preview_executor::*;
 pub use reference_extractor::*;
+pub use replay::*;
 pub use transaction_executor::*;
 pub use transaction_receipt::*;
@@ -92,7 +92,9 @@ pub fn extract_refs_from_instruction(
             let value: ManifestValue = manifest_decode(&manifest_encode(rule).unwrap()).unwrap();
             extract_refs_from_value(&value, global_references, local_references);
         }
-        Instruction::RecallResource { vault_id, .. } => {
+        Instruction::RecallResource { vault_id, .. }
+        | Instruction::FreezeVault { vault_id, .. }
+        | Instruction::UnfreezeVault { vault_id, .. } => {
             // TODO: This needs to be cleaned up
             // TODO: How does this relate to newly created vaults in the transaction frame?
             // TODO: Will probably want different spacing for refed vs. owned nodes
@@ -124,6 +126,9 @@ pub fn extract_refs_from_instruction(
         | Instruction::TakeFromWorktopByIds {
             resource_address, ..
         }
+        | Instruction::TakeFromWorktopAdvanced {
+            resource_address, ..
+        }
         | Instruction::AssertWorktopContains {
             resource_address, ..
         }
@@ -144,6 +149,9 @@ pub fn extract_refs_from_instruction(
         }
         | Instruction::MintFungible {
             resource_address, ..
+        }
+        | Instruction::BurnNonFungibles {
+            resource_address, ..
         } => {
             global_references.insert(resource_address.clone().into());
         }
@@ -0,0 +1,357 @@
+use crate::kernel::interpreters::ScryptoInterpreter;
+use crate::ledger::*;
+use crate::transaction::*;
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::network::NetworkDefinition;
+use transaction::errors::TransactionValidationError;
+use transaction::model::{NotarizedTransaction, SystemTransaction};
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
+
+/// A transaction committed to a ledger, in the form it can be re-executed in.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum LedgerTransaction {
+    /// A user transaction, validated before execution.
+    User(NotarizedTransaction),
+    /// A system transaction, executed with the system role.
+    System(SystemTransaction),
+}
+
+/// A committed transaction, together with the receipt it originally produced.
+#[derive(Clone, ScryptoSbor)]
+pub struct RecordedTransaction {
+    /// The manifest-encoded `LedgerTransaction`.
+    pub transaction_payload: Vec<u8>,
+    pub receipt: TransactionReceipt,
+}
+
+impl RecordedTransaction {
+    pub fn new(transaction: &LedgerTransaction, receipt: TransactionReceipt) -> Self {
+        Self {
+            transaction_payload: manifest_encode(transaction)
+                .expect("Could not encode ledger transaction"),
+            receipt,
+        }
+    }
+}
+
+/// The first point at which a replay did not reproduce the recorded receipts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayDivergence {
+    /// The index of the diverging transaction within the replayed sequence.
+    pub index: usize,
+    pub reason: DivergenceReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DivergenceReason {
+    /// The recorded transaction payload could not be decoded.
+    InvalidPayload(DecodeError),
+    /// A recorded user transaction no longer passes validation.
+    ValidationError(TransactionValidationError),
+    /// The result (i.e. commit success/failure, rejection or abort) differs.
+    Result { expected: String, actual: String },
+    /// The outputs of the manifest instructions differ.
+    InstructionOutputs,
+    /// The committed state updates differ; lists the substates which were updated differently.
+    StateUpdates { substate_ids: Vec<SubstateId> },
+    /// The emitted application events differ.
+    ApplicationEvents,
+    /// The fee summary differs.
+    FeeSummary,
+}
+
+/// Re-executes recorded transactions, in order, and compares the new receipts with the recorded
+/// ones.
+///
+/// The substate store should be bootstrapped in the same way as the ledger on which the
+/// transactions were originally committed.
+pub struct TransactionReplayer<'s, 'w, S, W>
+where
+    S: ReadableSubstateStore + WriteableSubstateStore,
+    W: WasmEngine,
+{
+    substate_store: &'s mut S,
+    scrypto_interpreter: &'w ScryptoInterpreter<W>,
+    validator: NotarizedTransactionValidator,
+    intent_hash_manager: TestIntentHashManager,
+    fee_reserve_config: FeeReserveConfig,
+    execution_config: ExecutionConfig,
+    replayed_count: usize,
+}
+
+impl<'s, 'w, S, W> TransactionReplayer<'s, 'w, S, W>
+where
+    S: ReadableSubstateStore + WriteableSubstateStore,
+    W: WasmEngine,
+{
+    pub fn new(
+        substate_store: &'s mut S,
+        scrypto_interpreter: &'w ScryptoInterpreter<W>,
+        network: &NetworkDefinition,
+    ) -> Self {
+        Self {
+            substate_store,
+            scrypto_interpreter,
+            validator: NotarizedTransactionValidator::new(ValidationConfig::default(network.id)),
+            intent_hash_manager: TestIntentHashManager::new(),
+            fee_reserve_config: FeeReserveConfig::default(),
+            execution_config: ExecutionConfig::standard(),
+            replayed_count: 0,
+        }
+    }
+
+    pub fn with_fee_reserve_config(mut self, fee_reserve_config: FeeReserveConfig) -> Self {
+        self.fee_reserve_config = fee_reserve_config;
+        self
+    }
+
+    pub fn with_execution_config(mut self, execution_config: ExecutionConfig) -> Self {
+        self.execution_config = execution_config;
+        self
+    }
+
+    /// Returns the number of transactions replayed so far.
+    pub fn replayed_count(&self) -> usize {
+        self.replayed_count
+    }
+
+    /// Replays all the given transactions, stopping at the first divergence.
+    /// Returns the total number of transactions replayed so far.
+    pub fn replay_all(
+        &mut self,
+        transactions: &[RecordedTransaction],
+    ) -> Result<usize, ReplayDivergence> {
+        for transaction in transactions {
+            self.replay(transaction)?;
+        }
+        Ok(self.replayed_count)
+    }
+
+    /// Re-executes a single transaction and commits its state updates (if any).
+    /// Returns the new receipt, if it matches the recorded one.
+    pub fn replay(
+        &mut self,
+        recorded: &RecordedTransaction,
+    ) -> Result<TransactionReceipt, ReplayDivergence> {
+        let index = self.replayed_count;
+        let receipt = self
+            .execute(&recorded.transaction_payload)
+            .and_then(|receipt| compare_receipts(&recorded.receipt, &receipt).map(|_| receipt))
+            .map_err(|reason| ReplayDivergence { index, reason })?;
+        if let TransactionResult::Commit(commit) = &receipt.result {
+            commit.state_updates.commit(self.substate_store);
+        }
+        self.replayed_count += 1;
+        Ok(receipt)
+    }
+
+    fn execute(&mut self, payload: &[u8]) -> Result<TransactionReceipt, DivergenceReason> {
+        let transaction: LedgerTransaction =
+            manifest_decode(payload).map_err(DivergenceReason::InvalidPayload)?;
+        let receipt = match &transaction {
+            LedgerTransaction::User(notarized) => {
+                // The payload size is that of the notarized transaction, as originally submitted
+                let payload_size = notarized
+                    .to_bytes()
+                    .expect("Could not encode notarized transaction")
+                    .len();
+                let executable = self
+                    .validator
+                    .validate(notarized, payload_size, &self.intent_hash_manager)
+                    .map_err(DivergenceReason::ValidationError)?;
+                execute_transaction(
+                    self.substate_store,
+                    self.scrypto_interpreter,
+                    &self.fee_reserve_config,
+                    &self.execution_config,
+                    &executable,
+                )
+            }
+            LedgerTransaction::System(system) => execute_transaction(
+                self.substate_store,
+                self.scrypto_interpreter,
+                &self.fee_reserve_config,
+                &self.execution_config,
+                &system.get_executable(vec![AuthAddresses::system_role()]),
+            ),
+        };
+        Ok(receipt)
+    }
+}
+
+/// Compares the parts of the receipts which must be reproduced deterministically.
+pub fn compare_receipts(
+    expected: &TransactionReceipt,
+    actual: &TransactionReceipt,
+) -> Result<(), DivergenceReason> {
+    let (expected_commit, actual_commit) = match (&expected.result, &actual.result) {
+        (TransactionResult::Commit(expected_commit), TransactionResult::Commit(actual_commit)) => {
+            (expected_commit, actual_commit)
+        }
+        (expected_result, actual_result) => {
+            let expected = describe_result(expected_result);
+            let actual = describe_result(actual_result);
+            if expected != actual {
+                return Err(DivergenceReason::Result { expected, actual });
+            }
+            return Ok(());
+        }
+    };
+
+    let expected_result = describe_result(&expected.result);
+    let actual_result = describe_result(&actual.result);
+    if expected_result != actual_result {
+        return Err(DivergenceReason::Result {
+            expected: expected_result,
+            actual: actual_result,
+        });
+    }
+    if !encodings_equal(&expected_commit.outcome, &actual_commit.outcome) {
+        return Err(DivergenceReason::InstructionOutputs);
+    }
+
+    let expected_updates = &expected_commit.state_updates;
+    let actual_updates = &actual_commit.state_updates;
+    let mut substate_ids = expected_updates
+        .up_substate_ids()
+        .union(&actual_updates.up_substate_ids())
+        .filter(|substate_id| {
+            let expected_value = expected_updates.up_substates.get(substate_id);
+            let actual_value = actual_updates.up_substates.get(substate_id);
+            expected_value != actual_value
+        })
+        .map(|substate_id| (*substate_id).clone())
+        .collect::<BTreeSet<SubstateId>>();
+    substate_ids.extend(
+        expected_updates
+            .down_substates
+            .symmetric_difference(&actual_updates.down_substates)
+            .map(|output_id| output_id.substate_id.clone()),
+    );
+    if !substate_ids.is_empty() {
+        return Err(DivergenceReason::StateUpdates {
+            substate_ids: substate_ids.into_iter().collect(),
+        });
+    }
+
+    if expected_commit.application_events != actual_commit.application_events {
+        return Err(DivergenceReason::ApplicationEvents);
+    }
+
+    if !encodings_equal(&expected_commit.fee_summary, &actual_commit.fee_summary)
+        || !encodings_equal(&expected_commit.fee_payments, &actual_commit.fee_payments)
+    {
+        return Err(DivergenceReason::FeeSummary);
+    }
+
+    Ok(())
+}
+
+fn describe_result(result: &TransactionResult) -> String {
+    match result {
+        TransactionResult::Commit(commit) => match &commit.outcome {
+            TransactionOutcome::Success(_) => "Commit(Success)".to_string(),
+            TransactionOutcome::Failure(error) => format!("Commit(Failure({:?}))", error),
+        },
+        TransactionResult::Reject(reject) => format!("Reject({:?})", reject.error),
+        TransactionResult::Abort(abort) => format!("Abort({:?})", abort.reason),
+    }
+}
+
+fn encodings_equal<T: ScryptoEncode>(expected: &T, actual: &T) -> bool {
+    scrypto_encode(expected).ok() == scrypto_encode(actual).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::TypedInMemorySubstateStore;
+    use crate::wasm::DefaultWasmEngine;
+    use radix_engine_interface::blueprints::epoch_manager::{
+        EpochManagerSetEpochInput, EPOCH_MANAGER_SET_EPOCH_IDENT,
+    };
+    use transaction::model::Instruction;
+
+    #[test]
+    fn replay_of_own_receipts_does_not_diverge() {
+        let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+        let mut original_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
+        let recorded = (0..3)
+            .map(|nonce| {
+                let transaction = LedgerTransaction::System(set_epoch_transaction(nonce));
+                let receipt = execute_transaction(
+                    &original_store,
+                    &scrypto_interpreter,
+                    &FeeReserveConfig::default(),
+                    &ExecutionConfig::standard(),
+                    &set_epoch_transaction(nonce)
+                        .get_executable(vec![AuthAddresses::system_role()]),
+                );
+                receipt
+                    .expect_commit_success()
+                    .state_updates
+                    .commit(&mut original_store);
+                RecordedTransaction::new(&transaction, receipt)
+            })
+            .collect::<Vec<_>>();
+
+        let mut replayed_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
+        let mut replayer = TransactionReplayer::new(
+            &mut replayed_store,
+            &scrypto_interpreter,
+            &NetworkDefinition::simulator(),
+        );
+
+        assert_eq!(replayer.replay_all(&recorded), Ok(3));
+        replayed_store.assert_eq(&original_store);
+    }
+
+    #[test]
+    fn replay_reports_first_divergence() {
+        let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+        let original_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
+        let receipt = execute_transaction(
+            &original_store,
+            &scrypto_interpreter,
+            &FeeReserveConfig::default(),
+            &ExecutionConfig::standard(),
+            &set_epoch_transaction(0).get_executable(vec![AuthAddresses::system_role()]),
+        );
+        // Record the receipt against a transaction which sets a different epoch
+        let recorded = vec![RecordedTransaction::new(
+            &LedgerTransaction::System(set_epoch_transaction(1)),
+            receipt,
+        )];
+
+        let mut replayed_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
+        let mut replayer = TransactionReplayer::new(
+            &mut replayed_store,
+            &scrypto_interpreter,
+            &NetworkDefinition::simulator(),
+        );
+
+        let divergence = replayer.replay_all(&recorded).unwrap_err();
+        assert_eq!(divergence.index, 0);
+        assert!(matches!(
+            divergence.reason,
+            DivergenceReason::StateUpdates { .. }
+        ));
+    }
+
+    fn set_epoch_transaction(nonce: u64) -> SystemTransaction {
+        SystemTransaction {
+            instructions: vec![Instruction::CallMethod {
+                component_address: EPOCH_MANAGER,
+                method_name: EPOCH_MANAGER_SET_EPOCH_IDENT.to_string(),
+                args: to_manifest_value(&EpochManagerSetEpochInput { epoch: nonce + 10 }),
+            }],
+            pre_allocated_ids: BTreeSet::new(),
+            blobs: vec![],
+            nonce,
+        }
+    }
+}
@@ -24,6 +24,7 @@ use transaction::model::*;
 pub struct FeeReserveConfig {
     pub cost_unit_price: u128,
     pub system_loan: u32,
+    pub fee_table: FeeTable,
 }
 
 impl Default for FeeReserveConfig {
@@ -37,8 +38,14 @@ impl FeeReserveConfig {
         Self {
             cost_unit_price: DEFAULT_COST_UNIT_PRICE,
             system_loan: DEFAULT_SYSTEM_LOAN,
+            fee_table: FeeTable::new(),
         }
     }
+
+    pub fn with_fee_table(mut self, fee_table: FeeTable) -> Self {
+        self.fee_table = fee_table;
+        self
+    }
 }
 
 pub struct ExecutionConfig {
@@ -150,7 +157,12 @@ where
             FeePayment::NoFee => SystemLoanFeeReserve::no_fee(),
         };
 
-        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, FeeTable::new())
+        self.execute_with_fee_reserve(
+            transaction,
+            execution_config,
+            fee_reserve,
+            fee_reserve_config.fee_table.clone(),
+        )
     }
 
     fn apply_pre_execution_costs(
@@ -286,7 +298,8 @@ where
 
             // Teardown
             let (modules, invoke_result) = kernel.teardown(invoke_result);
-            let fee_reserve = modules.costing.take_fee_reserve();
+            let (fee_reserve, execution_cost_tree) =
+                modules.costing.take_fee_reserve_and_cost_tree();
             let application_events = modules.events.events();
             let application_logs = modules.logger.logs();
             let (execution_traces, vault_ops) = modules.execution_trace.collect_traces();
@@ -295,6 +308,7 @@ where
             let transaction_result = track.finalize(
                 invoke_result,
                 fee_reserve,
+                execution_cost_tree,
                 application_events,
                 application_logs,
             );
@@ -30,6 +30,10 @@ pub const WRITE_SUBSTATE_FUNCTION_ID: usize = 0x42;
 pub const WRITE_SUBSTATE_FUNCTION_NAME: &str = "write_substate";
 pub const DROP_LOCK_FUNCTION_ID: usize = 0x43;
 pub const DROP_LOCK_FUNCTION_NAME: &str = "drop_lock";
+pub const REMOVE_KEY_VALUE_ENTRY_FUNCTION_ID: usize = 0x44;
+pub const REMOVE_KEY_VALUE_ENTRY_FUNCTION_NAME: &str = "remove_key_value_entry";
+pub const SCAN_KEY_VALUE_STORE_FUNCTION_ID: usize = 0x45;
+pub const SCAN_KEY_VALUE_STORE_FUNCTION_NAME: &str = "scan_key_value_store";
 
 pub const EMIT_EVENT_FUNCTION_ID: usize = 0x50;
 pub const EMIT_EVENT_FUNCTION_NAME: &str = "emit_event";
@@ -42,6 +46,21 @@ pub const GENERATE_UUID_FUNCTION_NAME: &str = "generate_uuid";
 pub const GET_ACTOR_FUNCTION_ID: usize = 0x54;
 pub const GET_ACTOR_FUNCTION_NAME: &str = "get_actor";
 
+pub const BLAKE2B_256_HASH_FUNCTION_ID: usize = 0x60;
+pub const BLAKE2B_256_HASH_FUNCTION_NAME: &str = "blake2b_256_hash";
+pub const SHA256_HASH_FUNCTION_ID: usize = 0x61;
+pub const SHA256_HASH_FUNCTION_NAME: &str = "sha256_hash";
+pub const KECCAK256_HASH_FUNCTION_ID: usize = 0x62;
+pub const KECCAK256_HASH_FUNCTION_NAME: &str = "keccak256_hash";
+pub const VERIFY_ECDSA_SECP256K1_FUNCTION_ID: usize = 0x63;
+pub const VERIFY_ECDSA_SECP256K1_FUNCTION_NAME: &str = "verify_ecdsa_secp256k1";
+pub const RECOVER_ECDSA_SECP256K1_FUNCTION_ID: usize = 0x64;
+pub const RECOVER_ECDSA_SECP256K1_FUNCTION_NAME: &str = "recover_ecdsa_secp256k1";
+pub const VERIFY_EDDSA_ED25519_FUNCTION_ID: usize = 0x65;
+pub const VERIFY_EDDSA_ED25519_FUNCTION_NAME: &str = "verify_eddsa_ed25519";
+pub const VERIFY_BLS12381_AGGREGATE_FUNCTION_ID: usize = 0x66;
+pub const VERIFY_BLS12381_AGGREGATE_FUNCTION_NAME: &str = "verify_bls12381_aggregate";
+
 pub const MODULE_ENV_NAME: &str = "env";
 pub const EXPORT_MEMORY: &str = "memory";
 
@@ -62,3 +81,6 @@ pub const DEFAULT_MAX_NUMBER_OF_GLOBALS: u32 = 512;
 
 /// The max number of functions
 pub const DEFAULT_MAX_NUMBER_OF_FUNCTIONS: u32 = 64 * 1024;
+
+/// The max number of imports
+pub const DEFAULT_MAX_NUMBER_OF_IMPORTS: u32 = 64;
@@ -53,6 +53,8 @@ pub enum InvalidImport {
     /// The import is not allowed
     ImportNotAllowed,
     InvalidFunctionType(String),
+    /// The module has too many imports
+    TooManyImports,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Sbor)]
@@ -136,6 +138,16 @@ pub enum WasmRuntimeError {
     InvalidLockFlags,
     /// Invalid log level
     InvalidLogLevel(DecodeError),
+    /// Invalid key value store scan start key
+    InvalidStartKey(DecodeError),
+    /// Invalid hash
+    InvalidHash(DecodeError),
+    /// Invalid public key
+    InvalidPublicKey(DecodeError),
+    /// Invalid signature
+    InvalidSignature(DecodeError),
+    /// Invalid signed messages
+    InvalidMessages(DecodeError),
 
     //=============
     // No-op Runtime
@@ -14,7 +14,7 @@ use crate::types::*;
 use crate::wasm::{constants::*, errors::*, PrepareError};
 
 use super::WasmiModule;
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WasmModule {
     module: Module,
 }
@@ -169,7 +169,11 @@ impl WasmModule {
         }
     }
 
-    pub fn enforce_import_limit(self) -> Result<Self, PrepareError> {
+    pub fn enforce_import_limit(self, max_number_of_imports: u32) -> Result<Self, PrepareError> {
+        if self.number_of_imports() > max_number_of_imports {
+            return Err(PrepareError::InvalidImport(InvalidImport::TooManyImports));
+        }
+
         // Only allow `env::radix_engine` import
         if let Some(sec) = self.module.import_section() {
             for entry in sec.entries() {
@@ -342,6 +346,53 @@ impl WasmModule {
                                 ));
                             }
                         }
+                        REMOVE_KEY_VALUE_ENTRY_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        REMOVE_KEY_VALUE_ENTRY_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        SCAN_KEY_VALUE_STORE_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        SCAN_KEY_VALUE_STORE_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
                         GET_ACTOR_FUNCTION_NAME => {
                             if let External::Function(type_index) = entry.external() {
                                 if Self::function_type_matches(
@@ -514,6 +565,156 @@ impl WasmModule {
                                 }
                             }
                         }
+                        BLAKE2B_256_HASH_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        BLAKE2B_256_HASH_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        SHA256_HASH_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        SHA256_HASH_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        KECCAK256_HASH_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        KECCAK256_HASH_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        VERIFY_ECDSA_SECP256K1_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I32],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        VERIFY_ECDSA_SECP256K1_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        RECOVER_ECDSA_SECP256K1_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        RECOVER_ECDSA_SECP256K1_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        VERIFY_EDDSA_ED25519_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I32],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        VERIFY_EDDSA_ED25519_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        VERIFY_BLS12381_AGGREGATE_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I32],
+                                ) {
+                                    continue;
+                                }
+
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        VERIFY_BLS12381_AGGREGATE_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
                         _ => {}
                     };
                 }
@@ -588,18 +789,8 @@ impl WasmModule {
         self,
         max_number_of_br_table_targets: u32,
     ) -> Result<Self, PrepareError> {
-        if let Some(section) = self.module.code_section() {
-            for inst in section
-                .bodies()
-                .iter()
-                .flat_map(|body| body.code().elements())
-            {
-                if let Instruction::BrTable(table_data) = inst {
-                    if table_data.table.len() > max_number_of_br_table_targets as usize {
-                        return Err(PrepareError::TooManyTargetsInBrTable);
-                    }
-                }
-            }
+        if self.max_number_of_br_table_targets() > max_number_of_br_table_targets {
+            return Err(PrepareError::TooManyTargetsInBrTable);
         }
         Ok(self)
     }
@@ -608,10 +799,8 @@ impl WasmModule {
         self,
         max_number_of_functions: u32,
     ) -> Result<Self, PrepareError> {
-        if let Some(section) = self.module.function_section() {
-            if section.entries().len() > max_number_of_functions as usize {
-                return Err(PrepareError::TooManyGlobals);
-            }
+        if self.number_of_functions() > max_number_of_functions {
+            return Err(PrepareError::TooManyFunctions);
         }
 
         // TODO: do we need to enforce limit on the number of locals and parameters?
@@ -620,14 +809,95 @@ impl WasmModule {
     }
 
     pub fn enforce_global_limit(self, max_number_of_globals: u32) -> Result<Self, PrepareError> {
-        if let Some(section) = self.module.global_section() {
-            if section.entries().len() > max_number_of_globals as usize {
-                return Err(PrepareError::TooManyGlobals);
-            }
+        if self.number_of_globals() > max_number_of_globals {
+            return Err(PrepareError::TooManyGlobals);
         }
         Ok(self)
     }
 
+    /// The initial size of the first memory, in pages, or zero if there's none.
+    pub fn initial_memory_size_pages(&self) -> u32 {
+        self.module
+            .memory_section()
+            .and_then(|section| section.entries().first())
+            .map(|memory| memory.limits().initial())
+            .unwrap_or(0)
+    }
+
+    /// The initial size of the first table, or zero if there's none.
+    pub fn initial_table_size(&self) -> u32 {
+        self.module
+            .table_section()
+            .and_then(|section| section.entries().first())
+            .map(|table| table.limits().initial())
+            .unwrap_or(0)
+    }
+
+    /// The number of targets of the largest `br_table` instruction, excluding the default.
+    pub fn max_number_of_br_table_targets(&self) -> u32 {
+        self.module
+            .code_section()
+            .map(|section| {
+                section
+                    .bodies()
+                    .iter()
+                    .flat_map(|body| body.code().elements())
+                    .filter_map(|inst| match inst {
+                        Instruction::BrTable(table_data) => Some(table_data.table.len()),
+                        _ => None,
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .unwrap_or(0)
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// The number of imports of the module.
+    pub fn number_of_imports(&self) -> u32 {
+        self.module
+            .import_section()
+            .map(|section| section.entries().len())
+            .unwrap_or(0)
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// The number of functions defined by the module, excluding imports.
+    pub fn number_of_functions(&self) -> u32 {
+        self.module
+            .function_section()
+            .map(|section| section.entries().len())
+            .unwrap_or(0)
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// The number of globals defined by the module, excluding imports.
+    pub fn number_of_globals(&self) -> u32 {
+        self.module
+            .global_section()
+            .map(|section| section.entries().len())
+            .unwrap_or(0)
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Whether the module imports a function with the given name from the `env` module.
+    pub fn imports_env_function(&self, name: &str) -> bool {
+        self.module
+            .import_section()
+            .map(|section| {
+                section.entries().iter().any(|entry| {
+                    entry.module() == MODULE_ENV_NAME
+                        && entry.field() == name
+                        && matches!(entry.external(), External::Function(_))
+                })
+            })
+            .unwrap_or(false)
+    }
+
     pub fn enforce_export_constraints(self, schema: &PackageSchema) -> Result<Self, PrepareError> {
         let exports = self
             .module
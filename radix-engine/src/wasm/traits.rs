@@ -75,6 +75,19 @@ pub trait WasmRuntime {
 
     fn drop_lock(&mut self, handle: LockHandle) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn remove_key_value_entry(
+        &mut self,
+        node_id: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn scan_key_value_store(
+        &mut self,
+        node_id: Vec<u8>,
+        start_key: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn get_actor(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn consume_cost_units(&mut self, n: u32) -> Result<(), InvokeError<WasmRuntimeError>>;
@@ -99,6 +112,38 @@ pub trait WasmRuntime {
     fn get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn generate_uuid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>>;
+
+    fn recover_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>>;
+
+    fn verify_bls12381_aggregate(
+        &mut self,
+        messages: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, InvokeError<WasmRuntimeError>>;
 }
 
 /// Represents an instantiated, invokable Scrypto module.
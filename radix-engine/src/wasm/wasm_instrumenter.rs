@@ -1,25 +1,57 @@
 use super::{CodeKey, MeteredCodeKey, WasmMeteringParams};
 use crate::types::*;
+#[cfg(not(feature = "alloc"))]
+use crate::wasm::CONSUME_COST_UNITS_FUNCTION_NAME;
 use crate::wasm::{WasmMeteringConfig, WasmModule};
 use sbor::rust::sync::Arc;
+#[cfg(not(feature = "alloc"))]
+use std::path::PathBuf;
+#[cfg(not(feature = "alloc"))]
+use std::sync::atomic::{AtomicU64, Ordering};
 
 pub struct WasmInstrumenter {
     #[cfg(not(feature = "moka"))]
     cache: RefCell<lru::LruCache<MeteredCodeKey, Arc<Vec<u8>>>>,
     #[cfg(feature = "moka")]
     cache: moka::sync::Cache<MeteredCodeKey, Arc<Vec<u8>>>,
+    #[cfg(not(feature = "alloc"))]
+    disk_cache_dir: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
 pub struct InstrumenterOptions {
     max_cache_size_bytes: usize,
+    #[cfg(not(feature = "alloc"))]
+    disk_cache_dir: Option<PathBuf>,
 }
 
-impl Default for WasmInstrumenter {
+impl Default for InstrumenterOptions {
     fn default() -> Self {
-        Self::new(InstrumenterOptions {
+        Self {
             max_cache_size_bytes: 200 * 1024 * 1024,
-        })
+            #[cfg(not(feature = "alloc"))]
+            disk_cache_dir: None,
+        }
+    }
+}
+
+impl InstrumenterOptions {
+    /// Persists instrumented code in the given directory, so that it's reused across processes.
+    ///
+    /// Entries are keyed by the hash of the original code and the metering parameters, and are
+    /// checked against the hash of the instrumented code stored with them. The cache is
+    /// best-effort: if an entry can't be read, fails the checks or can't be written, the code is
+    /// simply instrumented.
+    #[cfg(not(feature = "alloc"))]
+    pub fn with_disk_cache_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.disk_cache_dir = Some(dir.into());
+        self
+    }
+}
+
+impl Default for WasmInstrumenter {
+    fn default() -> Self {
+        Self::new(InstrumenterOptions::default())
     }
 }
 
@@ -42,7 +74,11 @@ impl WasmInstrumenter {
             .max_capacity(options.max_cache_size_bytes as u64)
             .build();
 
-        Self { cache }
+        Self {
+            cache,
+            #[cfg(not(feature = "alloc"))]
+            disk_cache_dir: options.disk_cache_dir,
+        }
     }
 
     pub fn instrument(
@@ -71,7 +107,7 @@ impl WasmInstrumenter {
         }
 
         let instrumented_ref =
-            Arc::new(self.instrument_no_cache(code, wasm_metering_config.parameters()));
+            Arc::new(self.instrument_with_disk_cache(code, wasm_metering_config.parameters()));
 
         #[cfg(not(feature = "moka"))]
         self.cache
@@ -87,6 +123,95 @@ impl WasmInstrumenter {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    fn instrument_with_disk_cache(
+        &self,
+        code: &[u8],
+        metering_params: WasmMeteringParams,
+    ) -> Vec<u8> {
+        self.instrument_no_cache(code, metering_params)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn instrument_with_disk_cache(
+        &self,
+        code: &[u8],
+        metering_params: WasmMeteringParams,
+    ) -> Vec<u8> {
+        static NEXT_TEMP_FILE_ID: AtomicU64 = AtomicU64::new(0);
+
+        let dir = match &self.disk_cache_dir {
+            Some(dir) => dir,
+            None => return self.instrument_no_cache(code, metering_params),
+        };
+        let path = dir.join(format!(
+            "{}.wasm",
+            Self::disk_cache_key(code, &metering_params)
+        ));
+        if let Some(cached) = std::fs::read(&path)
+            .ok()
+            .and_then(Self::decode_disk_cache_entry)
+        {
+            return cached;
+        }
+
+        let instrumented = self.instrument_no_cache(code, metering_params);
+
+        // Write to a unique temporary file first, so that readers never observe a partial entry
+        let temp_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            NEXT_TEMP_FILE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = std::fs::create_dir_all(dir)
+            .and_then(|_| std::fs::write(&temp_path, Self::encode_disk_cache_entry(&instrumented)))
+            .and_then(|_| std::fs::rename(&temp_path, &path));
+        if written.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+
+        instrumented
+    }
+
+    /// The key also covers the engine version, as instrumentation may change between versions.
+    #[cfg(not(feature = "alloc"))]
+    fn disk_cache_key(code: &[u8], metering_params: &WasmMeteringParams) -> Hash {
+        let metering_params =
+            scrypto_encode(metering_params).expect("Failed to encode WASM metering params");
+
+        hash(
+            [
+                env!("CARGO_PKG_VERSION").as_bytes(),
+                &hash(code).0,
+                &metering_params,
+            ]
+            .concat(),
+        )
+    }
+
+    /// An entry is the hash of the instrumented code, followed by the code itself.
+    #[cfg(not(feature = "alloc"))]
+    fn encode_disk_cache_entry(instrumented: &[u8]) -> Vec<u8> {
+        [hash(instrumented).0.as_slice(), instrumented].concat()
+    }
+
+    /// Returns the instrumented code of an entry, unless it's corrupted or isn't metered.
+    #[cfg(not(feature = "alloc"))]
+    fn decode_disk_cache_entry(entry: Vec<u8>) -> Option<Vec<u8>> {
+        if entry.len() < Hash::LENGTH {
+            return None;
+        }
+        let (checksum, instrumented) = entry.split_at(Hash::LENGTH);
+        if checksum != hash(instrumented).0 {
+            return None;
+        }
+        WasmModule::init(instrumented)
+            .ok()
+            .filter(|module| module.imports_env_function(CONSUME_COST_UNITS_FUNCTION_NAME))?;
+
+        Some(instrumented.to_vec())
+    }
+
     pub fn instrument_no_cache(&self, code: &[u8], metering_params: WasmMeteringParams) -> Vec<u8> {
         WasmModule::init(code)
             .and_then(|m| m.inject_instruction_metering(metering_params.instruction_cost_rules()))
@@ -96,3 +221,81 @@ impl WasmInstrumenter {
             .0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wabt::wat2wasm;
+
+    #[cfg(not(feature = "alloc"))]
+    fn module_returning(value: i64) -> Vec<u8> {
+        wat2wasm(format!(
+            r#"
+            (module
+                (func $f (param $0 i64) (result i64)
+                    (i64.const {})
+                )
+                (memory $0 1)
+                (export "memory" (memory $0))
+                (export "f" (func $f))
+            )
+            "#,
+            value
+        ))
+        .unwrap()
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_instrumented_code_is_reused_from_disk_cache() {
+        let dir =
+            std::env::temp_dir().join(format!("wasm_instrumenter_test_{}", std::process::id()));
+        let code = module_returning(0);
+        let options = InstrumenterOptions::default().with_disk_cache_dir(&dir);
+        let instrument = |code: &[u8], key: u8| {
+            WasmInstrumenter::new(options.clone())
+                .instrument(
+                    PackageAddress::Normal([key; 26]),
+                    code,
+                    WasmMeteringConfig::V0,
+                )
+                .code
+        };
+
+        // Instrumenting populates the disk cache
+        let instrumented = instrument(&code, 0);
+        let entries: Vec<PathBuf> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(entries.len(), 1);
+        let entry = std::fs::read(&entries[0]).unwrap();
+        assert_eq!(
+            entry,
+            WasmInstrumenter::encode_disk_cache_entry(&instrumented)
+        );
+
+        // A fresh instrumenter loads a valid entry from disk, rather than instrumenting again
+        let other_instrumented = instrument(&module_returning(1), 1);
+        assert_ne!(other_instrumented, instrumented);
+        std::fs::write(
+            &entries[0],
+            WasmInstrumenter::encode_disk_cache_entry(&other_instrumented),
+        )
+        .unwrap();
+        assert_eq!(instrument(&code, 2), other_instrumented);
+
+        // Corrupted entries, and entries of code which isn't metered, are instrumented again
+        for tampered in [
+            vec![1, 2, 3],
+            [&entry[..entry.len() - 1], &[0u8]].concat(),
+            WasmInstrumenter::encode_disk_cache_entry(&code),
+        ] {
+            std::fs::write(&entries[0], tampered).unwrap();
+            assert_eq!(instrument(&code, 3), instrumented);
+            assert_eq!(std::fs::read(&entries[0]).unwrap(), entry);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,16 +2,25 @@ use radix_engine_interface::schema::PackageSchema;
 
 use crate::types::*;
 use crate::wasm::*;
+#[cfg(feature = "serde")]
+use sbor::serde_serialization::{
+    json_to_payload, JsonValue, SborPayloadWithSchema, SerializationContext, SerializationMode,
+};
+#[cfg(feature = "serde")]
+use utils::ContextualSerialize;
 
-pub struct WasmValidator {
+/// The limits that the WASM code of a package must stay within.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct WasmValidatorConfig {
     pub max_initial_memory_size_pages: u32,
     pub max_initial_table_size: u32,
     pub max_number_of_br_table_targets: u32,
     pub max_number_of_functions: u32,
     pub max_number_of_globals: u32,
+    pub max_number_of_imports: u32,
 }
 
-impl Default for WasmValidator {
+impl Default for WasmValidatorConfig {
     fn default() -> Self {
         Self {
             max_initial_memory_size_pages: DEFAULT_MAX_INITIAL_MEMORY_SIZE_PAGES,
@@ -19,11 +28,96 @@ impl Default for WasmValidator {
             max_number_of_br_table_targets: DEFAULT_MAX_NUMBER_OF_BR_TABLE_TARGETS,
             max_number_of_functions: DEFAULT_MAX_NUMBER_OF_FUNCTIONS,
             max_number_of_globals: DEFAULT_MAX_NUMBER_OF_GLOBALS,
+            max_number_of_imports: DEFAULT_MAX_NUMBER_OF_IMPORTS,
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WasmValidatorConfigLoadError {
+    DecodeError(DecodeError),
+    InvalidJson(String),
+}
+
+impl WasmValidatorConfig {
+    /// Parses a config from its (simple) JSON representation, as produced by [`Self::to_json`].
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, WasmValidatorConfigLoadError> {
+        let json: JsonValue = serde_json::from_str(json)
+            .map_err(|error| WasmValidatorConfigLoadError::InvalidJson(error.to_string()))?;
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<Self, ScryptoCustomTypeExtension>();
+        let context = SerializationContext {
+            schema: &schema,
+            mode: SerializationMode::Simple,
+            custom_context: ScryptoValueDisplayContext::no_context(),
+        };
+        let payload = json_to_payload(&json, &context, type_index)
+            .map_err(|error| WasmValidatorConfigLoadError::InvalidJson(error.error_message()))?;
+        scrypto_decode(&payload).map_err(WasmValidatorConfigLoadError::DecodeError)
+    }
+
+    /// Outputs this config in its (simple) JSON representation.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<Self, ScryptoCustomTypeExtension>();
+        let payload = scrypto_encode(self).expect("Failed to encode WASM validator config");
+        let payload =
+            SborPayloadWithSchema::<ScryptoCustomTypeExtension>::new(&payload, type_index);
+        let serializable = payload.serializable(SerializationContext {
+            schema: &schema,
+            mode: SerializationMode::Simple,
+            custom_context: ScryptoValueDisplayContext::no_context(),
+        });
+        serde_json::to_string_pretty(&serializable)
+            .expect("Failed to serialize WASM validator config")
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WasmValidator {
+    pub config: WasmValidatorConfig,
+}
+
+/// How much of a limit is used by the WASM code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct WasmLimitUsage {
+    pub used: u32,
+    pub limit: u32,
+}
+
+impl WasmLimitUsage {
+    pub fn is_exceeded(&self) -> bool {
+        self.used > self.limit
+    }
+}
+
+/// The outcome of validating WASM code against every rule, rather than just up to the first
+/// violated one.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct WasmValidationReport {
+    pub initial_memory_size_pages: WasmLimitUsage,
+    pub initial_table_size: WasmLimitUsage,
+    pub number_of_br_table_targets: WasmLimitUsage,
+    pub number_of_functions: WasmLimitUsage,
+    pub number_of_globals: WasmLimitUsage,
+    pub number_of_imports: WasmLimitUsage,
+    /// The violated rules, in the order in which `validate` checks them.
+    pub violations: Vec<PrepareError>,
+}
+
+impl WasmValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
 impl WasmValidator {
+    pub fn new(config: WasmValidatorConfig) -> Self {
+        Self { config }
+    }
+
     pub fn validate(&self, code: &[u8], schema: &PackageSchema) -> Result<(), PrepareError> {
         // Not all "valid" wasm modules are instrumentable, with the instrumentation library
         // we are using. To deal with this, we attempt to instrument the input module with
@@ -33,12 +127,12 @@ impl WasmValidator {
         WasmModule::init(code)?
             .enforce_no_floating_point()?
             .enforce_no_start_function()?
-            .enforce_import_limit()?
-            .enforce_memory_limit(self.max_initial_memory_size_pages)?
-            .enforce_table_limit(self.max_initial_table_size)?
-            .enforce_br_table_limit(self.max_number_of_br_table_targets)?
-            .enforce_function_limit(self.max_number_of_functions)?
-            .enforce_global_limit(self.max_number_of_globals)?
+            .enforce_import_limit(self.config.max_number_of_imports)?
+            .enforce_memory_limit(self.config.max_initial_memory_size_pages)?
+            .enforce_table_limit(self.config.max_initial_table_size)?
+            .enforce_br_table_limit(self.config.max_number_of_br_table_targets)?
+            .enforce_function_limit(self.config.max_number_of_functions)?
+            .enforce_global_limit(self.config.max_number_of_globals)?
             .enforce_export_constraints(schema)?
             .inject_instruction_metering(parameters.instruction_cost_rules())?
             .inject_stack_metering(parameters.max_stack_size())?
@@ -48,4 +142,113 @@ impl WasmValidator {
 
         Ok(())
     }
+
+    /// Applies each of the `validate` rules independently, reporting all the violated ones.
+    ///
+    /// Fails only if the code can't be decoded into a valid WASM module, in which case no other
+    /// rule can be checked. Instrumentation and instantiation are only attempted once all the
+    /// other rules are satisfied.
+    pub fn validate_with_report(
+        &self,
+        code: &[u8],
+        schema: &PackageSchema,
+    ) -> Result<WasmValidationReport, PrepareError> {
+        let parameters = WasmMeteringConfig::V0.parameters();
+        let module = WasmModule::init(code)?;
+
+        let mut violations: Vec<PrepareError> = vec![
+            module.clone().enforce_no_floating_point().err(),
+            module.clone().enforce_no_start_function().err(),
+            module
+                .clone()
+                .enforce_import_limit(self.config.max_number_of_imports)
+                .err(),
+            module
+                .clone()
+                .enforce_memory_limit(self.config.max_initial_memory_size_pages)
+                .err(),
+            module
+                .clone()
+                .enforce_table_limit(self.config.max_initial_table_size)
+                .err(),
+            module
+                .clone()
+                .enforce_br_table_limit(self.config.max_number_of_br_table_targets)
+                .err(),
+            module
+                .clone()
+                .enforce_function_limit(self.config.max_number_of_functions)
+                .err(),
+            module
+                .clone()
+                .enforce_global_limit(self.config.max_number_of_globals)
+                .err(),
+            module.clone().enforce_export_constraints(schema).err(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // Instrumentation and instantiation assume that the module follows the rules above
+        if violations.is_empty() {
+            violations.extend(
+                module
+                    .clone()
+                    .inject_instruction_metering(parameters.instruction_cost_rules())
+                    .and_then(|m| m.inject_stack_metering(parameters.max_stack_size()))
+                    .and_then(|m| m.ensure_instantiatable())
+                    .and_then(|m| m.ensure_compilable())
+                    .and_then(|m| m.to_bytes())
+                    .err(),
+            );
+        }
+
+        Ok(WasmValidationReport {
+            initial_memory_size_pages: WasmLimitUsage {
+                used: module.initial_memory_size_pages(),
+                limit: self.config.max_initial_memory_size_pages,
+            },
+            initial_table_size: WasmLimitUsage {
+                used: module.initial_table_size(),
+                limit: self.config.max_initial_table_size,
+            },
+            number_of_br_table_targets: WasmLimitUsage {
+                used: module.max_number_of_br_table_targets(),
+                limit: self.config.max_number_of_br_table_targets,
+            },
+            number_of_functions: WasmLimitUsage {
+                used: module.number_of_functions(),
+                limit: self.config.max_number_of_functions,
+            },
+            number_of_globals: WasmLimitUsage {
+                used: module.number_of_globals(),
+                limit: self.config.max_number_of_globals,
+            },
+            number_of_imports: WasmLimitUsage {
+                used: module.number_of_imports(),
+                limit: self.config.max_number_of_imports,
+            },
+            violations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_json_round_trip() {
+        let config = WasmValidatorConfig {
+            max_number_of_imports: 1,
+            ..Default::default()
+        };
+        let json = config.to_json();
+        assert_eq!(WasmValidatorConfig::from_json(&json), Ok(config));
+        assert!(matches!(
+            WasmValidatorConfig::from_json("{\"max_number_of_imports\": 1}"),
+            Err(WasmValidatorConfigLoadError::InvalidJson(_))
+        ));
+    }
 }
@@ -129,9 +129,9 @@ pub fn get_memory_size(instance: &Instance) -> Result<usize, WasmRuntimeError> {
         .exports
         .get_memory(EXPORT_MEMORY)
         .map_err(|_| WasmRuntimeError::MemoryAccessError)?;
-    let memory_slice = unsafe { memory.data_unchecked() };
 
-    Ok(memory_slice.len())
+    // Same as wasmi, the size of the linear memory in bytes (pages * 64KiB).
+    Ok(memory.size().bytes().0)
 }
 
 impl WasmerEnv for WasmerInstanceEnv {
@@ -374,6 +374,45 @@ impl WasmerModule {
             Ok(())
         }
 
+        pub fn remove_key_value_entry(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            key_ptr: u32,
+            key_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let node_id = read_memory(&instance, node_id_ptr, node_id_len)?;
+            let key = read_memory(&instance, key_ptr, key_len)?;
+
+            let buffer = runtime
+                .remove_key_value_entry(node_id, key)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn scan_key_value_store(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            start_key_ptr: u32,
+            start_key_len: u32,
+            limit: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let node_id = read_memory(&instance, node_id_ptr, node_id_len)?;
+            let start_key = read_memory(&instance, start_key_ptr, start_key_len)?;
+
+            let buffer = runtime
+                .scan_key_value_store(node_id, start_key, limit)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
         pub fn get_actor(env: &WasmerInstanceEnv) -> Result<u64, RuntimeError> {
             let (_instance, runtime) = grab_runtime!(env);
 
@@ -441,6 +480,136 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        pub fn blake2b_256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            let buffer = runtime
+                .blake2b_256_hash(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn sha256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            let buffer = runtime
+                .sha256_hash(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn keccak256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            let buffer = runtime
+                .keccak256_hash(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn verify_ecdsa_secp256k1(
+            env: &WasmerInstanceEnv,
+            message_hash_ptr: u32,
+            message_hash_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message_hash = read_memory(&instance, message_hash_ptr, message_hash_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let valid = runtime
+                .verify_ecdsa_secp256k1(message_hash, public_key, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(valid.into())
+        }
+
+        pub fn recover_ecdsa_secp256k1(
+            env: &WasmerInstanceEnv,
+            message_hash_ptr: u32,
+            message_hash_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message_hash = read_memory(&instance, message_hash_ptr, message_hash_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let buffer = runtime
+                .recover_ecdsa_secp256k1(message_hash, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn verify_eddsa_ed25519(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let valid = runtime
+                .verify_eddsa_ed25519(message, public_key, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(valid.into())
+        }
+
+        pub fn verify_bls12381_aggregate(
+            env: &WasmerInstanceEnv,
+            messages_ptr: u32,
+            messages_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let messages = read_memory(&instance, messages_ptr, messages_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let valid = runtime
+                .verify_bls12381_aggregate(messages, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(valid.into())
+        }
+
         // native functions ends
 
         // env
@@ -464,12 +633,21 @@ impl WasmerModule {
                 READ_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), read_substate),
                 WRITE_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), write_substate),
                 DROP_LOCK_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), drop_lock),
+                REMOVE_KEY_VALUE_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), remove_key_value_entry),
+                SCAN_KEY_VALUE_STORE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), scan_key_value_store),
                 GET_ACTOR_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_actor),
                 CONSUME_COST_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_cost_units),
                 EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_event),
                 LOG_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), log_message),
                 GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_transaction_hash),
                 GENERATE_UUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), generate_uuid),
+                BLAKE2B_256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), blake2b_256_hash),
+                SHA256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sha256_hash),
+                KECCAK256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), keccak256_hash),
+                VERIFY_ECDSA_SECP256K1_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), verify_ecdsa_secp256k1),
+                RECOVER_ECDSA_SECP256K1_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), recover_ecdsa_secp256k1),
+                VERIFY_EDDSA_ED25519_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), verify_eddsa_ed25519),
+                VERIFY_BLS12381_AGGREGATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), verify_bls12381_aggregate),
             }
         };
 
@@ -486,10 +664,21 @@ impl WasmerModule {
 
 impl From<RuntimeError> for InvokeError<WasmRuntimeError> {
     fn from(error: RuntimeError) -> Self {
+        // Errors raised by host functions are passed through, matching the wasmi integration
+        let error = match error.downcast::<InvokeError<WasmRuntimeError>>() {
+            Ok(e) => return e,
+            Err(error) => error,
+        };
+        let error = match error.downcast::<WasmRuntimeError>() {
+            Ok(e) => return InvokeError::SelfError(e),
+            Err(error) => error,
+        };
+
         let e_str = format!("{:?}", error);
-        match error.downcast::<InvokeError<WasmRuntimeError>>() {
-            Ok(e) => e,
-            _ => InvokeError::SelfError(WasmRuntimeError::InterpreterError(e_str)),
+        if error.to_trap().is_some() {
+            InvokeError::SelfError(WasmRuntimeError::Trap(e_str))
+        } else {
+            InvokeError::SelfError(WasmRuntimeError::InterpreterError(e_str))
         }
     }
 }
@@ -532,13 +721,7 @@ impl WasmInstance for WasmerInstance {
     }
 
     fn consumed_memory(&self) -> Result<usize, InvokeError<WasmRuntimeError>> {
-        let memory = self
-            .instance
-            .exports
-            .get_memory(EXPORT_MEMORY)
-            .map_err(|_| WasmRuntimeError::MemoryAccessError)?;
-        let memory_slice = unsafe { memory.data_unchecked_mut() };
-        Ok(memory_slice.len())
+        get_memory_size(&self.instance).map_err(InvokeError::SelfError)
     }
 }
 
@@ -586,7 +769,7 @@ impl WasmEngine for WasmerEngine {
         let metered_code_key = &instrumented_code.metered_code_key;
         #[cfg(not(feature = "moka"))]
         {
-            if let Some(cached_module) = self.modules_cache.borrow_mut().get(key) {
+            if let Some(cached_module) = self.modules_cache.borrow_mut().get(metered_code_key) {
                 return cached_module.instantiate();
             }
         }
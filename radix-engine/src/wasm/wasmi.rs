@@ -324,6 +324,46 @@ fn drop_lock(
     runtime.drop_lock(handle)
 }
 
+fn remove_key_value_entry(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+
+    runtime
+        .remove_key_value_entry(node_id, key)
+        .map(|buffer| buffer.0)
+}
+
+fn scan_key_value_store(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    start_key_ptr: u32,
+    start_key_len: u32,
+    limit: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let start_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        start_key_ptr,
+        start_key_len,
+    )?;
+
+    runtime
+        .scan_key_value_store(node_id, start_key, limit)
+        .map(|buffer| buffer.0)
+}
+
 fn get_actor(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
     let (_memory, runtime) = grab_runtime!(caller);
 
@@ -377,6 +417,156 @@ fn generate_uuid(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmR
     runtime.generate_uuid().map(|buffer| buffer.0)
 }
 
+fn blake2b_256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime.blake2b_256_hash(data).map(|buffer| buffer.0)
+}
+
+fn sha256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime.sha256_hash(data).map(|buffer| buffer.0)
+}
+
+fn keccak256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime.keccak256_hash(data).map(|buffer| buffer.0)
+}
+
+fn verify_ecdsa_secp256k1(
+    mut caller: Caller<'_, HostState>,
+    message_hash_ptr: u32,
+    message_hash_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message_hash = read_memory(
+        caller.as_context_mut(),
+        memory,
+        message_hash_ptr,
+        message_hash_len,
+    )?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime
+        .verify_ecdsa_secp256k1(message_hash, public_key, signature)
+        .map(|valid| valid.into())
+}
+
+fn recover_ecdsa_secp256k1(
+    mut caller: Caller<'_, HostState>,
+    message_hash_ptr: u32,
+    message_hash_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message_hash = read_memory(
+        caller.as_context_mut(),
+        memory,
+        message_hash_ptr,
+        message_hash_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime
+        .recover_ecdsa_secp256k1(message_hash, signature)
+        .map(|buffer| buffer.0)
+}
+
+fn verify_eddsa_ed25519(
+    mut caller: Caller<'_, HostState>,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime
+        .verify_eddsa_ed25519(message, public_key, signature)
+        .map(|valid| valid.into())
+}
+
+fn verify_bls12381_aggregate(
+    mut caller: Caller<'_, HostState>,
+    messages_ptr: u32,
+    messages_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let messages = read_memory(caller.as_context_mut(), memory, messages_ptr, messages_len)?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime
+        .verify_bls12381_aggregate(messages, signature)
+        .map(|valid| valid.into())
+}
+
 fn log_message(
     mut caller: Caller<'_, HostState>,
     level_ptr: u32,
@@ -599,6 +789,40 @@ impl WasmiModule {
             },
         );
 
+        let host_remove_key_value_entry = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Trap> {
+                remove_key_value_entry(caller, node_id_ptr, node_id_len, key_ptr, key_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
+        let host_scan_key_value_store = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             start_key_ptr: u32,
+             start_key_len: u32,
+             limit: u32|
+             -> Result<u64, Trap> {
+                scan_key_value_store(
+                    caller,
+                    node_id_ptr,
+                    node_id_len,
+                    start_key_ptr,
+                    start_key_len,
+                    limit,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_get_actor = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
@@ -659,6 +883,111 @@ impl WasmiModule {
             },
         );
 
+        let host_blake2b_256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                blake2b_256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_sha256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                sha256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_keccak256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                keccak256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_verify_ecdsa_secp256k1 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_hash_ptr: u32,
+             message_hash_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                verify_ecdsa_secp256k1(
+                    caller,
+                    message_hash_ptr,
+                    message_hash_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_recover_ecdsa_secp256k1 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_hash_ptr: u32,
+             message_hash_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u64, Trap> {
+                recover_ecdsa_secp256k1(
+                    caller,
+                    message_hash_ptr,
+                    message_hash_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_verify_eddsa_ed25519 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_ptr: u32,
+             message_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                verify_eddsa_ed25519(
+                    caller,
+                    message_ptr,
+                    message_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_verify_bls12381_aggregate = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             messages_ptr: u32,
+             messages_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                verify_bls12381_aggregate(
+                    caller,
+                    messages_ptr,
+                    messages_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let mut linker = <Linker<HostState>>::new();
         linker_define!(linker, CONSUME_BUFFER_FUNCTION_NAME, host_consume_buffer);
         linker_define!(linker, CALL_METHOD_FUNCTION_NAME, host_call_method);
@@ -684,6 +1013,16 @@ impl WasmiModule {
         linker_define!(linker, READ_SUBSTATE_FUNCTION_NAME, host_read_substate);
         linker_define!(linker, WRITE_SUBSTATE_FUNCTION_NAME, host_write_substate);
         linker_define!(linker, DROP_LOCK_FUNCTION_NAME, host_drop_lock);
+        linker_define!(
+            linker,
+            REMOVE_KEY_VALUE_ENTRY_FUNCTION_NAME,
+            host_remove_key_value_entry
+        );
+        linker_define!(
+            linker,
+            SCAN_KEY_VALUE_STORE_FUNCTION_NAME,
+            host_scan_key_value_store
+        );
         linker_define!(linker, GET_ACTOR_FUNCTION_NAME, host_get_actor);
         linker_define!(
             linker,
@@ -698,6 +1037,33 @@ impl WasmiModule {
             host_get_transaction_hash
         );
         linker_define!(linker, GENERATE_UUID_FUNCTION_NAME, host_generate_uuid);
+        linker_define!(
+            linker,
+            BLAKE2B_256_HASH_FUNCTION_NAME,
+            host_blake2b_256_hash
+        );
+        linker_define!(linker, SHA256_HASH_FUNCTION_NAME, host_sha256_hash);
+        linker_define!(linker, KECCAK256_HASH_FUNCTION_NAME, host_keccak256_hash);
+        linker_define!(
+            linker,
+            VERIFY_ECDSA_SECP256K1_FUNCTION_NAME,
+            host_verify_ecdsa_secp256k1
+        );
+        linker_define!(
+            linker,
+            RECOVER_ECDSA_SECP256K1_FUNCTION_NAME,
+            host_recover_ecdsa_secp256k1
+        );
+        linker_define!(
+            linker,
+            VERIFY_EDDSA_ED25519_FUNCTION_NAME,
+            host_verify_eddsa_ed25519
+        );
+        linker_define!(
+            linker,
+            VERIFY_BLS12381_AGGREGATE_FUNCTION_NAME,
+            host_verify_bls12381_aggregate
+        );
 
         linker.instantiate(store.as_context_mut(), &module)
     }
@@ -239,4 +239,26 @@ mod serde_serialization {
             unreachable!("No custom values exist")
         }
     }
+
+    impl DeserializableCustomTypeExtension for NoCustomTypeExtension {
+        fn custom_value_kind_from_name(_: &str) -> Option<Self::CustomValueKind> {
+            None
+        }
+
+        fn custom_value_kind_for_type_kind(
+            _: &SchemaCustomTypeKind<Self>,
+        ) -> Self::CustomValueKind {
+            unreachable!("No custom type kinds exist")
+        }
+
+        fn deserialize_value_body(
+            _: &SerializationContext<'_, '_, Self>,
+            _: LocalTypeIndex,
+            _: Self::CustomValueKind,
+            _: &JsonValue,
+            _: &mut VecEncoder<Self::CustomValueKind>,
+        ) -> Result<(), DeserializationError<Self>> {
+            unreachable!("No custom values exist")
+        }
+    }
 }
@@ -0,0 +1,823 @@
+use super::*;
+use crate::rust::prelude::*;
+use crate::*;
+use serde::de::{self, DeserializeSeed, Deserializer};
+use serde::Deserialize;
+use serde_json::Map as JsonMap;
+
+pub use serde_json::Value as JsonValue;
+
+/// Parses the JSON output of [`SborPayloadWithSchema`] back into an SBOR payload.
+///
+/// The JSON is interpreted against the type at `type_index` in the schema of the context, in the
+/// context's [`SerializationMode`]. The schema fixes the value kinds and the order of fields, and
+/// the resulting payload is validated against the schema before it is returned.
+///
+/// Some things to be aware of:
+/// * A value of type `Any` can only be read if its value kind is given by the JSON - either by
+///   its own `kind` field, or by the `element_kind`, `key_kind` or `value_kind` of its parent.
+///   In practice, this means that `Any` values are only supported in `Invertible` mode.
+/// * `Simple` mode outputs maps with string keys as JSON objects. Parsed JSON objects keep their
+///   keys sorted (`serde_json` is used without `preserve_order`), so the entries of such maps are
+///   written in sorted key order, which may differ from the order in the JSON text or in the
+///   original payload.
+pub struct SborPayloadDeserializer<'s, 'a, E: DeserializableCustomTypeExtension> {
+    context: SerializationContext<'s, 'a, E>,
+    type_index: LocalTypeIndex,
+}
+
+impl<'s, 'a, E: DeserializableCustomTypeExtension> SborPayloadDeserializer<'s, 'a, E> {
+    pub fn new(context: SerializationContext<'s, 'a, E>, type_index: LocalTypeIndex) -> Self {
+        Self {
+            context,
+            type_index,
+        }
+    }
+
+    pub fn from_json(&self, json: &JsonValue) -> Result<Vec<u8>, LocatedDeserializationError<E>> {
+        json_to_payload(json, &self.context, self.type_index)
+    }
+}
+
+impl<'de, 's, 'a, E: DeserializableCustomTypeExtension> DeserializeSeed<'de>
+    for SborPayloadDeserializer<'s, 'a, E>
+{
+    type Value = Vec<u8>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let json = JsonValue::deserialize(deserializer)?;
+        self.from_json(&json)
+            .map_err(|error| <D::Error as de::Error>::custom(error.error_message()))
+    }
+}
+
+pub fn json_to_payload<E: DeserializableCustomTypeExtension>(
+    json: &JsonValue,
+    context: &SerializationContext<'_, '_, E>,
+    type_index: LocalTypeIndex,
+) -> Result<Vec<u8>, LocatedDeserializationError<E>> {
+    let mut payload = Vec::with_capacity(512);
+    {
+        let mut builder = PayloadBuilder::new(context, &mut payload);
+        builder
+            .write_payload(json, type_index)
+            .map_err(|error| builder.locate(error))?;
+    }
+    validate_payload_against_schema(&payload, context.schema, type_index).map_err(|error| {
+        LocatedDeserializationError {
+            error: DeserializationError::PayloadValidationError(
+                error.error_message(context.schema),
+            ),
+            json_path: "$".to_string(),
+        }
+    })?;
+    Ok(payload)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializationError<E: CustomTypeExtension> {
+    UnknownTypeIndex(LocalTypeIndex),
+    UnexpectedJsonType {
+        expected: &'static str,
+        actual: &'static str,
+    },
+    MissingField(String),
+    UnexpectedField(String),
+    UnknownValueKind(String),
+    /// The value has type `Any`, and its value kind isn't given by the JSON
+    UndeterminedValueKind,
+    MismatchingValueKind {
+        expected: ValueKind<E::CustomValueKind>,
+        actual: ValueKind<E::CustomValueKind>,
+    },
+    MismatchingFieldCount {
+        expected: usize,
+        actual: usize,
+    },
+    UnknownEnumVariant(u8),
+    UnknownEnumVariantName(String),
+    InvalidInteger(String),
+    InvalidHex(String),
+    InvalidCustomValue(String),
+    EncodeError(EncodeError),
+    PayloadValidationError(String),
+}
+
+impl<E: CustomTypeExtension> From<EncodeError> for DeserializationError<E> {
+    fn from(value: EncodeError) -> Self {
+        Self::EncodeError(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedDeserializationError<E: CustomTypeExtension> {
+    pub error: DeserializationError<E>,
+    /// The location of the offending value in the JSON, eg `$.fields[2].value`
+    pub json_path: String,
+}
+
+impl<E: CustomTypeExtension> LocatedDeserializationError<E> {
+    pub fn error_message(&self) -> String {
+        format!("{:?} occurred at JSON path {}", self.error, self.json_path)
+    }
+}
+
+pub fn json_type_name(json: &JsonValue) -> &'static str {
+    match json {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "a boolean",
+        JsonValue::Number(_) => "a number",
+        JsonValue::String(_) => "a string",
+        JsonValue::Array(_) => "an array",
+        JsonValue::Object(_) => "an object",
+    }
+}
+
+struct PayloadBuilder<'c, 's, 'a, 'b, E: DeserializableCustomTypeExtension> {
+    context: &'c SerializationContext<'s, 'a, E>,
+    encoder: VecEncoder<'b, E::CustomValueKind>,
+    /// The path segments to the JSON value currently being read.
+    /// If an error occurs, the segments aren't popped, so this captures the location of the error.
+    path: Vec<String>,
+}
+
+impl<'c, 's, 'a, 'b, E: DeserializableCustomTypeExtension> PayloadBuilder<'c, 's, 'a, 'b, E> {
+    fn new(context: &'c SerializationContext<'s, 'a, E>, buffer: &'b mut Vec<u8>) -> Self {
+        Self {
+            context,
+            encoder: VecEncoder::new(buffer, E::MAX_DEPTH),
+            path: vec![],
+        }
+    }
+
+    fn write_payload(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+    ) -> Result<(), DeserializationError<E>> {
+        self.encoder.write_payload_prefix(E::PAYLOAD_PREFIX)?;
+        self.deserialize_value(json, type_index, &ValueContext::Default, None, true)
+    }
+
+    fn locate(&self, error: DeserializationError<E>) -> LocatedDeserializationError<E> {
+        let mut json_path = "$".to_string();
+        for segment in self.path.iter() {
+            json_path.push_str(segment);
+        }
+        LocatedDeserializationError { error, json_path }
+    }
+
+    fn nested<T>(
+        &mut self,
+        segment: String,
+        read: impl FnOnce(&mut Self) -> Result<T, DeserializationError<E>>,
+    ) -> Result<T, DeserializationError<E>> {
+        self.path.push(segment);
+        let output = read(self)?;
+        self.path.pop();
+        Ok(output)
+    }
+
+    fn resolve_type_kind(
+        &self,
+        type_index: LocalTypeIndex,
+    ) -> Result<&'s SchemaTypeKind<E>, DeserializationError<E>> {
+        let schema: &'s Schema<E> = self.context.schema;
+        schema
+            .resolve_type_kind(type_index)
+            .ok_or(DeserializationError::UnknownTypeIndex(type_index))
+    }
+
+    fn resolve_child_names(&self, type_index: LocalTypeIndex) -> Option<&'s ChildNames> {
+        let schema: &'s Schema<E> = self.context.schema;
+        schema
+            .resolve_type_metadata(type_index)
+            .and_then(|metadata| metadata.child_names.as_ref())
+    }
+
+    /// Combines the value kind implied by the schema, the value kind implied by the parent, and
+    /// the value kind given by a kind tag in the JSON - and checks they all agree.
+    fn resolve_value_kind(
+        &self,
+        type_index: LocalTypeIndex,
+        known_value_kind: Option<ValueKind<E::CustomValueKind>>,
+        kind_tag: Option<&JsonValue>,
+    ) -> Result<ValueKind<E::CustomValueKind>, DeserializationError<E>> {
+        let type_kind = self.resolve_type_kind(type_index)?;
+        let tagged_value_kind = match kind_tag {
+            Some(kind_tag) => Some(Self::parse_value_kind(Self::expect_str(kind_tag)?)?),
+            None => None,
+        };
+        let mut resolved_value_kind = None;
+        for value_kind in [
+            Self::value_kind_for_type_kind(type_kind),
+            known_value_kind,
+            tagged_value_kind,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            match resolved_value_kind {
+                None => resolved_value_kind = Some(value_kind),
+                Some(expected) if expected != value_kind => {
+                    return Err(DeserializationError::MismatchingValueKind {
+                        expected,
+                        actual: value_kind,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        resolved_value_kind.ok_or(DeserializationError::UndeterminedValueKind)
+    }
+
+    /// Mirrors [`SerdeValueMapAggregator::should_embed_value_in_contextual_json_map`] for
+    /// non-custom values.
+    fn expects_wrapper(&self, value_context: &ValueContext) -> bool {
+        match (self.context.mode, value_context) {
+            (SerializationMode::Simple, _) => false,
+            (SerializationMode::Invertible, ValueContext::VecOrMapChild) => false,
+            _ => true,
+        }
+    }
+
+    fn deserialize_value(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+        value_context: &ValueContext,
+        known_value_kind: Option<ValueKind<E::CustomValueKind>>,
+        include_value_kind: bool,
+    ) -> Result<(), DeserializationError<E>> {
+        // We only look at a "kind" field if the schema doesn't fix the value kind - to avoid
+        // misreading eg a struct field named "kind" in Simple mode
+        let kind_tag = match self.resolve_type_kind(type_index)? {
+            TypeKind::Any => json.as_object().and_then(|object| object.get("kind")),
+            _ => None,
+        };
+        let value_kind = self.resolve_value_kind(type_index, known_value_kind, kind_tag)?;
+        if include_value_kind {
+            self.encoder.write_value_kind(value_kind)?;
+        }
+        match value_kind {
+            ValueKind::Tuple => self.deserialize_tuple(json, type_index, value_context),
+            ValueKind::Enum => self.deserialize_enum_variant(json, type_index, value_context),
+            ValueKind::Array => self.deserialize_array(json, type_index, value_context),
+            ValueKind::Map => self.deserialize_map(json, type_index, value_context),
+            _ => self.deserialize_terminal_value(json, type_index, value_kind),
+        }
+    }
+
+    fn deserialize_tuple(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+        value_context: &ValueContext,
+    ) -> Result<(), DeserializationError<E>> {
+        let field_types = match self.resolve_type_kind(type_index)? {
+            TypeKind::Tuple { field_types } => Some(field_types.as_slice()),
+            _ => None,
+        };
+        let fields_type: FieldsType<'s> = self.resolve_child_names(type_index).into();
+
+        if !self.expects_wrapper(value_context) {
+            return self.deserialize_fields(json, field_types, &fields_type);
+        }
+        let object = Self::expect_object(json)?;
+        Self::check_kind_tag(object, ValueKind::Tuple)?;
+        let fields = Self::get_field(object, "fields")?;
+        self.nested(".fields".to_string(), |builder| {
+            builder.deserialize_fields(fields, field_types, &fields_type)
+        })
+    }
+
+    fn deserialize_enum_variant(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+        value_context: &ValueContext,
+    ) -> Result<(), DeserializationError<E>> {
+        // Enum variants are always output as an object, even when they don't need a wrapper
+        let object = Self::expect_object(json)?;
+        if self.expects_wrapper(value_context) {
+            Self::check_kind_tag(object, ValueKind::Enum)?;
+        }
+        let variant_metadata = match self.resolve_child_names(type_index) {
+            Some(ChildNames::EnumVariants(variants)) => Some(variants),
+            _ => None,
+        };
+        let variant_id = match (object.get("variant_id"), object.get("variant_name")) {
+            (Some(variant_id), _) => self.nested(".variant_id".to_string(), |_| {
+                Self::parse_integer::<u8>(variant_id)
+            })?,
+            // Allow hand-written JSON to reference a variant by name only
+            (None, Some(variant_name)) => {
+                let variant_name = Self::expect_str(variant_name)?;
+                variant_metadata
+                    .and_then(|variants| {
+                        variants
+                            .iter()
+                            .find(|(_, metadata)| metadata.get_name() == Some(variant_name))
+                            .map(|(variant_id, _)| *variant_id)
+                    })
+                    .ok_or_else(|| {
+                        DeserializationError::UnknownEnumVariantName(variant_name.to_string())
+                    })?
+            }
+            (None, None) => {
+                return Err(DeserializationError::MissingField("variant_id".to_string()))
+            }
+        };
+        let field_types = match self.resolve_type_kind(type_index)? {
+            TypeKind::Enum { variants } => Some(
+                variants
+                    .get(&variant_id)
+                    .ok_or(DeserializationError::UnknownEnumVariant(variant_id))?
+                    .as_slice(),
+            ),
+            _ => None,
+        };
+        let fields_type: FieldsType<'s> = variant_metadata
+            .and_then(|variants| variants.get(&variant_id))
+            .and_then(|metadata| metadata.child_names.as_ref())
+            .into();
+
+        self.encoder.write_discriminator(variant_id)?;
+        let fields = Self::get_field(object, "fields")?;
+        self.nested(".fields".to_string(), |builder| {
+            builder.deserialize_fields(fields, field_types, &fields_type)
+        })
+    }
+
+    fn deserialize_fields(
+        &mut self,
+        json: &JsonValue,
+        field_types: Option<&[LocalTypeIndex]>,
+        fields_type: &FieldsType<'_>,
+    ) -> Result<(), DeserializationError<E>> {
+        let field_type = |index: usize| {
+            field_types
+                .and_then(|field_types| field_types.get(index).cloned())
+                .unwrap_or(LocalTypeIndex::any())
+        };
+        match (self.context.mode, fields_type) {
+            // In simple mode, structs are output as JSON objects
+            (SerializationMode::Simple, FieldsType::NamedFields(field_names)) => {
+                let object = Self::expect_object(json)?;
+                if let Some(unexpected_field) = object
+                    .keys()
+                    .find(|key| !field_names.iter().any(|name| &**name == key.as_str()))
+                {
+                    return Err(DeserializationError::UnexpectedField(
+                        unexpected_field.clone(),
+                    ));
+                }
+                self.encoder.write_size(field_names.len())?;
+                for (index, field_name) in field_names.iter().enumerate() {
+                    let value = Self::get_field(object, field_name)?;
+                    self.nested(format!(".{}", field_name), |builder| {
+                        builder.deserialize_value(
+                            value,
+                            field_type(index),
+                            &ValueContext::Default,
+                            None,
+                            true,
+                        )
+                    })?;
+                }
+                Ok(())
+            }
+            (mode, fields_type) => {
+                let values = Self::expect_array(json)?;
+                if let Some(field_types) = field_types {
+                    if field_types.len() != values.len() {
+                        return Err(DeserializationError::MismatchingFieldCount {
+                            expected: field_types.len(),
+                            actual: values.len(),
+                        });
+                    }
+                }
+                self.encoder.write_size(values.len())?;
+                for (index, value) in values.iter().enumerate() {
+                    // In invertible mode, named fields are output with their key
+                    let value_context = match (mode, fields_type) {
+                        (SerializationMode::Invertible, FieldsType::NamedFields(field_names)) => {
+                            match field_names.get(index) {
+                                Some(field_name) => ValueContext::IncludeFieldKey {
+                                    key: field_name.to_string(),
+                                },
+                                None => ValueContext::Default,
+                            }
+                        }
+                        _ => ValueContext::Default,
+                    };
+                    self.nested(format!("[{}]", index), |builder| {
+                        builder.deserialize_value(
+                            value,
+                            field_type(index),
+                            &value_context,
+                            None,
+                            true,
+                        )
+                    })?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn deserialize_array(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+        value_context: &ValueContext,
+    ) -> Result<(), DeserializationError<E>> {
+        let element_type = match self.resolve_type_kind(type_index)? {
+            TypeKind::Array { element_type } => *element_type,
+            _ => LocalTypeIndex::any(),
+        };
+        let wrapper = if self.expects_wrapper(value_context) {
+            let object = Self::expect_object(json)?;
+            Self::check_kind_tag(object, ValueKind::Array)?;
+            Some(object)
+        } else {
+            None
+        };
+        // Byte arrays are output as an object with a hex field even without a wrapper, and the
+        // element kind is included on the object whenever it isn't given by the parent
+        let details = wrapper.or_else(|| json.as_object());
+        let is_hex = details.map_or(false, |object| object.contains_key("hex"));
+        let element_value_kind = self.resolve_value_kind(
+            element_type,
+            is_hex.then_some(ValueKind::U8),
+            details.and_then(|object| object.get("element_kind")),
+        )?;
+        self.encoder.write_value_kind(element_value_kind)?;
+
+        if let (true, Some(object)) = (is_hex, details) {
+            let hex = Self::get_field(object, "hex")?;
+            return self.nested(".hex".to_string(), |builder| {
+                let hex = Self::expect_str(hex)?;
+                let bytes = hex::decode(hex)
+                    .map_err(|_| DeserializationError::InvalidHex(hex.to_string()))?;
+                builder.encoder.write_size(bytes.len())?;
+                builder.encoder.write_slice(&bytes)?;
+                Ok(())
+            });
+        }
+        match wrapper {
+            Some(object) => {
+                let elements = Self::get_field(object, "elements")?;
+                self.nested(".elements".to_string(), |builder| {
+                    builder.deserialize_array_elements(elements, element_type, element_value_kind)
+                })
+            }
+            None => self.deserialize_array_elements(json, element_type, element_value_kind),
+        }
+    }
+
+    fn deserialize_array_elements(
+        &mut self,
+        json: &JsonValue,
+        element_type: LocalTypeIndex,
+        element_value_kind: ValueKind<E::CustomValueKind>,
+    ) -> Result<(), DeserializationError<E>> {
+        let elements = Self::expect_array(json)?;
+        self.encoder.write_size(elements.len())?;
+        for (index, element) in elements.iter().enumerate() {
+            self.nested(format!("[{}]", index), |builder| {
+                builder.deserialize_value(
+                    element,
+                    element_type,
+                    &ValueContext::VecOrMapChild,
+                    Some(element_value_kind),
+                    false,
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_map(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+        value_context: &ValueContext,
+    ) -> Result<(), DeserializationError<E>> {
+        let (key_type, value_type) = match self.resolve_type_kind(type_index)? {
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => (*key_type, *value_type),
+            _ => (LocalTypeIndex::any(), LocalTypeIndex::any()),
+        };
+        let wrapper = if self.expects_wrapper(value_context) {
+            let object = Self::expect_object(json)?;
+            Self::check_kind_tag(object, ValueKind::Map)?;
+            Some(object)
+        } else {
+            None
+        };
+        let entry_types = MapEntryTypes {
+            key_type,
+            key_value_kind: self.resolve_value_kind(
+                key_type,
+                None,
+                wrapper.and_then(|object| object.get("key_kind")),
+            )?,
+            value_type,
+            value_value_kind: self.resolve_value_kind(
+                value_type,
+                None,
+                wrapper.and_then(|object| object.get("value_kind")),
+            )?,
+        };
+        self.encoder.write_value_kind(entry_types.key_value_kind)?;
+        self.encoder
+            .write_value_kind(entry_types.value_value_kind)?;
+
+        match wrapper {
+            Some(object) => {
+                let entries = Self::get_field(object, "entries")?;
+                self.nested(".entries".to_string(), |builder| {
+                    builder.deserialize_map_entries(entries, &entry_types)
+                })
+            }
+            None => self.deserialize_map_entries(json, &entry_types),
+        }
+    }
+
+    fn deserialize_map_entries(
+        &mut self,
+        json: &JsonValue,
+        entry_types: &MapEntryTypes<E>,
+    ) -> Result<(), DeserializationError<E>> {
+        // In simple mode, maps with string keys are output as JSON objects
+        if let (SerializationMode::Simple, ValueKind::String) =
+            (self.context.mode, entry_types.key_value_kind)
+        {
+            let entries = Self::expect_object(json)?;
+            self.encoder.write_size(entries.len())?;
+            for (key, value) in entries.iter() {
+                self.encoder.write_size(key.len())?;
+                self.encoder.write_slice(key.as_bytes())?;
+                self.nested(format!(".{}", key), |builder| {
+                    builder.deserialize_value(
+                        value,
+                        entry_types.value_type,
+                        &ValueContext::VecOrMapChild,
+                        Some(entry_types.value_value_kind),
+                        false,
+                    )
+                })?;
+            }
+            return Ok(());
+        }
+
+        let entries = Self::expect_array(json)?;
+        self.encoder.write_size(entries.len())?;
+        for (index, entry) in entries.iter().enumerate() {
+            self.nested(format!("[{}]", index), |builder| {
+                let entry = Self::expect_array(entry)?;
+                if entry.len() != 2 {
+                    return Err(DeserializationError::MismatchingFieldCount {
+                        expected: 2,
+                        actual: entry.len(),
+                    });
+                }
+                builder.nested("[0]".to_string(), |builder| {
+                    builder.deserialize_value(
+                        &entry[0],
+                        entry_types.key_type,
+                        &ValueContext::VecOrMapChild,
+                        Some(entry_types.key_value_kind),
+                        false,
+                    )
+                })?;
+                builder.nested("[1]".to_string(), |builder| {
+                    builder.deserialize_value(
+                        &entry[1],
+                        entry_types.value_type,
+                        &ValueContext::VecOrMapChild,
+                        Some(entry_types.value_value_kind),
+                        false,
+                    )
+                })
+            })?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_terminal_value(
+        &mut self,
+        json: &JsonValue,
+        type_index: LocalTypeIndex,
+        value_kind: ValueKind<E::CustomValueKind>,
+    ) -> Result<(), DeserializationError<E>> {
+        // Terminal values themselves are never output as JSON objects, so an object is a wrapper
+        if let JsonValue::Object(object) = json {
+            Self::check_kind_tag(object, value_kind)?;
+            let value = Self::get_field(object, "value")?;
+            return self.nested(".value".to_string(), |builder| {
+                builder.deserialize_terminal_value(value, type_index, value_kind)
+            });
+        }
+        match value_kind {
+            ValueKind::Bool => {
+                let value = json
+                    .as_bool()
+                    .ok_or_else(|| Self::unexpected_json_type("a boolean", json))?;
+                self.encoder.write_byte(value as u8)?;
+            }
+            ValueKind::I8 => {
+                let value = Self::parse_integer::<i8>(json)?;
+                self.encoder.write_byte(value as u8)?;
+            }
+            ValueKind::I16 => {
+                let value = Self::parse_integer::<i16>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::I32 => {
+                let value = Self::parse_integer::<i32>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::I64 => {
+                let value = Self::parse_integer::<i64>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::I128 => {
+                let value = Self::parse_integer::<i128>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::U8 => {
+                let value = Self::parse_integer::<u8>(json)?;
+                self.encoder.write_byte(value)?;
+            }
+            ValueKind::U16 => {
+                let value = Self::parse_integer::<u16>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::U32 => {
+                let value = Self::parse_integer::<u32>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::U64 => {
+                let value = Self::parse_integer::<u64>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::U128 => {
+                let value = Self::parse_integer::<u128>(json)?;
+                self.encoder.write_slice(&value.to_le_bytes())?;
+            }
+            ValueKind::String => {
+                let value = Self::expect_str(json)?;
+                self.encoder.write_size(value.len())?;
+                self.encoder.write_slice(value.as_bytes())?;
+            }
+            ValueKind::Custom(custom_value_kind) => {
+                E::deserialize_value_body(
+                    self.context,
+                    type_index,
+                    custom_value_kind,
+                    json,
+                    &mut self.encoder,
+                )?;
+            }
+            ValueKind::Tuple | ValueKind::Enum | ValueKind::Array | ValueKind::Map => {
+                unreachable!("Container value kinds are not terminal values")
+            }
+        }
+        Ok(())
+    }
+
+    fn value_kind_for_type_kind(
+        type_kind: &SchemaTypeKind<E>,
+    ) -> Option<ValueKind<E::CustomValueKind>> {
+        let value_kind = match type_kind {
+            TypeKind::Any => return None,
+            TypeKind::Bool => ValueKind::Bool,
+            TypeKind::I8 => ValueKind::I8,
+            TypeKind::I16 => ValueKind::I16,
+            TypeKind::I32 => ValueKind::I32,
+            TypeKind::I64 => ValueKind::I64,
+            TypeKind::I128 => ValueKind::I128,
+            TypeKind::U8 => ValueKind::U8,
+            TypeKind::U16 => ValueKind::U16,
+            TypeKind::U32 => ValueKind::U32,
+            TypeKind::U64 => ValueKind::U64,
+            TypeKind::U128 => ValueKind::U128,
+            TypeKind::String => ValueKind::String,
+            TypeKind::Array { .. } => ValueKind::Array,
+            TypeKind::Tuple { .. } => ValueKind::Tuple,
+            TypeKind::Enum { .. } => ValueKind::Enum,
+            TypeKind::Map { .. } => ValueKind::Map,
+            TypeKind::Custom(custom_type_kind) => {
+                ValueKind::Custom(E::custom_value_kind_for_type_kind(custom_type_kind))
+            }
+        };
+        Some(value_kind)
+    }
+
+    /// The inverse of the `Display` implementation of [`ValueKind`]
+    fn parse_value_kind(
+        name: &str,
+    ) -> Result<ValueKind<E::CustomValueKind>, DeserializationError<E>> {
+        let value_kind = match name {
+            "Bool" => ValueKind::Bool,
+            "I8" => ValueKind::I8,
+            "I16" => ValueKind::I16,
+            "I32" => ValueKind::I32,
+            "I64" => ValueKind::I64,
+            "I128" => ValueKind::I128,
+            "U8" => ValueKind::U8,
+            "U16" => ValueKind::U16,
+            "U32" => ValueKind::U32,
+            "U64" => ValueKind::U64,
+            "U128" => ValueKind::U128,
+            "String" => ValueKind::String,
+            "Enum" => ValueKind::Enum,
+            "Array" => ValueKind::Array,
+            "Tuple" => ValueKind::Tuple,
+            "Map" => ValueKind::Map,
+            _ => match E::custom_value_kind_from_name(name) {
+                Some(custom_value_kind) => ValueKind::Custom(custom_value_kind),
+                None => return Err(DeserializationError::UnknownValueKind(name.to_string())),
+            },
+        };
+        Ok(value_kind)
+    }
+
+    /// Integers are output as JSON numbers up to 32 bits, and as strings above that - but we
+    /// accept either for all integer kinds.
+    fn parse_integer<T: FromStr + TryFrom<u64> + TryFrom<i64>>(
+        json: &JsonValue,
+    ) -> Result<T, DeserializationError<E>> {
+        match json {
+            JsonValue::Number(number) => number
+                .as_u64()
+                .and_then(|value| T::try_from(value).ok())
+                .or_else(|| number.as_i64().and_then(|value| T::try_from(value).ok()))
+                .ok_or_else(|| DeserializationError::InvalidInteger(number.to_string())),
+            JsonValue::String(string) => string
+                .parse::<T>()
+                .map_err(|_| DeserializationError::InvalidInteger(string.clone())),
+            _ => Err(Self::unexpected_json_type("a number", json)),
+        }
+    }
+
+    fn check_kind_tag(
+        object: &JsonMap<String, JsonValue>,
+        value_kind: ValueKind<E::CustomValueKind>,
+    ) -> Result<(), DeserializationError<E>> {
+        if let Some(kind_tag) = object.get("kind") {
+            let tagged_value_kind = Self::parse_value_kind(Self::expect_str(kind_tag)?)?;
+            if tagged_value_kind != value_kind {
+                return Err(DeserializationError::MismatchingValueKind {
+                    expected: value_kind,
+                    actual: tagged_value_kind,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn get_field<'j>(
+        object: &'j JsonMap<String, JsonValue>,
+        field_name: &str,
+    ) -> Result<&'j JsonValue, DeserializationError<E>> {
+        object
+            .get(field_name)
+            .ok_or_else(|| DeserializationError::MissingField(field_name.to_string()))
+    }
+
+    fn expect_object(
+        json: &JsonValue,
+    ) -> Result<&JsonMap<String, JsonValue>, DeserializationError<E>> {
+        json.as_object()
+            .ok_or_else(|| Self::unexpected_json_type("an object", json))
+    }
+
+    fn expect_array(json: &JsonValue) -> Result<&Vec<JsonValue>, DeserializationError<E>> {
+        json.as_array()
+            .ok_or_else(|| Self::unexpected_json_type("an array", json))
+    }
+
+    fn expect_str(json: &JsonValue) -> Result<&str, DeserializationError<E>> {
+        json.as_str()
+            .ok_or_else(|| Self::unexpected_json_type("a string", json))
+    }
+
+    fn unexpected_json_type(expected: &'static str, json: &JsonValue) -> DeserializationError<E> {
+        DeserializationError::UnexpectedJsonType {
+            expected,
+            actual: json_type_name(json),
+        }
+    }
+}
+
+struct MapEntryTypes<E: CustomTypeExtension> {
+    key_type: LocalTypeIndex,
+    key_value_kind: ValueKind<E::CustomValueKind>,
+    value_type: LocalTypeIndex,
+    value_value_kind: ValueKind<E::CustomValueKind>,
+}
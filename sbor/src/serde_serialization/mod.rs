@@ -1,7 +1,9 @@
+mod deserialization;
 mod serialization;
 mod traits;
 mod value_map_aggregator;
 
+pub use deserialization::*;
 pub use serialization::*;
 pub use traits::*;
 pub use value_map_aggregator::*;
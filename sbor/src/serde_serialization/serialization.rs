@@ -948,12 +948,8 @@ mod tests {
         field2: Vec<String>,
     }
 
-    #[test]
-    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
-    fn complex_value_encoding() {
-        let (type_index, schema) =
-            generate_full_schema_from_single_type::<MyComplexTupleStruct, NoCustomTypeExtension>();
-        let value = MyComplexTupleStruct(
+    fn complex_value() -> MyComplexTupleStruct {
+        MyComplexTupleStruct(
             vec![1, 2, 3],
             vec![],
             vec![],
@@ -990,8 +986,15 @@ mod tests {
                     },
                 ],
             },
-        );
-        let payload = basic_encode(&value).unwrap();
+        )
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn complex_value_encoding() {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<MyComplexTupleStruct, NoCustomTypeExtension>();
+        let payload = basic_encode(&complex_value()).unwrap();
 
         let expected_simple = json!([
             [1, 2, 3],
@@ -1288,4 +1291,147 @@ mod tests {
             expected_invertible,
         );
     }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn complex_value_invertible_round_trip() {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<MyComplexTupleStruct, NoCustomTypeExtension>();
+        let payload = basic_encode(&complex_value()).unwrap();
+        let context = SerializationContext {
+            mode: SerializationMode::Invertible,
+            schema: &schema,
+            custom_context: (),
+        };
+
+        let json = to_value(
+            SborPayloadWithSchema::<NoCustomTypeExtension>::new(&payload, type_index)
+                .serializable(context),
+        )
+        .unwrap();
+
+        assert_eq!(json_to_payload(&json, &context, type_index), Ok(payload));
+    }
+
+    #[derive(Sbor)]
+    struct MySimpleModeStruct(
+        MyFieldStruct,
+        IndexMap<TestEnum, MyFieldStruct>,
+        BTreeMap<String, MyUnitStruct>,
+        Vec<u8>,
+        Vec<u16>,
+        i64,
+        u128,
+    );
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn simple_value_without_any_round_trip() {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<MySimpleModeStruct, NoCustomTypeExtension>();
+        let value = MySimpleModeStruct(
+            MyFieldStruct {
+                field1: u64::MAX,
+                field2: vec!["hello".to_string()],
+            },
+            indexmap! {
+                TestEnum::DoubleStructVariant { field1: 1, field2: 2 } => MyFieldStruct { field1: 3, field2: vec![] },
+                TestEnum::UnitVariant => MyFieldStruct { field1: 1, field2: vec!["world".to_string()] },
+            },
+            btreemap! {
+                "hello".to_string() => MyUnitStruct,
+            },
+            vec![1, 2, 3],
+            vec![153, 62],
+            i64::MIN,
+            u128::MAX,
+        );
+        let payload = basic_encode(&value).unwrap();
+        let context = SerializationContext {
+            mode: SerializationMode::Simple,
+            schema: &schema,
+            custom_context: (),
+        };
+
+        let json = to_value(
+            SborPayloadWithSchema::<NoCustomTypeExtension>::new(&payload, type_index)
+                .serializable(context),
+        )
+        .unwrap();
+
+        assert_eq!(json_to_payload(&json, &context, type_index), Ok(payload));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn simple_map_with_string_keys_is_read_in_sorted_key_order() {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<IndexMap<String, u8>, NoCustomTypeExtension>();
+        let context = SerializationContext {
+            mode: SerializationMode::Simple,
+            schema: &schema,
+            custom_context: (),
+        };
+
+        let json: JsonValue = serde_json::from_str(r#"{ "zebra": 1, "apple": 2 }"#).unwrap();
+        let sorted = basic_encode(&indexmap! {
+            "apple".to_string() => 2u8,
+            "zebra".to_string() => 1u8,
+        })
+        .unwrap();
+        assert_eq!(
+            json_to_payload(&json, &context, type_index),
+            Ok(sorted.clone())
+        );
+
+        let unsorted = basic_encode(&indexmap! {
+            "zebra".to_string() => 1u8,
+            "apple".to_string() => 2u8,
+        })
+        .unwrap();
+        let json = to_value(
+            SborPayloadWithSchema::<NoCustomTypeExtension>::new(&unsorted, type_index)
+                .serializable(context),
+        )
+        .unwrap();
+        assert_eq!(json_to_payload(&json, &context, type_index), Ok(sorted));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn json_deserialization_errors_are_located() {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<MyFieldStruct, NoCustomTypeExtension>();
+        let context = SerializationContext {
+            mode: SerializationMode::Simple,
+            schema: &schema,
+            custom_context: (),
+        };
+
+        let error = json_to_payload(
+            &json!({ "field1": "1", "field2": [5] }),
+            &context,
+            type_index,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.error,
+            DeserializationError::UnexpectedJsonType {
+                expected: "a string",
+                actual: "a number",
+            }
+        );
+        assert_eq!(error.json_path, "$.field2[0]");
+
+        let error = json_to_payload(
+            &json!({ "field1": "1", "field2": [], "field3": true }),
+            &context,
+            type_index,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.error,
+            DeserializationError::UnexpectedField("field3".to_string())
+        );
+    }
 }
@@ -21,3 +21,26 @@ pub trait SerializableCustomTypeExtension: CustomTypeExtension + Copy {
         value: <Self::CustomTraversal as CustomTraversal>::CustomTerminalValueRef<'de>,
     ) -> CustomTypeSerialization<'a, 't, 'de, 's1, 's2, Self>;
 }
+
+/// Extends a [`SerializableCustomTypeExtension`] so that the JSON it produces can be parsed back
+/// into an SBOR payload, given the schema of the payload.
+pub trait DeserializableCustomTypeExtension: SerializableCustomTypeExtension {
+    /// Maps the name of a custom value kind (as output in the `kind` field) to the value kind.
+    fn custom_value_kind_from_name(name: &str) -> Option<Self::CustomValueKind>;
+
+    /// Returns the value kind which a value of the given custom type kind is encoded as.
+    fn custom_value_kind_for_type_kind(
+        type_kind: &SchemaCustomTypeKind<Self>,
+    ) -> Self::CustomValueKind;
+
+    /// Writes the body of the custom value represented by the JSON `value` into the encoder.
+    ///
+    /// The `value` has already been unwrapped from any `{ "kind": .., "value": .. }` object.
+    fn deserialize_value_body(
+        context: &SerializationContext<'_, '_, Self>,
+        type_index: LocalTypeIndex,
+        value_kind: Self::CustomValueKind,
+        value: &JsonValue,
+        encoder: &mut VecEncoder<Self::CustomValueKind>,
+    ) -> Result<(), DeserializationError<Self>>;
+}
@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use radix_engine::blueprints::epoch_manager::*;
+use radix_engine::blueprints::resource::VaultFrozenFlagSubstate;
 use radix_engine::errors::*;
 use radix_engine::kernel::id_allocator::IdAllocator;
 use radix_engine::kernel::interpreters::ScryptoInterpreter;
@@ -16,12 +17,18 @@ use radix_engine::system::kernel_modules::costing::FeeTable;
 use radix_engine::system::kernel_modules::costing::SystemLoanFeeReserve;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction, ExecutionConfig, FeeReserveConfig, PreviewError,
-    PreviewResult, TransactionReceipt, TransactionResult,
+    execute_preview, execute_transaction, CommitResult, ExecutionConfig, FeeReserveConfig,
+    LedgerTransaction, PreviewError, PreviewResult, RecordedTransaction, ResourcesUsage,
+    TransactionOutcome, TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
-use radix_engine::wasm::{DefaultWasmEngine, WasmInstrumenter, WasmMeteringConfig};
+#[cfg(feature = "compare-wasm-engines")]
+use radix_engine::wasm::WasmiEngine;
+use radix_engine::wasm::{
+    DefaultWasmEngine, InstrumenterOptions, WasmEngine, WasmInstrumenter, WasmMeteringConfig,
+    WasmRuntimeError,
+};
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::node_modules::royalty::*;
@@ -32,7 +39,7 @@ use radix_engine_interface::blueprints::clock::{
     CLOCK_GET_CURRENT_TIME_IDENT, CLOCK_SET_CURRENT_TIME_IDENT,
 };
 use radix_engine_interface::blueprints::epoch_manager::{
-    EpochManagerGetCurrentEpochInput, EpochManagerSetEpochInput,
+    EpochManagerConfig, EpochManagerGetCurrentEpochInput, EpochManagerSetEpochInput,
     EPOCH_MANAGER_GET_CURRENT_EPOCH_IDENT, EPOCH_MANAGER_SET_EPOCH_IDENT,
 };
 use radix_engine_interface::blueprints::resource::*;
@@ -44,16 +51,19 @@ use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_interface::schema::{BlueprintSchema, FunctionSchema, PackageSchema};
 use radix_engine_interface::time::Instant;
 use radix_engine_interface::{dec, rule};
+use radix_engine_stores::hash_tree::proof::SubstateProof;
 use radix_engine_stores::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
-use radix_engine_stores::hash_tree::{put_at_next_version, SubstateHashChange};
+use radix_engine_stores::hash_tree::{get_with_proof, put_at_next_version, SubstateHashChange};
 use sbor::basic_well_known_types::{ANY_ID, UNIT_ID};
 use scrypto::modules::Mutability::*;
 use scrypto::prelude::*;
 use transaction::builder::ManifestBuilder;
 use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
-use transaction::model::{AuthZoneParams, PreviewIntent, TestTransaction};
+use transaction::model::{AuthZoneParams, NotarizedTransaction, PreviewIntent, TestTransaction};
 use transaction::model::{Executable, Instruction, SystemTransaction, TransactionManifest};
-use transaction::validation::TestIntentHashManager;
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
 
 pub struct Compile;
 
@@ -123,6 +133,8 @@ pub struct TestRunnerBuilder {
     custom_genesis: Option<SystemTransaction>,
     trace: bool,
     state_hashing: bool,
+    transaction_recording: bool,
+    wasm_disk_cache_dir: Option<PathBuf>,
 }
 
 impl TestRunnerBuilder {
@@ -136,30 +148,66 @@ impl TestRunnerBuilder {
         self
     }
 
+    /// Records the notarized and system transactions executed after genesis, together with their
+    /// receipts, so that they can be replayed with a `TransactionReplayer`.
+    pub fn with_transaction_recording(mut self) -> Self {
+        self.transaction_recording = true;
+        self
+    }
+
     pub fn with_custom_genesis(mut self, genesis: SystemTransaction) -> Self {
         self.custom_genesis = Some(genesis);
         self
     }
 
+    /// Persists instrumented WASM code in the given directory, to be reused by later test runners.
+    pub fn with_wasm_disk_cache_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.wasm_disk_cache_dir = Some(dir.into());
+        self
+    }
+
     pub fn build(self) -> TestRunner {
+        self.build_with_wasm_engine(DefaultWasmEngine::default())
+    }
+
+    /// Builds a test runner which executes WASM with the given engine, rather than the default one.
+    pub fn build_with_wasm_engine<W: WasmEngine>(self, wasm_engine: W) -> TestRunner<W> {
+        let mut instrumenter_options = InstrumenterOptions::default();
+        if let Some(dir) = self.wasm_disk_cache_dir {
+            instrumenter_options = instrumenter_options.with_disk_cache_dir(dir);
+        }
         let mut runner = TestRunner {
             scrypto_interpreter: ScryptoInterpreter {
                 wasm_metering_config: WasmMeteringConfig::V0,
-                wasm_engine: DefaultWasmEngine::default(),
+                wasm_engine,
+                wasm_instrumenter: WasmInstrumenter::new(instrumenter_options),
+            },
+            #[cfg(feature = "compare-wasm-engines")]
+            reference_scrypto_interpreter: ScryptoInterpreter {
+                wasm_metering_config: WasmMeteringConfig::V0,
+                wasm_engine: WasmiEngine::default(),
                 wasm_instrumenter: WasmInstrumenter::default(),
             },
             substate_store: TypedInMemorySubstateStore::new(),
             state_hash_support: Some(self.state_hashing)
                 .filter(|x| *x)
                 .map(|_| StateHashSupport::new()),
+            recorded_transactions: Some(self.transaction_recording)
+                .filter(|x| *x)
+                .map(|_| Vec::new()),
             intent_hash_manager: TestIntentHashManager::new(),
             next_private_key: 1, // 0 is invalid
             next_transaction_nonce: 0,
             trace: self.trace,
         };
-        let genesis = self
-            .custom_genesis
-            .unwrap_or_else(|| create_genesis(BTreeMap::new(), BTreeMap::new(), 1u64, 1u64, 1u64));
+        let genesis = self.custom_genesis.unwrap_or_else(|| {
+            create_genesis(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                1u64,
+                EpochManagerConfig::default(),
+            )
+        });
         let receipt = runner.execute_transaction_with_config(
             genesis.get_executable(vec![AuthAddresses::system_role()]),
             &FeeReserveConfig::default(),
@@ -170,14 +218,22 @@ impl TestRunnerBuilder {
     }
 }
 
-pub struct TestRunner {
-    scrypto_interpreter: ScryptoInterpreter<DefaultWasmEngine>,
+/// Executes transactions against an in-memory ledger.
+///
+/// With the `compare-wasm-engines` feature, which makes Wasmer the default engine, every
+/// transaction is also executed with wasmi against the same state, and the receipts are asserted
+/// to match (see [`assert_receipts_match`]).
+pub struct TestRunner<W: WasmEngine = DefaultWasmEngine> {
+    scrypto_interpreter: ScryptoInterpreter<W>,
+    #[cfg(feature = "compare-wasm-engines")]
+    reference_scrypto_interpreter: ScryptoInterpreter<WasmiEngine>,
     substate_store: TypedInMemorySubstateStore,
     intent_hash_manager: TestIntentHashManager,
     next_private_key: u64,
     next_transaction_nonce: u64,
     trace: bool,
     state_hash_support: Option<StateHashSupport>,
+    recorded_transactions: Option<Vec<RecordedTransaction>>,
 }
 
 impl TestRunner {
@@ -186,9 +242,56 @@ impl TestRunner {
             custom_genesis: None,
             trace: true,
             state_hashing: false,
+            transaction_recording: false,
+            wasm_disk_cache_dir: None,
         }
     }
 
+    pub fn kernel_invoke_function(
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+        args: &Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        // Prepare data for creating kernel
+        let substate_store = TypedInMemorySubstateStore::new();
+        let mut track = Track::new(&substate_store);
+        let transaction_hash = hash(vec![0]);
+        let mut id_allocator = IdAllocator::new(transaction_hash, BTreeSet::new());
+        let execution_config = ExecutionConfig::standard();
+        let modules = KernelModuleMixer::standard(
+            transaction_hash,
+            AuthZoneParams {
+                initial_proofs: vec![],
+                virtual_resources: BTreeSet::new(),
+            },
+            SystemLoanFeeReserve::no_fee(),
+            FeeTable::new(),
+            &execution_config,
+        );
+        let scrypto_interpreter = ScryptoInterpreter {
+            wasm_metering_config: WasmMeteringConfig::V0,
+            wasm_engine: DefaultWasmEngine::default(),
+            wasm_instrumenter: WasmInstrumenter::default(),
+        };
+
+        // Create kernel
+        let mut kernel = Kernel::new(&mut id_allocator, &mut track, &scrypto_interpreter, modules);
+
+        // Initialize kernel
+        kernel.initialize().expect("Failed to initialize kernel");
+
+        // Call function
+        kernel.call_function(
+            package_address,
+            blueprint_name,
+            function_name,
+            scrypto_args!(args),
+        )
+    }
+}
+
+impl<W: WasmEngine> TestRunner<W> {
     pub fn substate_store(&self) -> &TypedInMemorySubstateStore {
         &self.substate_store
     }
@@ -378,6 +481,19 @@ impl TestRunner {
         }
     }
 
+    pub fn inspect_vault_frozen_flags(&mut self, vault_id: ObjectId) -> Option<VaultFreezeFlags> {
+        self.substate_store()
+            .get_substate(&SubstateId(
+                RENodeId::Object(vault_id),
+                NodeModuleId::SELF,
+                SubstateOffset::Vault(VaultOffset::FrozenFlag),
+            ))
+            .map(|output| {
+                let frozen_flag: VaultFrozenFlagSubstate = output.substate.into();
+                frozen_flag.frozen
+            })
+    }
+
     pub fn inspect_fungible_vault(&mut self, vault_id: ObjectId) -> Option<Decimal> {
         self.substate_store()
             .get_substate(&SubstateId(
@@ -483,25 +599,29 @@ impl TestRunner {
     }
 
     pub fn get_validator_with_key(&mut self, key: &EcdsaSecp256k1PublicKey) -> ComponentAddress {
-        let substate_id = SubstateId(
-            RENodeId::GlobalObject(EPOCH_MANAGER.into()),
-            NodeModuleId::SELF,
-            SubstateOffset::EpochManager(EpochManagerOffset::CurrentValidatorSet),
-        );
-        let substate: ValidatorSetSubstate = self
-            .substate_store()
-            .get_substate(&substate_id)
-            .unwrap()
-            .substate
-            .to_runtime()
-            .into();
-        substate
-            .validator_set
-            .iter()
-            .find(|(_, v)| v.key.eq(key))
-            .unwrap()
-            .0
-            .clone()
+        // Validators left out of the current set because of its size cap are still candidates
+        // in the preparing set
+        for offset in [
+            EpochManagerOffset::CurrentValidatorSet,
+            EpochManagerOffset::PreparingValidatorSet,
+        ] {
+            let substate_id = SubstateId(
+                RENodeId::GlobalObject(EPOCH_MANAGER.into()),
+                NodeModuleId::SELF,
+                SubstateOffset::EpochManager(offset),
+            );
+            let substate: ValidatorSetSubstate = self
+                .substate_store()
+                .get_substate(&substate_id)
+                .unwrap()
+                .substate
+                .to_runtime()
+                .into();
+            if let Some((address, _)) = substate.validator_set.iter().find(|(_, v)| v.key.eq(key)) {
+                return *address;
+            }
+        }
+        panic!("No validator with the given key")
     }
 
     pub fn new_allocated_account(
@@ -669,6 +789,17 @@ impl TestRunner {
             execution_config,
             &executable,
         );
+        #[cfg(feature = "compare-wasm-engines")]
+        assert_receipts_match(
+            &execute_transaction(
+                &self.substate_store,
+                &self.reference_scrypto_interpreter,
+                fee_reserve_config,
+                execution_config,
+                &executable,
+            ),
+            &transaction_receipt,
+        );
         if let TransactionResult::Commit(commit) = &transaction_receipt.result {
             let commit_receipt = commit.state_updates.commit(&mut self.substate_store);
             if let Some(state_hash_support) = &mut self.state_hash_support {
@@ -678,6 +809,48 @@ impl TestRunner {
         transaction_receipt
     }
 
+    /// Validates and executes a notarized transaction, like a node would.
+    pub fn execute_notarized_transaction(
+        &mut self,
+        transaction: &NotarizedTransaction,
+    ) -> TransactionReceipt {
+        let payload_size = transaction
+            .to_bytes()
+            .expect("Could not encode notarized transaction")
+            .len();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let executable = validator
+            .validate(transaction, payload_size, &self.intent_hash_manager)
+            .expect("Invalid notarized transaction");
+        let receipt = self.execute_transaction(executable);
+        self.record(LedgerTransaction::User(transaction.clone()), &receipt);
+        receipt
+    }
+
+    /// Executes a system transaction with the system role.
+    pub fn execute_system_transaction(
+        &mut self,
+        transaction: SystemTransaction,
+    ) -> TransactionReceipt {
+        let receipt = self
+            .execute_transaction(transaction.get_executable(vec![AuthAddresses::system_role()]));
+        self.record(LedgerTransaction::System(transaction), &receipt);
+        receipt
+    }
+
+    /// Returns the transactions recorded so far (see `TestRunnerBuilder::with_transaction_recording`).
+    pub fn recorded_transactions(&self) -> &[RecordedTransaction] {
+        self.recorded_transactions
+            .as_ref()
+            .expect("transaction recording not enabled")
+    }
+
+    fn record(&mut self, transaction: LedgerTransaction, receipt: &TransactionReceipt) {
+        if let Some(recorded_transactions) = &mut self.recorded_transactions {
+            recorded_transactions.push(RecordedTransaction::new(&transaction, receipt.clone()));
+        }
+    }
+
     pub fn preview(
         &mut self,
         preview_intent: PreviewIntent,
@@ -845,6 +1018,15 @@ impl TestRunner {
         self.create_fungible_resource_and_deposit(access_rules, account)
     }
 
+    pub fn create_freezeable_token(&mut self, account: ComponentAddress) -> ResourceAddress {
+        let mut access_rules = BTreeMap::new();
+        access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Freeze, (rule!(allow_all), LOCKED));
+
+        self.create_fungible_resource_and_deposit(access_rules, account)
+    }
+
     pub fn create_restricted_burn_token(
         &mut self,
         account: ComponentAddress,
@@ -860,6 +1042,21 @@ impl TestRunner {
         (auth_resource_address, resource_address)
     }
 
+    pub fn create_restricted_burn_non_fungible_resource(
+        &mut self,
+        account: ComponentAddress,
+    ) -> (ResourceAddress, ResourceAddress) {
+        let auth_resource_address = self.create_non_fungible_resource(account);
+
+        let mut access_rules = BTreeMap::new();
+        access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
+        access_rules.insert(Burn, (rule!(require(auth_resource_address)), LOCKED));
+        let resource_address = self.create_non_fungible_resource_and_deposit(access_rules, account);
+
+        (auth_resource_address, resource_address)
+    }
+
     pub fn create_restricted_transfer_token(
         &mut self,
         account: ComponentAddress,
@@ -882,6 +1079,27 @@ impl TestRunner {
         access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
         access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
 
+        self.create_non_fungible_resource_and_deposit(access_rules, account)
+    }
+
+    pub fn create_burnable_non_fungible_resource(
+        &mut self,
+        account: ComponentAddress,
+    ) -> ResourceAddress {
+        let mut access_rules = BTreeMap::new();
+        access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Burn, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Freeze, (rule!(allow_all), LOCKED));
+
+        self.create_non_fungible_resource_and_deposit(access_rules, account)
+    }
+
+    fn create_non_fungible_resource_and_deposit(
+        &mut self,
+        access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
+        account: ComponentAddress,
+    ) -> ResourceAddress {
         let mut entries = BTreeMap::new();
         entries.insert(NonFungibleLocalId::integer(1), EmptyNonFungibleData {});
         entries.insert(NonFungibleLocalId::integer(2), EmptyNonFungibleData {});
@@ -1018,6 +1236,18 @@ impl TestRunner {
             .get_current()
     }
 
+    /// Returns the hash of the given substate in the current state (or `None` if it does not
+    /// exist), together with a proof verifiable against `get_state_hash()`.
+    pub fn get_substate_hash_with_proof(
+        &self,
+        substate_id: &SubstateId,
+    ) -> (Option<Hash>, SubstateProof) {
+        self.state_hash_support
+            .as_ref()
+            .expect("state hashing not enabled")
+            .get_with_proof(substate_id)
+    }
+
     pub fn set_current_time(&mut self, current_time_ms: i64) {
         let instructions = vec![Instruction::CallMethod {
             component_address: CLOCK,
@@ -1060,49 +1290,6 @@ impl TestRunner {
         receipt.expect_commit(true).output(0)
     }
 
-    pub fn kernel_invoke_function(
-        package_address: PackageAddress,
-        blueprint_name: &str,
-        function_name: &str,
-        args: &Vec<u8>,
-    ) -> Result<Vec<u8>, RuntimeError> {
-        // Prepare data for creating kernel
-        let substate_store = TypedInMemorySubstateStore::new();
-        let mut track = Track::new(&substate_store);
-        let transaction_hash = hash(vec![0]);
-        let mut id_allocator = IdAllocator::new(transaction_hash, BTreeSet::new());
-        let execution_config = ExecutionConfig::standard();
-        let modules = KernelModuleMixer::standard(
-            transaction_hash,
-            AuthZoneParams {
-                initial_proofs: vec![],
-                virtual_resources: BTreeSet::new(),
-            },
-            SystemLoanFeeReserve::no_fee(),
-            FeeTable::new(),
-            &execution_config,
-        );
-        let scrypto_interpreter = ScryptoInterpreter {
-            wasm_metering_config: WasmMeteringConfig::V0,
-            wasm_engine: DefaultWasmEngine::default(),
-            wasm_instrumenter: WasmInstrumenter::default(),
-        };
-
-        // Create kernel
-        let mut kernel = Kernel::new(&mut id_allocator, &mut track, &scrypto_interpreter, modules);
-
-        // Initialize kernel
-        kernel.initialize().expect("Failed to initialize kernel");
-
-        // Call function
-        kernel.call_function(
-            package_address,
-            blueprint_name,
-            function_name,
-            scrypto_args!(args),
-        )
-    }
-
     pub fn event_schema(
         &self,
         event_type_identifier: &EventTypeIdentifier,
@@ -1251,6 +1438,10 @@ impl StateHashSupport {
     pub fn get_current(&self) -> Hash {
         self.current_hash
     }
+
+    pub fn get_with_proof(&self, substate_id: &SubstateId) -> (Option<Hash>, SubstateProof) {
+        get_with_proof(&self.tree_store, self.current_version, substate_id)
+    }
 }
 
 pub fn is_auth_error(e: &RuntimeError) -> bool {
@@ -1268,6 +1459,54 @@ pub fn is_wasm_error(e: &RuntimeError) -> bool {
     )
 }
 
+/// Asserts that the receipts of a transaction executed with two different WASM engines match.
+///
+/// The resources used and the description of WASM traps are engine specific, and are ignored.
+pub fn assert_receipts_match(expected: &TransactionReceipt, actual: &TransactionReceipt) {
+    fn normalize(receipt: &TransactionReceipt) -> TransactionReceipt {
+        let mut receipt = receipt.clone();
+        receipt.execution_trace.resources_usage = ResourcesUsage::default();
+        if let TransactionResult::Commit(CommitResult {
+            outcome:
+                TransactionOutcome::Failure(RuntimeError::KernelError(KernelError::WasmRuntimeError(
+                    WasmRuntimeError::Trap(description),
+                ))),
+            ..
+        }) = &mut receipt.result
+        {
+            description.clear();
+        }
+        receipt
+    }
+
+    let expected = normalize(expected);
+    let actual = normalize(actual);
+    if let (TransactionResult::Commit(expected), TransactionResult::Commit(actual)) =
+        (&expected.result, &actual.result)
+    {
+        assert_eq!(
+            format!("{:?}", expected.outcome),
+            format!("{:?}", actual.outcome),
+            "Transaction outcomes differ"
+        );
+        assert_eq!(
+            format!("{:?}", expected.fee_summary),
+            format!("{:?}", actual.fee_summary),
+            "Fee summaries differ"
+        );
+    }
+    assert_eq!(
+        format!("{:?}", expected.result),
+        format!("{:?}", actual.result),
+        "Transaction results differ"
+    );
+    assert_eq!(
+        format!("{:?}", expected.execution_trace),
+        format!("{:?}", actual.execution_trace),
+        "Execution traces differ"
+    );
+}
+
 pub fn wat2wasm(wat: &str) -> Vec<u8> {
     wabt::wat2wasm(
         wat.replace("${memcpy}", include_str!("snippets/memcpy.wat"))
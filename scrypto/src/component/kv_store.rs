@@ -122,6 +122,46 @@ impl<
             .unwrap();
         env.sys_drop_lock(handle).unwrap();
     }
+
+    /// Removes the entry of the given key, returning the value it was associated with.
+    ///
+    /// Entries whose value owns objects (e.g. vaults) can't be removed, as the objects
+    /// would otherwise be orphaned.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let mut env = ScryptoEnv;
+        let key_payload = scrypto_encode(key).unwrap();
+        let removed = env
+            .sys_remove_key_value_entry(RENodeId::KeyValueStore(self.id), key_payload)
+            .unwrap();
+        removed.map(|value_payload| scrypto_decode(&value_payload).unwrap())
+    }
+
+    /// Returns up to `limit` entries, starting at the given key (inclusive), or at the first
+    /// entry if there is none.
+    ///
+    /// Entries are ordered by the SBOR encoding of their key, with shorter encodings first (for
+    /// encodings under 128 bytes), and the last key of a page can be used to request the next
+    /// one. `limit` can be at most
+    /// [`MAX_KEY_VALUE_STORE_SCAN_LIMIT`](radix_engine_interface::api::substate_api::MAX_KEY_VALUE_STORE_SCAN_LIMIT)
+    /// and is paid for in full.
+    /// Objects owned by the returned values can only be accessed through [`Self::get`] or
+    /// [`Self::get_mut`].
+    pub fn range(&self, start_key: Option<&K>, limit: u32) -> Vec<(K, V)> {
+        let mut env = ScryptoEnv;
+        let start_key_payload = start_key.map(|key| scrypto_encode(key).unwrap());
+        let entries = env
+            .sys_scan_key_value_store(RENodeId::KeyValueStore(self.id), start_key_payload, limit)
+            .unwrap();
+        entries
+            .into_iter()
+            .map(|(key_payload, value_payload)| {
+                (
+                    scrypto_decode(&key_payload).unwrap(),
+                    scrypto_decode(&value_payload).unwrap(),
+                )
+            })
+            .collect()
+    }
 }
 
 //========
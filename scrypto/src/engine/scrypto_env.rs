@@ -1,8 +1,10 @@
-use crate::engine::wasm_api::*;
+use crate::engine::wasm_api::{self, *};
 use radix_engine_interface::api::{types::*, ClientTransactionRuntimeApi};
 use radix_engine_interface::api::{ClientActorApi, ClientObjectApi, ClientSubstateApi};
-use radix_engine_interface::api::{ClientEventApi, ClientLoggerApi, LockFlags};
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::api::{
+    ClientCryptoUtilsApi, ClientEventApi, ClientLoggerApi, LockFlags,
+};
+use radix_engine_interface::crypto::*;
 use radix_engine_interface::data::scrypto::model::{Address, PackageAddress};
 use radix_engine_interface::data::scrypto::*;
 use radix_engine_interface::*;
@@ -221,6 +223,42 @@ impl ClientSubstateApi<ClientApiError> for ScryptoEnv {
 
         Ok(())
     }
+
+    fn sys_remove_key_value_entry(
+        &mut self,
+        node_id: RENodeId,
+        key: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, ClientApiError> {
+        let node_id = scrypto_encode(&node_id).unwrap();
+
+        let bytes = copy_buffer(unsafe {
+            remove_key_value_entry(node_id.as_ptr(), node_id.len(), key.as_ptr(), key.len())
+        });
+
+        scrypto_decode(&bytes).map_err(ClientApiError::DecodeError)
+    }
+
+    fn sys_scan_key_value_store(
+        &mut self,
+        node_id: RENodeId,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, ClientApiError> {
+        let node_id = scrypto_encode(&node_id).unwrap();
+        let start_key = scrypto_encode(&start_key).unwrap();
+
+        let bytes = copy_buffer(unsafe {
+            scan_key_value_store(
+                node_id.as_ptr(),
+                node_id.len(),
+                start_key.as_ptr(),
+                start_key.len(),
+                limit,
+            )
+        });
+
+        scrypto_decode(&bytes).map_err(ClientApiError::DecodeError)
+    }
 }
 
 impl ClientActorApi<ClientApiError> for ScryptoEnv {
@@ -271,6 +309,113 @@ impl ClientTransactionRuntimeApi<ClientApiError> for ScryptoEnv {
     }
 }
 
+impl ClientCryptoUtilsApi<ClientApiError> for ScryptoEnv {
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, ClientApiError> {
+        let hash = copy_buffer(unsafe { wasm_api::blake2b_256_hash(data.as_ptr(), data.len()) });
+
+        scrypto_decode(&hash).map_err(ClientApiError::DecodeError)
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Hash, ClientApiError> {
+        let hash = copy_buffer(unsafe { wasm_api::sha256_hash(data.as_ptr(), data.len()) });
+
+        scrypto_decode(&hash).map_err(ClientApiError::DecodeError)
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, ClientApiError> {
+        let hash = copy_buffer(unsafe { wasm_api::keccak256_hash(data.as_ptr(), data.len()) });
+
+        scrypto_decode(&hash).map_err(ClientApiError::DecodeError)
+    }
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Hash,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Result<bool, ClientApiError> {
+        let message_hash = scrypto_encode(&message_hash).unwrap();
+        let public_key = scrypto_encode(&public_key).unwrap();
+        let signature = scrypto_encode(&signature).unwrap();
+
+        let verified = unsafe {
+            verify_ecdsa_secp256k1(
+                message_hash.as_ptr(),
+                message_hash.len(),
+                public_key.as_ptr(),
+                public_key.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(verified != 0)
+    }
+
+    fn recover_ecdsa_secp256k1(
+        &mut self,
+        message_hash: Hash,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Result<Option<EcdsaSecp256k1PublicKey>, ClientApiError> {
+        let message_hash = scrypto_encode(&message_hash).unwrap();
+        let signature = scrypto_encode(&signature).unwrap();
+
+        let public_key = copy_buffer(unsafe {
+            recover_ecdsa_secp256k1(
+                message_hash.as_ptr(),
+                message_hash.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        });
+
+        scrypto_decode(&public_key).map_err(ClientApiError::DecodeError)
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EddsaEd25519PublicKey,
+        signature: EddsaEd25519Signature,
+    ) -> Result<bool, ClientApiError> {
+        let public_key = scrypto_encode(&public_key).unwrap();
+        let signature = scrypto_encode(&signature).unwrap();
+
+        let verified = unsafe {
+            verify_eddsa_ed25519(
+                message.as_ptr(),
+                message.len(),
+                public_key.as_ptr(),
+                public_key.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(verified != 0)
+    }
+
+    fn verify_bls12381_aggregate(
+        &mut self,
+        messages: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, ClientApiError> {
+        let messages = scrypto_encode(&messages).unwrap();
+        let signature = scrypto_encode(&signature).unwrap();
+
+        let verified = unsafe {
+            verify_bls12381_aggregate(
+                messages.as_ptr(),
+                messages.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(verified != 0)
+    }
+}
+
 #[macro_export]
 macro_rules! scrypto_env_native_fn {
     ($($vis:vis $fn:ident $fn_name:ident ($($args:tt)*) -> $rtn:ty { $arg:expr })*) => {
@@ -132,6 +132,23 @@ extern "C" {
     // Releases a lock
     pub fn drop_lock(handle: u32);
 
+    // Removes a key value store entry, returning the previous value
+    pub fn remove_key_value_entry(
+        node_id_ptr: *const u8,
+        node_id_len: usize,
+        key_ptr: *const u8,
+        key_len: usize,
+    ) -> Buffer;
+
+    // Returns a page of key value store entries
+    pub fn scan_key_value_store(
+        node_id_ptr: *const u8,
+        node_id_len: usize,
+        start_key_ptr: *const u8,
+        start_key_len: usize,
+        limit: u32,
+    ) -> Buffer;
+
     //===============
     // Actor API
     //===============
@@ -164,6 +181,47 @@ extern "C" {
     pub fn get_transaction_hash() -> Buffer;
 
     pub fn generate_uuid() -> Buffer;
+
+    //===============
+    // Crypto Utils API
+    //===============
+    pub fn blake2b_256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+    pub fn sha256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+    pub fn keccak256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+    pub fn verify_ecdsa_secp256k1(
+        message_hash_ptr: *const u8,
+        message_hash_len: usize,
+        public_key_ptr: *const u8,
+        public_key_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
+
+    pub fn recover_ecdsa_secp256k1(
+        message_hash_ptr: *const u8,
+        message_hash_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> Buffer;
+
+    pub fn verify_eddsa_ed25519(
+        message_ptr: *const u8,
+        message_len: usize,
+        public_key_ptr: *const u8,
+        public_key_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
+
+    pub fn verify_bls12381_aggregate(
+        messages_ptr: *const u8,
+        messages_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -296,6 +354,27 @@ pub unsafe fn drop_lock(_handle: u32) {
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn remove_key_value_entry(
+    _node_id_ptr: *const u8,
+    _node_id_len: usize,
+    _key_ptr: *const u8,
+    _key_len: usize,
+) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn scan_key_value_store(
+    _node_id_ptr: *const u8,
+    _node_id_len: usize,
+    _start_key_ptr: *const u8,
+    _start_key_len: usize,
+    _limit: u32,
+) -> Buffer {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn get_actor() -> Buffer {
     unreachable!()
@@ -330,3 +409,62 @@ pub unsafe fn get_transaction_hash() -> Buffer {
 pub unsafe fn generate_uuid() -> Buffer {
     unreachable!()
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn blake2b_256_hash(_data_ptr: *const u8, _data_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn sha256_hash(_data_ptr: *const u8, _data_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn keccak256_hash(_data_ptr: *const u8, _data_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn verify_ecdsa_secp256k1(
+    _message_hash_ptr: *const u8,
+    _message_hash_len: usize,
+    _public_key_ptr: *const u8,
+    _public_key_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn recover_ecdsa_secp256k1(
+    _message_hash_ptr: *const u8,
+    _message_hash_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn verify_eddsa_ed25519(
+    _message_ptr: *const u8,
+    _message_len: usize,
+    _public_key_ptr: *const u8,
+    _public_key_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn verify_bls12381_aggregate(
+    _messages_ptr: *const u8,
+    _messages_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
@@ -6,7 +6,7 @@ use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::constants::RESOURCE_MANAGER_PACKAGE;
 use radix_engine_interface::data::scrypto::model::*;
 use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode};
-use radix_engine_interface::math::Decimal;
+use radix_engine_interface::math::{Decimal, RoundingMode};
 use radix_engine_interface::*;
 use sbor::rust::collections::BTreeSet;
 use sbor::rust::vec::Vec;
@@ -27,6 +27,7 @@ pub trait ScryptoBucket {
     fn non_fungible_local_ids(&self) -> BTreeSet<NonFungibleLocalId>;
     fn amount(&self) -> Decimal;
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Self;
+    fn take_advanced<A: Into<Decimal>>(&mut self, amount: A, rounding_mode: RoundingMode) -> Self;
     fn take_non_fungible(&mut self, non_fungible_local_id: &NonFungibleLocalId) -> Self;
     fn is_empty(&self) -> bool;
     fn authorize<F: FnOnce() -> O, O>(&self, f: F) -> O;
@@ -173,6 +174,24 @@ impl ScryptoBucket for Bucket {
         self.take_internal(amount.into())
     }
 
+    /// Takes some amount of resources from this bucket, rounded to the divisibility of the
+    /// resource with the given rounding mode.
+    fn take_advanced<A: Into<Decimal>>(&mut self, amount: A, rounding_mode: RoundingMode) -> Self {
+        let mut env = ScryptoEnv;
+        let rtn = env
+            .call_method(
+                RENodeId::Object(self.0),
+                BUCKET_TAKE_ADVANCED_IDENT,
+                scrypto_encode(&BucketTakeAdvancedInput {
+                    amount: amount.into(),
+                    rounding_mode,
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
     /// Takes a specific non-fungible from this bucket.
     ///
     /// # Panics
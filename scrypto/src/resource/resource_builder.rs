@@ -265,6 +265,29 @@ pub trait UpdateAuthBuilder: private::CanAddAuth {
         self.add_auth(Recall, method_auth, mutability.into())
     }
 
+    /// Sets the resource to be freezable in vaults, blocking withdrawals, deposits or burns.
+    ///
+    /// * The first parameter is the access rule which allows freezing and unfreezing of vaults.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to be freezable with a proof of a specific resource, and this is locked forever.
+    /// ResourceBuilder::new_fungible()
+    ///    .freezeable(rule!(require(resource_address)), LOCKED);
+    /// ```
+    fn freezeable<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self::OutputBuilder {
+        self.add_auth(Freeze, method_auth, mutability.into())
+    }
+
     /// Sets the resource to not be freely withdrawable from a vault.
     ///
     /// * The first parameter is the access rule which allows withdrawing from a vault.
@@ -49,13 +49,10 @@ impl ResourceManager {
             .call_module_method(
                 RENodeId::GlobalObject(self.0.into()),
                 NodeModuleId::AccessRules,
-                ACCESS_RULES_SET_METHOD_ACCESS_RULE_IDENT,
-                scrypto_encode(&AccessRulesSetMethodAccessRuleInput {
-                    key: MethodKey::new(
-                        NodeModuleId::SELF,
-                        RESOURCE_MANAGER_BURN_IDENT.to_string(),
-                    ),
-                    rule: AccessRuleEntry::AccessRule(access_rule),
+                ACCESS_RULES_SET_GROUP_ACCESS_RULE_IDENT,
+                scrypto_encode(&AccessRulesSetGroupAccessRuleInput {
+                    name: "burn".to_string(),
+                    rule: access_rule,
                 })
                 .unwrap(),
             )
@@ -160,12 +157,9 @@ impl ResourceManager {
             .call_module_method(
                 RENodeId::GlobalObject(self.0.into()),
                 NodeModuleId::AccessRules,
-                ACCESS_RULES_SET_METHOD_MUTABILITY_IDENT,
-                scrypto_encode(&AccessRulesSetMethodMutabilityInput {
-                    key: MethodKey::new(
-                        NodeModuleId::SELF,
-                        RESOURCE_MANAGER_BURN_IDENT.to_string(),
-                    ),
+                ACCESS_RULES_SET_GROUP_MUTABILITY_IDENT,
+                scrypto_encode(&AccessRulesSetGroupMutabilityInput {
+                    name: "burn".to_string(),
                     mutability: AccessRule::DenyAll,
                 })
                 .unwrap(),
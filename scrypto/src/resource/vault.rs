@@ -3,7 +3,7 @@ use radix_engine_interface::api::ClientObjectApi;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::data::scrypto::model::*;
 use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode};
-use radix_engine_interface::math::Decimal;
+use radix_engine_interface::math::{Decimal, RoundingMode};
 use radix_engine_interface::*;
 use sbor::rust::collections::BTreeSet;
 use sbor::rust::vec::Vec;
@@ -32,7 +32,11 @@ pub trait ScryptoVault {
     fn lock_fee<A: Into<Decimal>>(&mut self, amount: A);
     fn lock_contingent_fee<A: Into<Decimal>>(&mut self, amount: A);
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Bucket;
+    fn take_advanced<A: Into<Decimal>>(&mut self, amount: A, rounding_mode: RoundingMode)
+        -> Bucket;
     fn take_all(&mut self) -> Bucket;
+    fn burn<A: Into<Decimal>>(&mut self, amount: A);
+    fn burn_non_fungibles(&mut self, non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>);
     fn take_non_fungible(&mut self, non_fungible_local_id: &NonFungibleLocalId) -> Bucket;
     fn authorize<F: FnOnce() -> O, O>(&self, f: F) -> O;
     fn is_empty(&self) -> bool;
@@ -226,11 +230,68 @@ impl ScryptoVault for Vault {
         Bucket(bucket.0)
     }
 
+    /// Takes some amount of resource from this vault into a bucket, rounded to the divisibility
+    /// of the resource with the given rounding mode.
+    fn take_advanced<A: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        rounding_mode: RoundingMode,
+    ) -> Bucket {
+        let mut env = ScryptoEnv;
+        let rtn = env
+            .call_method(
+                RENodeId::Object(self.0),
+                VAULT_TAKE_ADVANCED_IDENT,
+                scrypto_encode(&VaultTakeAdvancedInput {
+                    amount: amount.into(),
+                    rounding_mode,
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
     /// Takes all resource stored in this vault.
     fn take_all(&mut self) -> Bucket {
         self.take(self.amount())
     }
 
+    /// Burns some amount of resource directly from this vault.
+    fn burn<A: Into<Decimal>>(&mut self, amount: A) {
+        let mut env = ScryptoEnv;
+        let rtn = env
+            .call_method(
+                RENodeId::Object(self.0),
+                VAULT_BURN_IDENT,
+                scrypto_encode(&VaultBurnInput {
+                    amount: amount.into(),
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
+    /// Burns the specified non-fungibles directly from this vault.
+    ///
+    /// # Panics
+    /// Panics if this is not a non-fungible vault or any of the non-fungibles is not found.
+    fn burn_non_fungibles(&mut self, non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>) {
+        let mut env = ScryptoEnv;
+        let rtn = env
+            .call_method(
+                RENodeId::Object(self.0),
+                VAULT_BURN_NON_FUNGIBLES_IDENT,
+                scrypto_encode(&VaultBurnNonFungiblesInput {
+                    non_fungible_local_ids: non_fungible_local_ids.clone(),
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
     /// Takes a specific non-fungible from this vault.
     ///
     /// # Panics
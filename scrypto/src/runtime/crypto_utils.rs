@@ -0,0 +1,68 @@
+use radix_engine_interface::api::ClientCryptoUtilsApi;
+use radix_engine_interface::crypto::*;
+use sbor::rust::vec::Vec;
+
+use crate::engine::scrypto_env::ScryptoEnv;
+
+/// A utility for cryptographic operations, which are computed natively by the engine.
+#[derive(Debug)]
+pub struct CryptoUtils {}
+
+impl CryptoUtils {
+    /// Computes the Blake2b-256 hash of the data.
+    pub fn blake2b_256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoEnv.blake2b_256_hash(data.as_ref().to_vec()).unwrap()
+    }
+
+    /// Computes the SHA-256 hash of the data.
+    pub fn sha256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoEnv.sha256_hash(data.as_ref().to_vec()).unwrap()
+    }
+
+    /// Computes the Keccak-256 hash of the data.
+    pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoEnv.keccak256_hash(data.as_ref().to_vec()).unwrap()
+    }
+
+    /// Verifies an ECDSA secp256k1 signature of a message hash.
+    pub fn verify_ecdsa_secp256k1(
+        message_hash: Hash,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: EcdsaSecp256k1Signature,
+    ) -> bool {
+        ScryptoEnv
+            .verify_ecdsa_secp256k1(message_hash, public_key, signature)
+            .unwrap()
+    }
+
+    /// Recovers the public key from an ECDSA secp256k1 signature of a message hash.
+    pub fn recover_ecdsa_secp256k1(
+        message_hash: Hash,
+        signature: EcdsaSecp256k1Signature,
+    ) -> Option<EcdsaSecp256k1PublicKey> {
+        ScryptoEnv
+            .recover_ecdsa_secp256k1(message_hash, signature)
+            .unwrap()
+    }
+
+    /// Verifies an EdDSA Ed25519 signature of a message.
+    pub fn verify_eddsa_ed25519<T: AsRef<[u8]>>(
+        message: T,
+        public_key: EddsaEd25519PublicKey,
+        signature: EddsaEd25519Signature,
+    ) -> bool {
+        ScryptoEnv
+            .verify_eddsa_ed25519(message.as_ref().to_vec(), public_key, signature)
+            .unwrap()
+    }
+
+    /// Verifies a BLS12-381 aggregate signature of each message by its public key.
+    pub fn verify_bls12381_aggregate(
+        messages: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Bls12381G2Signature,
+    ) -> bool {
+        ScryptoEnv
+            .verify_bls12381_aggregate(messages, signature)
+            .unwrap()
+    }
+}
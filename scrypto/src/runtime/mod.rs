@@ -1,9 +1,11 @@
 mod clock;
+mod crypto_utils;
 mod data;
 mod logger;
 mod runtime;
 
 pub use clock::*;
+pub use crypto_utils::CryptoUtils;
 pub use data::*;
 pub use logger::Logger;
 pub use radix_engine_interface::data::scrypto::model::*;
@@ -1,5 +1,7 @@
 /// Provides a file-based ledger.
 pub mod ledger;
+/// Radix transaction replay CLI.
+pub mod replay;
 /// Radix Engine Simulator CLI.
 pub mod resim;
 /// Radix transaction manifest compiler CLI.
@@ -0,0 +1,91 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::kernel::interpreters::ScryptoInterpreter;
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::system::kernel_modules::costing::{FeeTable, FeeTableLoadError};
+use radix_engine::transaction::{
+    ExecutionConfig, FeeReserveConfig, RecordedTransaction, ReplayDivergence, TransactionReplayer,
+};
+use radix_engine::types::*;
+use radix_engine::wasm::DefaultWasmEngine;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Radix transaction replay tool
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "replay")]
+pub struct Args {
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    trace: bool,
+
+    /// Fee table file in JSON format, to replay the transactions under a different fee schedule
+    #[clap(long)]
+    fee_table: Option<PathBuf>,
+
+    /// Input file, containing the SBOR-encoded recorded transactions
+    #[clap(required = true)]
+    input: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IoError(std::io::Error),
+    DecodeError(sbor::DecodeError),
+    ParseNetworkError(ParseNetworkError),
+    FeeTableLoadError(FeeTableLoadError),
+    Divergence(ReplayDivergence),
+}
+
+/// Replays the recorded transactions against a freshly bootstrapped in-memory ledger, and reports
+/// the first transaction whose receipt differs from the recorded one.
+pub fn run() -> Result<(), Error> {
+    let args = Args::parse();
+
+    let network = match args.network {
+        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+        None => NetworkDefinition::simulator(),
+    };
+    let content = std::fs::read(&args.input).map_err(Error::IoError)?;
+    let transactions: Vec<RecordedTransaction> =
+        scrypto_decode(&content).map_err(Error::DecodeError)?;
+
+    let fee_table = match args.fee_table {
+        Some(path) => {
+            let json = std::fs::read_to_string(path).map_err(Error::IoError)?;
+            FeeTable::from_json(&json).map_err(Error::FeeTableLoadError)?
+        }
+        None => FeeTable::new(),
+    };
+
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
+    let mut replayer =
+        TransactionReplayer::new(&mut substate_store, &scrypto_interpreter, &network)
+            .with_fee_reserve_config(FeeReserveConfig::standard().with_fee_table(fee_table))
+            .with_execution_config(ExecutionConfig::standard().with_trace(args.trace));
+
+    match replayer.replay_all(&transactions) {
+        Ok(count) => {
+            println!(
+                "{} {} transaction(s) replayed, no divergence found",
+                "Success!".green().bold(),
+                count
+            );
+            Ok(())
+        }
+        Err(divergence) => {
+            println!(
+                "{} transaction #{} diverged: {:?}",
+                "Divergence!".red().bold(),
+                divergence.index,
+                divergence.reason
+            );
+            Err(Error::Divergence(divergence))
+        }
+    }
+}
@@ -61,7 +61,7 @@ impl Publish {
         .map_err(Error::SborDecodeError)?;
 
         if let Some(package_address) = self.package_address.clone() {
-            let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+            let scrypto_interpreter = get_scrypto_interpreter();
             let mut substate_store =
                 RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
 
@@ -26,6 +26,10 @@ pub struct Run {
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    /// Write the execution cost as folded stacks to this file, for flamegraph tooling
+    #[clap(long)]
+    pub profile: Option<PathBuf>,
 }
 
 impl Run {
@@ -53,12 +57,11 @@ impl Run {
         let compiled_manifest =
             transaction::manifest::compile(&pre_processed_manifest, &network, blobs)
                 .map_err(Error::CompileError)?;
-        handle_manifest(
+        execute_manifest(
             compiled_manifest,
             &self.signing_keys,
-            &self.network,
-            &None,
             self.trace,
+            &self.profile,
             true,
             out,
         )
@@ -18,7 +18,7 @@ pub struct ShowLedger {}
 
 impl ShowLedger {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+        let scrypto_interpreter = get_scrypto_interpreter();
         let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
         let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
 
@@ -59,6 +59,15 @@ impl ShowLedger {
             .map_err(Error::IOError)?;
         }
 
+        writeln!(
+            out,
+            "{}: {} (version {})",
+            "State Hash".green().bold(),
+            substate_store.get_state_hash(),
+            substate_store.get_state_version()
+        )
+        .map_err(Error::IOError)?;
+
         // Close the database
         drop(substate_store);
 
@@ -30,6 +30,20 @@ pub fn get_data_dir() -> Result<PathBuf, Error> {
     Ok(path)
 }
 
+/// Returns the interpreter for running packages, which caches instrumented code on disk
+/// when `WASM_DISK_CACHE_DIR` is set.
+pub fn get_scrypto_interpreter() -> ScryptoInterpreter<DefaultWasmEngine> {
+    let mut instrumenter_options = InstrumenterOptions::default();
+    if let Ok(dir) = env::var(ENV_WASM_DISK_CACHE_DIR) {
+        instrumenter_options = instrumenter_options.with_disk_cache_dir(dir);
+    }
+    ScryptoInterpreter {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_instrumenter: WasmInstrumenter::new(instrumenter_options),
+        wasm_metering_config: WasmMeteringConfig::default(),
+    }
+}
+
 pub fn get_configs_path() -> Result<PathBuf, Error> {
     let mut path = get_data_dir()?;
     path.push("config");
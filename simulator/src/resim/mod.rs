@@ -51,12 +51,13 @@ pub use error::*;
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
+pub const ENV_WASM_DISK_CACHE_DIR: &'static str = "WASM_DISK_CACHE_DIR";
 
 use clap::{Parser, Subcommand};
 use radix_engine::kernel::interpreters::ScryptoInterpreter;
 use radix_engine::ledger::ReadableSubstateStore;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
-use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::execute_transaction;
 use radix_engine::transaction::TransactionOutcome;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::transaction::TransactionReceiptDisplayContextBuilder;
@@ -162,7 +163,7 @@ pub fn handle_system_transaction<O: std::io::Write>(
     print_receipt: bool,
     out: &mut O,
 ) -> Result<TransactionReceipt, Error> {
-    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let scrypto_interpreter = get_scrypto_interpreter();
     let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
 
     let nonce = get_nonce()?;
@@ -173,13 +174,16 @@ pub fn handle_system_transaction<O: std::io::Write>(
         pre_allocated_ids: BTreeSet::new(),
     };
 
-    let receipt = execute_and_commit_transaction(
-        &mut substate_store,
+    let receipt = execute_transaction(
+        &substate_store,
         &scrypto_interpreter,
         &FeeReserveConfig::default(),
         &ExecutionConfig::standard().with_trace(trace),
         &transaction.get_executable(initial_proofs),
     );
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        substate_store.commit(&commit.state_updates);
+    }
 
     if print_receipt {
         let encoder = Bech32Encoder::for_simulator();
@@ -227,42 +231,67 @@ pub fn handle_manifest<O: std::io::Write>(
             }
             Ok(None)
         }
-        None => {
-            let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
-            let mut substate_store =
-                RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
-
-            let sks = get_signing_keys(signing_keys)?;
-            let initial_proofs = sks
-                .into_iter()
-                .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
-                .collect::<Vec<NonFungibleGlobalId>>();
-            let nonce = get_nonce()?;
-            let transaction = TestTransaction::new(manifest, nonce, DEFAULT_COST_UNIT_LIMIT);
-
-            let receipt = execute_and_commit_transaction(
-                &mut substate_store,
-                &scrypto_interpreter,
-                &FeeReserveConfig::default(),
-                &ExecutionConfig::standard().with_trace(trace),
-                &transaction.get_executable(initial_proofs),
-            );
-
-            if print_receipt {
-                let encoder = Bech32Encoder::for_simulator();
-                let display_context = TransactionReceiptDisplayContextBuilder::new()
-                    .encoder(&encoder)
-                    .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
-                        get_event_schema(&substate_store, event_type_identifier)
-                    })
-                    .build();
-                writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
-            }
-            drop(substate_store);
+        None => execute_manifest(manifest, signing_keys, trace, &None, print_receipt, out)
+            .map(Option::Some),
+    }
+}
 
-            process_receipt(receipt).map(Option::Some)
-        }
+/// Executes the manifest against the ledger, and commits the result.
+///
+/// If a `profile` path is given, the execution cost of the transaction is written to it as folded
+/// stacks, for flamegraph tooling.
+pub fn execute_manifest<O: std::io::Write>(
+    manifest: TransactionManifest,
+    signing_keys: &Option<String>,
+    trace: bool,
+    profile: &Option<PathBuf>,
+    print_receipt: bool,
+    out: &mut O,
+) -> Result<TransactionReceipt, Error> {
+    let scrypto_interpreter = get_scrypto_interpreter();
+    let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+
+    let sks = get_signing_keys(signing_keys)?;
+    let initial_proofs = sks
+        .into_iter()
+        .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
+        .collect::<Vec<NonFungibleGlobalId>>();
+    let nonce = get_nonce()?;
+    let transaction = TestTransaction::new(manifest, nonce, DEFAULT_COST_UNIT_LIMIT);
+
+    let receipt = execute_transaction(
+        &substate_store,
+        &scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::standard().with_trace(trace),
+        &transaction.get_executable(initial_proofs),
+    );
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        substate_store.commit(&commit.state_updates);
+    }
+
+    if let (Some(path), TransactionResult::Commit(commit)) = (profile, &receipt.result) {
+        let encoder = Bech32Encoder::for_simulator();
+        let folded_stacks = commit
+            .fee_summary
+            .execution_cost_tree
+            .to_folded_stacks(&encoder);
+        fs::write(path, folded_stacks).map_err(Error::IOError)?;
     }
+
+    if print_receipt {
+        let encoder = Bech32Encoder::for_simulator();
+        let display_context = TransactionReceiptDisplayContextBuilder::new()
+            .encoder(&encoder)
+            .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
+                get_event_schema(&substate_store, event_type_identifier)
+            })
+            .build();
+        writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
+    }
+    drop(substate_store);
+
+    process_receipt(receipt)
 }
 
 pub fn process_receipt(receipt: TransactionReceipt) -> Result<TransactionReceipt, Error> {
@@ -308,7 +337,7 @@ pub fn get_signing_keys(
 }
 
 pub fn export_package_schema(package_address: PackageAddress) -> Result<PackageSchema, Error> {
-    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let scrypto_interpreter = get_scrypto_interpreter();
     let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
 
     let output = substate_store
@@ -341,7 +370,7 @@ pub fn export_blueprint_schema(
 pub fn get_blueprint(
     component_address: ComponentAddress,
 ) -> Result<(PackageAddress, String), Error> {
-    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+    let scrypto_interpreter = get_scrypto_interpreter();
     let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
 
     let output = substate_store
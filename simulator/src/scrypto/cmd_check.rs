@@ -0,0 +1,85 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use radix_engine::wasm::{WasmLimitUsage, WasmValidator, WasmValidatorConfig};
+use std::env::current_dir;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// Check a Scrypto package against the WASM validation rules
+#[derive(Parser, Debug)]
+pub struct Check {
+    /// The package directory
+    #[clap(long)]
+    path: Option<PathBuf>,
+
+    /// Validator config file in JSON format, to check against limits other than the default ones
+    #[clap(long)]
+    config: Option<PathBuf>,
+}
+
+impl Check {
+    pub fn run(&self) -> Result<(), Error> {
+        let config = match &self.config {
+            Some(path) => {
+                let json = fs::read_to_string(path).map_err(Error::IOError)?;
+                WasmValidatorConfig::from_json(&json)
+                    .map_err(Error::WasmValidatorConfigLoadError)?
+            }
+            None => WasmValidatorConfig::default(),
+        };
+
+        let (wasm_path, schema_path) = build_package(
+            self.path.clone().unwrap_or(current_dir().unwrap()),
+            false,
+            false,
+        )
+        .map_err(Error::BuildError)?;
+
+        let code = fs::read(&wasm_path).map_err(Error::IOError)?;
+        let schema = scrypto_decode(&fs::read(&schema_path).map_err(Error::IOError)?)
+            .map_err(Error::SborDecodeError)?;
+
+        let report = WasmValidator::new(config)
+            .validate_with_report(&code, &schema)
+            .map_err(Error::PrepareError)?;
+
+        print_limit_usage("Initial memory pages", &report.initial_memory_size_pages);
+        print_limit_usage("Initial table size", &report.initial_table_size);
+        print_limit_usage("Br table targets", &report.number_of_br_table_targets);
+        print_limit_usage("Functions", &report.number_of_functions);
+        print_limit_usage("Globals", &report.number_of_globals);
+        print_limit_usage("Imports", &report.number_of_imports);
+
+        if report.is_valid() {
+            println!("{}", "Package is valid".green().bold());
+            Ok(())
+        } else {
+            println!("{}", "Violations:".red().bold());
+            for (i, violation) in report.violations.iter().enumerate() {
+                println!(
+                    "{} {:?}",
+                    list_item_prefix(i == report.violations.len() - 1),
+                    violation
+                );
+            }
+            Err(Error::InvalidPackage(report.violations))
+        }
+    }
+}
+
+fn print_limit_usage(name: &str, usage: &WasmLimitUsage) {
+    let value = format!("{} / {}", usage.used, usage.limit);
+    println!(
+        "{}: {}",
+        name.green().bold(),
+        if usage.is_exceeded() {
+            value.red()
+        } else {
+            value.normal()
+        }
+    );
+}
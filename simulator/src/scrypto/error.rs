@@ -1,5 +1,8 @@
 use std::io;
 
+use radix_engine::wasm::{PrepareError, WasmValidatorConfigLoadError};
+use sbor::*;
+
 use crate::utils::*;
 
 #[derive(Debug)]
@@ -13,4 +16,12 @@ pub enum Error {
     FormatError(FormatError),
 
     PackageAlreadyExists,
+
+    SborDecodeError(DecodeError),
+
+    PrepareError(PrepareError),
+
+    InvalidPackage(Vec<PrepareError>),
+
+    WasmValidatorConfigLoadError(WasmValidatorConfigLoadError),
 }
@@ -21,4 +21,8 @@ export resource=`echo $output | cut -d " " -f2`
 
 $resim run ./tests/m2.rtm
 
+# Test - profile the execution cost as folded stacks
+$resim run ./tests/m2.rtm --profile ./target/m2.folded
+grep -q "Transaction;TransactionProcessor::run" ./target/m2.folded
+
 $resim show-ledger
\ No newline at end of file
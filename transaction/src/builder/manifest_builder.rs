@@ -69,7 +69,8 @@ impl ManifestBuilder {
         match &inst {
             Instruction::TakeFromWorktop { .. }
             | Instruction::TakeFromWorktopByAmount { .. }
-            | Instruction::TakeFromWorktopByIds { .. } => {
+            | Instruction::TakeFromWorktopByIds { .. }
+            | Instruction::TakeFromWorktopAdvanced { .. } => {
                 new_bucket_id = Some(self.id_allocator.new_bucket_id().unwrap());
             }
             Instruction::PopFromAuthZone { .. }
@@ -115,6 +116,25 @@ impl ManifestBuilder {
         then(builder, bucket_id.unwrap())
     }
 
+    /// Takes resource from worktop, by amount rounded to the divisibility of the resource.
+    pub fn take_from_worktop_advanced<F>(
+        &mut self,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+        rounding_mode: RoundingMode,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, ManifestBucket) -> &mut Self,
+    {
+        let (builder, bucket_id, _) = self.add_instruction(Instruction::TakeFromWorktopAdvanced {
+            amount,
+            resource_address,
+            rounding_mode,
+        });
+        then(builder, bucket_id.unwrap())
+    }
+
     /// Takes resource from worktop, by non-fungible ids.
     pub fn take_from_worktop_by_ids<F>(
         &mut self,
@@ -749,11 +769,35 @@ impl ManifestBuilder {
         self
     }
 
+    /// Burns non-fungibles by id through their resource manager, which requires the resource's
+    /// burn rule.
+    pub fn burn_non_fungibles(
+        &mut self,
+        resource_address: ResourceAddress,
+        ids: BTreeSet<NonFungibleLocalId>,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::BurnNonFungibles {
+            resource_address,
+            ids,
+        });
+        self
+    }
+
     pub fn recall(&mut self, vault_id: ObjectId, amount: Decimal) -> &mut Self {
         self.add_instruction(Instruction::RecallResource { vault_id, amount });
         self
     }
 
+    pub fn freeze_vault(&mut self, vault_id: ObjectId, flags: VaultFreezeFlags) -> &mut Self {
+        self.add_instruction(Instruction::FreezeVault { vault_id, flags });
+        self
+    }
+
+    pub fn unfreeze_vault(&mut self, vault_id: ObjectId, flags: VaultFreezeFlags) -> &mut Self {
+        self.add_instruction(Instruction::UnfreezeVault { vault_id, flags });
+        self
+    }
+
     pub fn burn_non_fungible(&mut self, non_fungible_global_id: NonFungibleGlobalId) -> &mut Self {
         let mut ids = BTreeSet::new();
         ids.insert(non_fungible_global_id.local_id().clone());
@@ -19,6 +19,13 @@ pub enum Instruction {
         new_bucket: Value,
     },
 
+    TakeFromWorktopAdvanced {
+        amount: Value,
+        resource_address: Value,
+        rounding_mode: Value,
+        new_bucket: Value,
+    },
+
     ReturnToWorktop {
         bucket: Value,
     },
@@ -107,12 +114,27 @@ pub enum Instruction {
         bucket: Value,
     },
 
+    BurnNonFungibles {
+        resource_address: Value,
+        ids: Value,
+    },
+
     // TODO: Dedicated bucket for this?
     RecallResource {
         vault_id: Value,
         amount: Value,
     },
 
+    FreezeVault {
+        vault_id: Value,
+        flags: Value,
+    },
+
+    UnfreezeVault {
+        vault_id: Value,
+        flags: Value,
+    },
+
     SetMetadata {
         entity_address: Value,
         key: Value,
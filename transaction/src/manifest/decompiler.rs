@@ -167,6 +167,26 @@ pub fn decompile_instruction<F: fmt::Write>(
                 name
             )?;
         }
+        Instruction::TakeFromWorktopAdvanced {
+            amount,
+            resource_address,
+            rounding_mode,
+        } => {
+            let bucket_id = context
+                .id_allocator
+                .new_bucket_id()
+                .map_err(DecompileError::IdAllocationError)?;
+            let name = format!("bucket{}", context.bucket_names.len() + 1);
+            context.bucket_names.insert(bucket_id, name.clone());
+            write!(
+                f,
+                "TAKE_FROM_WORKTOP_ADVANCED\n    Decimal(\"{}\")\n    Address(\"{}\")",
+                amount,
+                resource_address.display(context.bech32_encoder),
+            )?;
+            format_typed_value(f, context, rounding_mode)?;
+            write!(f, "\n    Bucket(\"{}\");", name)?;
+        }
         Instruction::ReturnToWorktop { bucket_id } => {
             write!(
                 f,
@@ -452,12 +472,33 @@ pub fn decompile_instruction<F: fmt::Write>(
                     .unwrap_or(format!("{}u32", bucket_id.0)),
             )?;
         }
+        Instruction::BurnNonFungibles {
+            resource_address,
+            ids,
+        } => {
+            f.write_str("BURN_NON_FUNGIBLES")?;
+            format_typed_value(f, context, resource_address)?;
+            format_typed_value(f, context, ids)?;
+            f.write_str(";")?;
+        }
         Instruction::RecallResource { vault_id, amount } => {
             f.write_str("RECALL_RESOURCE")?;
             format_typed_value(f, context, vault_id)?;
             format_typed_value(f, context, amount)?;
             f.write_str(";")?;
         }
+        Instruction::FreezeVault { vault_id, flags } => {
+            f.write_str("FREEZE_VAULT")?;
+            format_typed_value(f, context, vault_id)?;
+            format_typed_value(f, context, flags)?;
+            f.write_str(";")?;
+        }
+        Instruction::UnfreezeVault { vault_id, flags } => {
+            f.write_str("UNFREEZE_VAULT")?;
+            format_typed_value(f, context, vault_id)?;
+            format_typed_value(f, context, flags)?;
+            f.write_str(";")?;
+        }
         Instruction::SetMetadata {
             entity_address,
             key,
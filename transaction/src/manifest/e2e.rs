@@ -73,6 +73,11 @@ TAKE_FROM_WORKTOP_BY_IDS
     Array<NonFungibleLocalId>(NonFungibleLocalId("#1#"))
     Address("resource_sim1qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqs6d89k")
     Bucket("bucket3");
+TAKE_FROM_WORKTOP_ADVANCED
+    Decimal("1.5")
+    Address("resource_sim1qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqs6d89k")
+    Enum(2u8)
+    Bucket("bucket4");
 CALL_METHOD
     Address("account_sim1qjy5fakwygc45fkyhyxxulsf5zfae0ycez0x05et9hqs7d0gtn")
     "deposit_batch"
@@ -158,6 +163,39 @@ RECALL_RESOURCE
         );
     }
 
+    #[test]
+    fn test_vault_freeze() {
+        compile_and_decompile_with_inversion_test(
+            "vault_freeze",
+            include_str!("../../examples/resources/freeze.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+            r##"
+FREEZE_VAULT
+    Bytes("62b2c217e32e5b4754c08219ef16389761356eaccbf6f6bdbfa44d00000000")
+    Tuple(3u32);
+UNFREEZE_VAULT
+    Bytes("62b2c217e32e5b4754c08219ef16389761356eaccbf6f6bdbfa44d00000000")
+    Tuple(1u32);
+"##,
+        );
+    }
+
+    #[test]
+    fn test_burn_non_fungibles() {
+        compile_and_decompile_with_inversion_test(
+            "burn_non_fungibles",
+            include_str!("../../examples/resources/burn_non_fungibles.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+            r##"
+BURN_NON_FUNGIBLES
+    Address("resource_sim1qxntya3nlyju8zsj8h86fz8ma5yl8smwjlg9tckkqvrsxhzgyn")
+    Array<NonFungibleLocalId>(NonFungibleLocalId("#1#"), NonFungibleLocalId("#2#"));
+"##,
+        );
+    }
+
     #[test]
     fn test_call_function() {
         compile_and_decompile_with_inversion_test(
@@ -101,6 +101,7 @@ lazy_static! {
                 Withdraw = 4;
                 Deposit = 5;
                 Recall = 6;
+                Freeze = 7;
             }
         );
 
@@ -114,6 +115,18 @@ lazy_static! {
             }
         );
 
+        known_enum!(
+            m,
+            enum RoundingMode {
+                TowardsPositiveInfinity = 0;
+                TowardsNegativeInfinity = 1;
+                TowardsZero = 2;
+                AwayFromZero = 3;
+                TowardsNearestAndHalfTowardsZero = 4;
+                TowardsNearestAndHalfAwayFromZero = 5;
+            }
+        );
+
         m
     };
 }
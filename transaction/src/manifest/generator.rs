@@ -235,6 +235,28 @@ pub fn generate_instruction(
                 resource_address: generate_resource_address(resource_address, bech32_decoder)?,
             }
         }
+        ast::Instruction::TakeFromWorktopAdvanced {
+            amount,
+            resource_address,
+            rounding_mode,
+            new_bucket,
+        } => {
+            let bucket_id = id_validator
+                .new_bucket()
+                .map_err(GeneratorError::IdValidationError)?;
+            declare_bucket(new_bucket, resolver, bucket_id)?;
+
+            Instruction::TakeFromWorktopAdvanced {
+                amount: generate_decimal(amount)?,
+                resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+                rounding_mode: generate_typed_value(
+                    rounding_mode,
+                    resolver,
+                    bech32_decoder,
+                    blobs,
+                )?,
+            }
+        }
         ast::Instruction::ReturnToWorktop { bucket } => {
             let bucket_id = generate_bucket(bucket, resolver)?;
             id_validator
@@ -419,10 +441,25 @@ pub fn generate_instruction(
                 .map_err(GeneratorError::IdValidationError)?;
             Instruction::BurnResource { bucket_id }
         }
+        ast::Instruction::BurnNonFungibles {
+            resource_address,
+            ids,
+        } => Instruction::BurnNonFungibles {
+            resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+            ids: generate_non_fungible_local_ids(ids)?,
+        },
         ast::Instruction::RecallResource { vault_id, amount } => Instruction::RecallResource {
             vault_id: generate_typed_value(vault_id, resolver, bech32_decoder, blobs)?,
             amount: generate_decimal(amount)?,
         },
+        ast::Instruction::FreezeVault { vault_id, flags } => Instruction::FreezeVault {
+            vault_id: generate_typed_value(vault_id, resolver, bech32_decoder, blobs)?,
+            flags: generate_typed_value(flags, resolver, bech32_decoder, blobs)?,
+        },
+        ast::Instruction::UnfreezeVault { vault_id, flags } => Instruction::UnfreezeVault {
+            vault_id: generate_typed_value(vault_id, resolver, bech32_decoder, blobs)?,
+            flags: generate_typed_value(flags, resolver, bech32_decoder, blobs)?,
+        },
         ast::Instruction::SetMetadata {
             entity_address,
             key,
@@ -1169,6 +1206,7 @@ mod tests {
         NonFungibleResourceManagerMintManifestInput,
         NonFungibleResourceManagerMintUuidManifestInput, ResourceMethodAuthKey,
     };
+    use radix_engine_interface::math::RoundingMode;
     use radix_engine_interface::network::NetworkDefinition;
     use radix_engine_interface::{dec, pdec, ScryptoSbor};
 
@@ -1345,6 +1383,14 @@ mod tests {
                 resource_address: resource,
             },
         );
+        generate_instruction_ok!(
+            r#"TAKE_FROM_WORKTOP_ADVANCED  Decimal("1.5")  Address("resource_sim1qxntya3nlyju8zsj8h86fz8ma5yl8smwjlg9tckkqvrsxhzgyn")  Enum("RoundingMode::TowardsZero")  Bucket("xrd_bucket");"#,
+            Instruction::TakeFromWorktopAdvanced {
+                amount: dec!("1.5"),
+                resource_address: resource,
+                rounding_mode: RoundingMode::TowardsZero,
+            },
+        );
         generate_instruction_ok!(
             r#"TAKE_FROM_WORKTOP  Address("resource_sim1qxntya3nlyju8zsj8h86fz8ma5yl8smwjlg9tckkqvrsxhzgyn")  Bucket("xrd_bucket");"#,
             Instruction::TakeFromWorktop {
@@ -1386,6 +1432,16 @@ mod tests {
                 amount: dec!("100")
             },
         );
+        generate_instruction_ok!(
+            r##"BURN_NON_FUNGIBLES Address("resource_sim1qxntya3nlyju8zsj8h86fz8ma5yl8smwjlg9tckkqvrsxhzgyn") Array<NonFungibleLocalId>(NonFungibleLocalId("#1#"), NonFungibleLocalId("#2#"));"##,
+            Instruction::BurnNonFungibles {
+                resource_address: resource,
+                ids: BTreeSet::from([
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::integer(2)
+                ]),
+            },
+        );
     }
 
     #[test]
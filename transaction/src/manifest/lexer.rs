@@ -82,6 +82,7 @@ pub enum TokenKind {
     TakeFromWorktop,
     TakeFromWorktopByAmount,
     TakeFromWorktopByIds,
+    TakeFromWorktopAdvanced,
     ReturnToWorktop,
     AssertWorktopContains,
     AssertWorktopContainsByAmount,
@@ -101,7 +102,10 @@ pub enum TokenKind {
     CallMethod,
     PublishPackage,
     BurnResource,
+    BurnNonFungibles,
     RecallResource,
+    FreezeVault,
+    UnfreezeVault,
     SetMetadata,
     RemoveMetadata,
     SetPackageRoyaltyConfig,
@@ -414,6 +418,7 @@ impl Lexer {
             "TAKE_FROM_WORKTOP" => Ok(TokenKind::TakeFromWorktop),
             "TAKE_FROM_WORKTOP_BY_AMOUNT" => Ok(TokenKind::TakeFromWorktopByAmount),
             "TAKE_FROM_WORKTOP_BY_IDS" => Ok(TokenKind::TakeFromWorktopByIds),
+            "TAKE_FROM_WORKTOP_ADVANCED" => Ok(TokenKind::TakeFromWorktopAdvanced),
             "RETURN_TO_WORKTOP" => Ok(TokenKind::ReturnToWorktop),
             "ASSERT_WORKTOP_CONTAINS" => Ok(TokenKind::AssertWorktopContains),
             "ASSERT_WORKTOP_CONTAINS_BY_AMOUNT" => Ok(TokenKind::AssertWorktopContainsByAmount),
@@ -435,7 +440,10 @@ impl Lexer {
             "CALL_METHOD" => Ok(TokenKind::CallMethod),
             "PUBLISH_PACKAGE" => Ok(TokenKind::PublishPackage),
             "BURN_RESOURCE" => Ok(TokenKind::BurnResource),
+            "BURN_NON_FUNGIBLES" => Ok(TokenKind::BurnNonFungibles),
             "RECALL_RESOURCE" => Ok(TokenKind::RecallResource),
+            "FREEZE_VAULT" => Ok(TokenKind::FreezeVault),
+            "UNFREEZE_VAULT" => Ok(TokenKind::UnfreezeVault),
             "SET_METADATA" => Ok(TokenKind::SetMetadata),
             "REMOVE_METADATA" => Ok(TokenKind::RemoveMetadata),
             "SET_PACKAGE_ROYALTY_CONFIG" => Ok(TokenKind::SetPackageRoyaltyConfig),
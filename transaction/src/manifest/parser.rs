@@ -86,6 +86,12 @@ impl Parser {
                 resource_address: self.parse_value()?,
                 new_bucket: self.parse_value()?,
             },
+            TokenKind::TakeFromWorktopAdvanced => Instruction::TakeFromWorktopAdvanced {
+                amount: self.parse_value()?,
+                resource_address: self.parse_value()?,
+                rounding_mode: self.parse_value()?,
+                new_bucket: self.parse_value()?,
+            },
             TokenKind::ReturnToWorktop => Instruction::ReturnToWorktop {
                 bucket: self.parse_value()?,
             },
@@ -172,10 +178,22 @@ impl Parser {
             TokenKind::BurnResource => Instruction::BurnResource {
                 bucket: self.parse_value()?,
             },
+            TokenKind::BurnNonFungibles => Instruction::BurnNonFungibles {
+                resource_address: self.parse_value()?,
+                ids: self.parse_value()?,
+            },
             TokenKind::RecallResource => Instruction::RecallResource {
                 vault_id: self.parse_value()?,
                 amount: self.parse_value()?,
             },
+            TokenKind::FreezeVault => Instruction::FreezeVault {
+                vault_id: self.parse_value()?,
+                flags: self.parse_value()?,
+            },
+            TokenKind::UnfreezeVault => Instruction::UnfreezeVault {
+                vault_id: self.parse_value()?,
+                flags: self.parse_value()?,
+            },
             TokenKind::SetMetadata => Instruction::SetMetadata {
                 entity_address: self.parse_value()?,
                 key: self.parse_value()?,
@@ -1,9 +1,11 @@
 use radix_engine_common::data::scrypto::model::*;
 use radix_engine_interface::api::node_modules::metadata::MetadataEntry;
 use radix_engine_interface::api::types::*;
-use radix_engine_interface::blueprints::resource::{AccessRule, AccessRulesConfig, MethodKey};
+use radix_engine_interface::blueprints::resource::{
+    AccessRule, AccessRulesConfig, MethodKey, VaultFreezeFlags,
+};
 use radix_engine_interface::data::manifest::{model::*, ManifestValue};
-use radix_engine_interface::math::Decimal;
+use radix_engine_interface::math::{Decimal, RoundingMode};
 use radix_engine_interface::*;
 use sbor::rust::collections::BTreeMap;
 use sbor::rust::collections::BTreeSet;
@@ -183,4 +185,31 @@ pub enum Instruction {
         method_name: String,
         args: ManifestValue,
     },
+
+    /// Blocks the given operations on a vault.
+    FreezeVault {
+        vault_id: ObjectId,
+        flags: VaultFreezeFlags,
+    },
+
+    /// Unblocks the given operations on a vault.
+    UnfreezeVault {
+        vault_id: ObjectId,
+        flags: VaultFreezeFlags,
+    },
+
+    /// Takes resource from worktop by the given amount, rounded to the divisibility of the
+    /// resource.
+    TakeFromWorktopAdvanced {
+        amount: Decimal,
+        resource_address: ResourceAddress,
+        rounding_mode: RoundingMode,
+    },
+
+    /// Burns non-fungibles by id through their resource manager, which requires the resource's
+    /// burn rule.
+    BurnNonFungibles {
+        resource_address: ResourceAddress,
+        ids: BTreeSet<NonFungibleLocalId>,
+    },
 }
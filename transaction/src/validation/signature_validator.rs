@@ -1,4 +1,5 @@
 use radix_engine_interface::crypto::*;
+use sbor::rust::vec::Vec;
 
 use crate::{
     ecdsa_secp256k1::EcdsaSecp256k1Signature,
@@ -73,12 +74,84 @@ pub fn verify_eddsa_ed25519(
     message_hash: &Hash,
     public_key: &EddsaEd25519PublicKey,
     signature: &EddsaEd25519Signature,
+) -> bool {
+    verify_eddsa_ed25519_message(&message_hash.0, public_key, signature)
+}
+
+pub fn verify_eddsa_ed25519_message(
+    message: &[u8],
+    public_key: &EddsaEd25519PublicKey,
+    signature: &EddsaEd25519Signature,
 ) -> bool {
     if let Ok(sig) = ed25519_dalek::Signature::from_bytes(&signature.0) {
         if let Ok(pk) = ed25519_dalek::PublicKey::from_bytes(&public_key.0) {
-            return pk.verify_strict(&message_hash.0, &sig).is_ok();
+            return pk.verify_strict(message, &sig).is_ok();
+        }
+    }
+
+    false
+}
+
+/// The domain separation tag of the BLS12-381 proof-of-possession ciphersuite, with public keys
+/// in G1 and signatures in G2, as used by Ethereum.
+pub const BLS12381_CIPHERSUITE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verifies an aggregate signature of each message by its public key.
+///
+/// Messages don't need to be distinct, so the public keys must come with a proof of possession,
+/// which is the responsibility of the caller.
+pub fn verify_bls12381_aggregate(
+    messages: &[(Bls12381G1PublicKey, Vec<u8>)],
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if messages.is_empty() {
+        return false;
+    }
+
+    if let Ok(sig) = blst::min_pk::Signature::from_bytes(&signature.0) {
+        let mut pks = Vec::with_capacity(messages.len());
+        for (public_key, _) in messages {
+            match blst::min_pk::PublicKey::from_bytes(&public_key.0) {
+                Ok(pk) => pks.push(pk),
+                Err(_) => return false,
+            }
         }
+        let pk_refs: Vec<&blst::min_pk::PublicKey> = pks.iter().collect();
+        let msgs: Vec<&[u8]> = messages.iter().map(|(_, msg)| msg.as_slice()).collect();
+        return sig.aggregate_verify(true, &msgs, BLS12381_CIPHERSUITE_DST, &pk_refs, true)
+            == blst::BLST_ERROR::BLST_SUCCESS;
     }
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bls12381_key_pair(seed: u8) -> (blst::min_pk::SecretKey, Bls12381G1PublicKey) {
+        let secret_key = blst::min_pk::SecretKey::key_gen(&[seed; 32], &[]).unwrap();
+        let public_key = Bls12381G1PublicKey(secret_key.sk_to_pk().compress());
+        (secret_key, public_key)
+    }
+
+    #[test]
+    fn test_verify_bls12381_aggregate() {
+        let (sk1, pk1) = bls12381_key_pair(1);
+        let (sk2, pk2) = bls12381_key_pair(2);
+        let sig1 = sk1.sign(b"message 1", BLS12381_CIPHERSUITE_DST, &[]);
+        let sig2 = sk2.sign(b"message 2", BLS12381_CIPHERSUITE_DST, &[]);
+        let aggregate = blst::min_pk::AggregateSignature::aggregate(&[&sig1, &sig2], true)
+            .unwrap()
+            .to_signature();
+        let signature = Bls12381G2Signature(aggregate.compress());
+
+        let messages = vec![(pk1, b"message 1".to_vec()), (pk2, b"message 2".to_vec())];
+        assert!(verify_bls12381_aggregate(&messages, &signature));
+
+        let messages = vec![(pk1, b"message 1".to_vec()), (pk2, b"message 3".to_vec())];
+        assert!(!verify_bls12381_aggregate(&messages, &signature));
+        assert!(!verify_bls12381_aggregate(&messages[..1], &signature));
+        assert!(!verify_bls12381_aggregate(&[], &signature));
+    }
+}
@@ -217,6 +217,11 @@ impl NotarizedTransactionValidator {
                         .new_bucket()
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
+                Instruction::TakeFromWorktopAdvanced { .. } => {
+                    id_validator
+                        .new_bucket()
+                        .map_err(TransactionValidationError::IdValidationError)?;
+                }
                 Instruction::ReturnToWorktop { bucket_id } => {
                     id_validator
                         .drop_bucket(bucket_id)
@@ -283,7 +288,10 @@ impl NotarizedTransactionValidator {
                         .drop_bucket(bucket_id)
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
-                Instruction::RecallResource { .. }
+                Instruction::BurnNonFungibles { .. }
+                | Instruction::RecallResource { .. }
+                | Instruction::FreezeVault { .. }
+                | Instruction::UnfreezeVault { .. }
                 | Instruction::SetMetadata { .. }
                 | Instruction::RemoveMetadata { .. }
                 | Instruction::SetPackageRoyaltyConfig { .. }